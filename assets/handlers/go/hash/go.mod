@@ -0,0 +1,5 @@
+module creo.local/handlers/hash
+
+go 1.22
+
+require golang.org/x/crypto v0.21.0
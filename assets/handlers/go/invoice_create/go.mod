@@ -0,0 +1,7 @@
+module creo.local/handlers/invoice_create
+
+go 1.22
+
+require (
+	go.mongodb.org/mongo-driver v1.14.0
+)
@@ -0,0 +1,23 @@
+package invoice_create
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+)
+
+// CreateInvoice inserts the invoice of the given JSON data and returns the ID of the inserted
+// invoice.
+func CreateInvoice(jsonData []byte) string {
+	invoice := newInvoice()
+	if err := json.Unmarshal(jsonData, &invoice); err != nil {
+		panic(err)
+	}
+	invoice.validate()
+
+	result, err := getCollection().InsertOne(context.Background(), invoice)
+	if err != nil {
+		panic(err)
+	}
+	return fmt.Sprint(result.InsertedID)
+}
@@ -0,0 +1,83 @@
+package invoice_create
+
+import (
+	"fmt"
+	"time"
+	"unicode/utf8"
+)
+
+// Address is the billing or shipping address of an invoice.
+type Address struct {
+	FirstName string `bson:"first_name" json:"first_name"`
+	LastName  string `bson:"last_name" json:"last_name"`
+	Street    string `bson:"street" json:"street"`
+	Number    uint32 `bson:"number" json:"number"`
+	ZipCode   uint32 `bson:"zip_code" json:"zip_code"`
+	City      string `bson:"city" json:"city"`
+	Country   string `bson:"country" json:"country"`
+}
+
+// Item is a purchasable item.
+type Item struct {
+	PriceInCents uint64 `bson:"price_in_cents" json:"price_in_cents"`
+	Name         string `bson:"name" json:"name"`
+}
+
+// OrderItem is an ordered quantity of an item.
+type OrderItem struct {
+	Item     Item   `bson:"item" json:"item"`
+	Quantity uint64 `bson:"quantity" json:"quantity"`
+}
+
+// Invoice is the stored invoice entity.
+type Invoice struct {
+	Items           []OrderItem `bson:"items" json:"items"`
+	BillingAddress  Address     `bson:"billing_address" json:"billing_address"`
+	ShippingAddress Address     `bson:"shipping_address" json:"shipping_address"`
+	UserID          string      `bson:"user_id" json:"user_id"`
+	TaxRate         float32     `bson:"tax_rate" json:"tax_rate"`
+	IssuedAt        time.Time   `bson:"issued_at" json:"issued_at"`
+	ExtraInfo       string      `bson:"extra_info" json:"extra_info"`
+	Status          string      `bson:"status" json:"status"`
+	InvoiceNumber   string      `bson:"invoice_number" json:"invoice_number"`
+}
+
+// newInvoice returns an invoice with the default tax rate, issue date and status, which are
+// overwritten by the properties present in the request body.
+func newInvoice() Invoice {
+	return Invoice{TaxRate: 0.15, IssuedAt: time.Now().UTC(), Status: "OPEN"}
+}
+
+func (invoice Invoice) validate() {
+	for _, orderItem := range invoice.Items {
+		orderItem.validate()
+	}
+	invoice.BillingAddress.validate()
+	invoice.ShippingAddress.validate()
+	validateLength("invoice_number", invoice.InvoiceNumber, 10, 13)
+}
+
+func (address Address) validate() {
+	validateLength("first_name", address.FirstName, 2, 64)
+	validateLength("last_name", address.LastName, 2, 64)
+	validateLength("street", address.Street, 2, 128)
+	if address.Number < 1 {
+		panic("expected number to be at least 1")
+	}
+}
+
+func (orderItem OrderItem) validate() {
+	if orderItem.Item.PriceInCents < 1 {
+		panic("expected price_in_cents to be at least 1")
+	}
+	validateLength("name", orderItem.Item.Name, 1, 128)
+	if orderItem.Quantity < 1 {
+		panic("expected quantity to be at least 1")
+	}
+}
+
+func validateLength(name string, value string, min int, max int) {
+	if length := utf8.RuneCountInString(value); length < min || length > max {
+		panic(fmt.Sprintf("expected %s to have a length between %d and %d, but was %d", name, min, max, length))
+	}
+}
@@ -0,0 +1,17 @@
+package invoice_delete
+
+import (
+	"context"
+
+	"go.mongodb.org/mongo-driver/bson"
+)
+
+// DeleteInvoiceByID deletes the invoice with the given ID and returns the number of deleted
+// invoices.
+func DeleteInvoiceByID(id int64) int64 {
+	result, err := getCollection().DeleteOne(context.Background(), bson.M{"_id": id})
+	if err != nil {
+		panic(err)
+	}
+	return result.DeletedCount
+}
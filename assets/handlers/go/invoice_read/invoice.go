@@ -0,0 +1,22 @@
+package invoice_read
+
+import (
+	"context"
+	"errors"
+
+	"go.mongodb.org/mongo-driver/bson"
+	"go.mongodb.org/mongo-driver/mongo"
+)
+
+// ReadInvoiceByID returns the invoice with the given ID, or nil if there is no such invoice.
+func ReadInvoiceByID(id int64) *Invoice {
+	var invoice Invoice
+	err := getCollection().FindOne(context.Background(), bson.M{"_id": id}).Decode(&invoice)
+	if errors.Is(err, mongo.ErrNoDocuments) {
+		return nil
+	}
+	if err != nil {
+		panic(err)
+	}
+	return &invoice
+}
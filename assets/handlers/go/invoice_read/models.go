@@ -0,0 +1,39 @@
+package invoice_read
+
+import "time"
+
+// Address is the billing or shipping address of an invoice.
+type Address struct {
+	FirstName string `bson:"first_name" json:"first_name"`
+	LastName  string `bson:"last_name" json:"last_name"`
+	Street    string `bson:"street" json:"street"`
+	Number    uint32 `bson:"number" json:"number"`
+	ZipCode   uint32 `bson:"zip_code" json:"zip_code"`
+	City      string `bson:"city" json:"city"`
+	Country   string `bson:"country" json:"country"`
+}
+
+// Item is a purchasable item.
+type Item struct {
+	PriceInCents uint64 `bson:"price_in_cents" json:"price_in_cents"`
+	Name         string `bson:"name" json:"name"`
+}
+
+// OrderItem is an ordered quantity of an item.
+type OrderItem struct {
+	Item     Item   `bson:"item" json:"item"`
+	Quantity uint64 `bson:"quantity" json:"quantity"`
+}
+
+// Invoice is the stored invoice entity.
+type Invoice struct {
+	Items           []OrderItem `bson:"items" json:"items"`
+	BillingAddress  Address     `bson:"billing_address" json:"billing_address"`
+	ShippingAddress Address     `bson:"shipping_address" json:"shipping_address"`
+	UserID          string      `bson:"user_id" json:"user_id"`
+	TaxRate         float32     `bson:"tax_rate" json:"tax_rate"`
+	IssuedAt        time.Time   `bson:"issued_at" json:"issued_at"`
+	ExtraInfo       string      `bson:"extra_info" json:"extra_info"`
+	Status          string      `bson:"status" json:"status"`
+	InvoiceNumber   string      `bson:"invoice_number" json:"invoice_number"`
+}
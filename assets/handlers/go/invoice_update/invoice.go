@@ -0,0 +1,36 @@
+package invoice_update
+
+import (
+	"context"
+	"encoding/json"
+
+	"go.mongodb.org/mongo-driver/bson"
+)
+
+// UpdateInvoice updates the given properties of the invoice with the given ID and returns the
+// number of modified invoices.
+func UpdateInvoice(id int64, jsonData []byte) int64 {
+	var update invoiceUpdate
+	if err := json.Unmarshal(jsonData, &update); err != nil {
+		panic(err)
+	}
+	update.validate()
+
+	updateDoc, err := bson.Marshal(update)
+	if err != nil {
+		panic(err)
+	}
+	var set bson.M
+	if err := bson.Unmarshal(updateDoc, &set); err != nil {
+		panic(err)
+	}
+	if len(set) == 0 {
+		return 0
+	}
+
+	result, err := getCollection().UpdateOne(context.Background(), bson.M{"_id": id}, bson.M{"$set": set})
+	if err != nil {
+		panic(err)
+	}
+	return result.ModifiedCount
+}
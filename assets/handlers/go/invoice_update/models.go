@@ -0,0 +1,78 @@
+package invoice_update
+
+import (
+	"fmt"
+	"unicode/utf8"
+)
+
+// Address is the billing or shipping address of an invoice.
+type Address struct {
+	FirstName string `bson:"first_name" json:"first_name"`
+	LastName  string `bson:"last_name" json:"last_name"`
+	Street    string `bson:"street" json:"street"`
+	Number    uint32 `bson:"number" json:"number"`
+	ZipCode   uint32 `bson:"zip_code" json:"zip_code"`
+	City      string `bson:"city" json:"city"`
+	Country   string `bson:"country" json:"country"`
+}
+
+// Item is a purchasable item.
+type Item struct {
+	PriceInCents uint64 `bson:"price_in_cents" json:"price_in_cents"`
+	Name         string `bson:"name" json:"name"`
+}
+
+// OrderItem is an ordered quantity of an item.
+type OrderItem struct {
+	Item     Item   `bson:"item" json:"item"`
+	Quantity uint64 `bson:"quantity" json:"quantity"`
+}
+
+// invoiceUpdate contains the optional invoice properties to update.
+type invoiceUpdate struct {
+	Items           *[]OrderItem `bson:"items,omitempty" json:"items"`
+	BillingAddress  *Address     `bson:"billing_address,omitempty" json:"billing_address"`
+	ShippingAddress *Address     `bson:"shipping_address,omitempty" json:"shipping_address"`
+	TaxRate         *float32     `bson:"tax_rate,omitempty" json:"tax_rate"`
+	ExtraInfo       *string      `bson:"extra_info,omitempty" json:"extra_info"`
+	Status          *string      `bson:"status,omitempty" json:"status"`
+}
+
+func (update invoiceUpdate) validate() {
+	if update.Items != nil {
+		for _, orderItem := range *update.Items {
+			orderItem.validate()
+		}
+	}
+	if update.BillingAddress != nil {
+		update.BillingAddress.validate()
+	}
+	if update.ShippingAddress != nil {
+		update.ShippingAddress.validate()
+	}
+}
+
+func (address Address) validate() {
+	validateLength("first_name", address.FirstName, 2, 64)
+	validateLength("last_name", address.LastName, 2, 64)
+	validateLength("street", address.Street, 2, 128)
+	if address.Number < 1 {
+		panic("expected number to be at least 1")
+	}
+}
+
+func (orderItem OrderItem) validate() {
+	if orderItem.Item.PriceInCents < 1 {
+		panic("expected price_in_cents to be at least 1")
+	}
+	validateLength("name", orderItem.Item.Name, 1, 128)
+	if orderItem.Quantity < 1 {
+		panic("expected quantity to be at least 1")
+	}
+}
+
+func validateLength(name string, value string, min int, max int) {
+	if length := utf8.RuneCountInString(value); length < min || length > max {
+		panic(fmt.Sprintf("expected %s to have a length between %d and %d, but was %d", name, min, max, length))
+	}
+}
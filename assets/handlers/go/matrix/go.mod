@@ -0,0 +1,3 @@
+module creo.local/handlers/matrix
+
+go 1.22
@@ -0,0 +1,59 @@
+package matrix
+
+import (
+	"fmt"
+	"math"
+	"math/rand"
+)
+
+// InvertRandomMatrix inverts a random matrix of the given size.
+func InvertRandomMatrix(size int32) [][]float64 {
+	if size < 1 {
+		panic(fmt.Sprintf("Matrix size must be greater or equal to `1`, but was %d.", size))
+	}
+	n := int(size)
+
+	matrix := make([][]float64, n)
+	inverse := make([][]float64, n)
+	for i := range matrix {
+		matrix[i] = make([]float64, n)
+		inverse[i] = make([]float64, n)
+		for j := range matrix[i] {
+			matrix[i][j] = rand.Float64()
+		}
+		inverse[i][i] = 1
+	}
+
+	// Gauss-Jordan elimination with partial pivoting
+	for col := 0; col < n; col++ {
+		pivot := col
+		for row := col + 1; row < n; row++ {
+			if math.Abs(matrix[row][col]) > math.Abs(matrix[pivot][col]) {
+				pivot = row
+			}
+		}
+		if matrix[pivot][col] == 0 {
+			panic("matrix is not invertible")
+		}
+		matrix[col], matrix[pivot] = matrix[pivot], matrix[col]
+		inverse[col], inverse[pivot] = inverse[pivot], inverse[col]
+
+		scale := matrix[col][col]
+		for j := 0; j < n; j++ {
+			matrix[col][j] /= scale
+			inverse[col][j] /= scale
+		}
+		for row := 0; row < n; row++ {
+			if row == col {
+				continue
+			}
+			factor := matrix[row][col]
+			for j := 0; j < n; j++ {
+				matrix[row][j] -= factor * matrix[col][j]
+				inverse[row][j] -= factor * inverse[col][j]
+			}
+		}
+	}
+
+	return inverse
+}
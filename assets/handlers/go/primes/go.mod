@@ -0,0 +1,3 @@
+module creo.local/handlers/primes
+
+go 1.22
@@ -0,0 +1,33 @@
+package primes
+
+// sieve is an incremental sieve of Eratosthenes, which maps the next composite numbers to their
+// prime factors.
+type sieve struct {
+	q    uint64
+	seen map[uint64][]uint64
+}
+
+func (s *sieve) next() uint64 {
+	for {
+		s.q++
+		factors, ok := s.seen[s.q]
+		if !ok {
+			s.seen[s.q*s.q] = []uint64{s.q}
+			return s.q
+		}
+		for _, p := range factors {
+			s.seen[s.q+p] = append(s.seen[s.q+p], p)
+		}
+		delete(s.seen, s.q)
+	}
+}
+
+// GenerateFirstPrimes generates the first n prime numbers.
+func GenerateFirstPrimes(n int32) []uint64 {
+	s := &sieve{q: 1, seen: map[uint64][]uint64{}}
+	primes := make([]uint64, 0, n)
+	for i := int32(0); i < n; i++ {
+		primes = append(primes, s.next())
+	}
+	return primes
+}
@@ -0,0 +1,39 @@
+package user_create
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"sync"
+
+	"go.mongodb.org/mongo-driver/mongo"
+	"go.mongodb.org/mongo-driver/mongo/options"
+)
+
+const (
+	userDB         = "user_db"
+	userCollection = "user_collection"
+)
+
+var (
+	clientOnce sync.Once
+	client     *mongo.Client
+)
+
+func getCollection() *mongo.Collection {
+	clientOnce.Do(func() {
+		uri := fmt.Sprintf(
+			"mongodb://%s:%s@%s:%s",
+			os.Getenv("DB_MONGO_USER"),
+			os.Getenv("DB_MONGO_PASSWORD"),
+			os.Getenv("DB_MONGO_HOST"),
+			os.Getenv("DB_MONGO_PORT"),
+		)
+		var err error
+		client, err = mongo.Connect(context.Background(), options.Client().ApplyURI(uri))
+		if err != nil {
+			panic(err)
+		}
+	})
+	return client.Database(userDB).Collection(userCollection)
+}
@@ -0,0 +1,8 @@
+module creo.local/handlers/user_create
+
+go 1.22
+
+require (
+	go.mongodb.org/mongo-driver v1.14.0
+	golang.org/x/crypto v0.21.0
+)
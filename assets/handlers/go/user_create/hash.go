@@ -0,0 +1,37 @@
+package user_create
+
+import (
+	"crypto/rand"
+	"encoding/base64"
+	"fmt"
+
+	"golang.org/x/crypto/argon2"
+)
+
+const (
+	timeCost    = 1
+	memoryCost  = 6144
+	parallelism = 4
+	saltLength  = 16
+	keyLength   = 32
+)
+
+// hashPassword hashes the given password using the Argon2id hash function and returns the hash
+// in the PHC string format.
+func hashPassword(password string) []byte {
+	salt := make([]byte, saltLength)
+	if _, err := rand.Read(salt); err != nil {
+		panic(err)
+	}
+	key := argon2.IDKey([]byte(password), salt, timeCost, memoryCost, parallelism, keyLength)
+
+	return []byte(fmt.Sprintf(
+		"$argon2id$v=%d$m=%d,t=%d,p=%d$%s$%s",
+		argon2.Version,
+		memoryCost,
+		timeCost,
+		parallelism,
+		base64.RawStdEncoding.EncodeToString(salt),
+		base64.RawStdEncoding.EncodeToString(key),
+	))
+}
@@ -0,0 +1,52 @@
+package user_create
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"time"
+	"unicode/utf8"
+)
+
+// User is the stored user entity.
+type User struct {
+	Username     string    `bson:"username"`
+	Email        string    `bson:"email"`
+	PasswordHash []byte    `bson:"password_hash"`
+	CreatedAt    time.Time `bson:"created_at"`
+}
+
+// userData is the user entity of the request body.
+type userData struct {
+	Username string `json:"username"`
+	Email    string `json:"email"`
+	Password string `json:"password"`
+}
+
+// CreateUser inserts the user of the given JSON data and returns the ID of the inserted user.
+func CreateUser(jsonData []byte) string {
+	var data userData
+	if err := json.Unmarshal(jsonData, &data); err != nil {
+		panic(err)
+	}
+	validateLength("username", data.Username, 3, 64)
+	validateLength("email", data.Email, 3, 64)
+
+	user := User{
+		Username:     data.Username,
+		Email:        data.Email,
+		PasswordHash: hashPassword(data.Password),
+		CreatedAt:    time.Now().UTC(),
+	}
+	result, err := getCollection().InsertOne(context.Background(), user)
+	if err != nil {
+		panic(err)
+	}
+	return fmt.Sprint(result.InsertedID)
+}
+
+func validateLength(name string, value string, min int, max int) {
+	if length := utf8.RuneCountInString(value); length < min || length > max {
+		panic(fmt.Sprintf("expected %s to have a length between %d and %d, but was %d", name, min, max, length))
+	}
+}
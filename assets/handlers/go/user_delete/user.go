@@ -0,0 +1,16 @@
+package user_delete
+
+import (
+	"context"
+
+	"go.mongodb.org/mongo-driver/bson"
+)
+
+// DeleteUserByID deletes the user with the given ID and returns the number of deleted users.
+func DeleteUserByID(id int64) int64 {
+	result, err := getCollection().DeleteOne(context.Background(), bson.M{"_id": id})
+	if err != nil {
+		panic(err)
+	}
+	return result.DeletedCount
+}
@@ -0,0 +1,30 @@
+package user_read
+
+import (
+	"context"
+	"errors"
+	"time"
+
+	"go.mongodb.org/mongo-driver/bson"
+	"go.mongodb.org/mongo-driver/mongo"
+)
+
+// User is the stored user entity without its password hash.
+type User struct {
+	Username  string    `bson:"username" json:"username"`
+	Email     string    `bson:"email" json:"email"`
+	CreatedAt time.Time `bson:"created_at" json:"created_at"`
+}
+
+// ReadUserByID returns the user with the given ID, or nil if there is no such user.
+func ReadUserByID(id int64) *User {
+	var user User
+	err := getCollection().FindOne(context.Background(), bson.M{"_id": id}).Decode(&user)
+	if errors.Is(err, mongo.ErrNoDocuments) {
+		return nil
+	}
+	if err != nil {
+		panic(err)
+	}
+	return &user
+}
@@ -0,0 +1,54 @@
+package user_update
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"unicode/utf8"
+
+	"go.mongodb.org/mongo-driver/bson"
+)
+
+// userData is the partial user entity of the request body.
+type userData struct {
+	Username *string `json:"username"`
+	Email    *string `json:"email"`
+	Password *string `json:"password"`
+}
+
+// UpdateUserByID updates the given properties of the user with the given ID and returns the
+// number of modified users.
+func UpdateUserByID(id int64, jsonData []byte) int64 {
+	var data userData
+	if err := json.Unmarshal(jsonData, &data); err != nil {
+		panic(err)
+	}
+
+	update := bson.M{}
+	if data.Username != nil {
+		validateLength("username", *data.Username, 3, 64)
+		update["username"] = *data.Username
+	}
+	if data.Email != nil {
+		validateLength("email", *data.Email, 3, 64)
+		update["email"] = *data.Email
+	}
+	if data.Password != nil {
+		update["password_hash"] = hashPassword(*data.Password)
+	}
+	if len(update) == 0 {
+		return 0
+	}
+
+	result, err := getCollection().UpdateOne(context.Background(), bson.M{"_id": id}, bson.M{"$set": update})
+	if err != nil {
+		panic(err)
+	}
+	return result.ModifiedCount
+}
+
+func validateLength(name string, value string, min int, max int) {
+	if length := utf8.RuneCountInString(value); length < min || length > max {
+		panic(fmt.Sprintf("expected %s to have a length between %d and %d, but was %d", name, min, max, length))
+	}
+}
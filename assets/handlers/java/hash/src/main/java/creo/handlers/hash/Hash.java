@@ -0,0 +1,52 @@
+package creo.handlers.hash;
+
+import java.nio.charset.StandardCharsets;
+import java.security.SecureRandom;
+import java.util.Base64;
+import java.util.Map;
+
+import org.bouncycastle.crypto.generators.Argon2BytesGenerator;
+import org.bouncycastle.crypto.params.Argon2Parameters;
+
+public final class Hash {
+    private static final int TIME_COST = 1;
+    private static final int MEMORY_COST = 6144;
+    private static final int PARALLELISM = 4;
+    private static final int SALT_LENGTH = 16;
+    private static final int KEY_LENGTH = 32;
+
+    private static final SecureRandom random = new SecureRandom();
+
+    private Hash() {}
+
+    /**
+     * Hashes the given password using the Argon2id hash function and returns the hash in the PHC
+     * string format.
+     */
+    public static Map<String, String> hashPassword(String password) {
+        byte[] salt = new byte[SALT_LENGTH];
+        random.nextBytes(salt);
+        Argon2Parameters parameters = new Argon2Parameters.Builder(Argon2Parameters.ARGON2_id)
+                .withVersion(Argon2Parameters.ARGON2_VERSION_13)
+                .withIterations(TIME_COST)
+                .withMemoryAsKB(MEMORY_COST)
+                .withParallelism(PARALLELISM)
+                .withSalt(salt)
+                .build();
+        Argon2BytesGenerator generator = new Argon2BytesGenerator();
+        generator.init(parameters);
+        byte[] key = new byte[KEY_LENGTH];
+        generator.generateBytes(password.getBytes(StandardCharsets.UTF_8), key);
+
+        Base64.Encoder encoder = Base64.getEncoder().withoutPadding();
+        String hash = String.format(
+                "$argon2id$v=%d$m=%d,t=%d,p=%d$%s$%s",
+                Argon2Parameters.ARGON2_VERSION_13,
+                MEMORY_COST,
+                TIME_COST,
+                PARALLELISM,
+                encoder.encodeToString(salt),
+                encoder.encodeToString(key));
+        return Map.of("hash", hash);
+    }
+}
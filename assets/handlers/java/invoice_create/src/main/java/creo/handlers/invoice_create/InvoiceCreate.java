@@ -0,0 +1,70 @@
+package creo.handlers.invoice_create;
+
+import static creo.handlers.invoice_create.Validation.unmarshal;
+import static creo.handlers.invoice_create.Validation.validateLength;
+
+import java.util.Date;
+import java.util.List;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import creo.handlers.invoice_create.Models.Address;
+import creo.handlers.invoice_create.Models.OrderItem;
+import org.bson.Document;
+
+public final class InvoiceCreate {
+    private InvoiceCreate() {}
+
+    /**
+     * The invoice entity of the request body. The tax rate, issue date and status default to 0.15,
+     * the current time and "OPEN" if they are absent.
+     */
+    record Invoice(
+            @JsonProperty("items") List<OrderItem> items,
+            @JsonProperty("billing_address") Address billingAddress,
+            @JsonProperty("shipping_address") Address shippingAddress,
+            @JsonProperty("user_id") String userId,
+            @JsonProperty("tax_rate") Double taxRate,
+            @JsonProperty("issued_at") Date issuedAt,
+            @JsonProperty("extra_info") String extraInfo,
+            @JsonProperty("status") String status,
+            @JsonProperty("invoice_number") String invoiceNumber) {
+        Invoice {
+            items = items == null ? List.of() : items;
+            taxRate = taxRate == null ? 0.15 : taxRate;
+            issuedAt = issuedAt == null ? new Date() : issuedAt;
+            extraInfo = extraInfo == null ? "" : extraInfo;
+            status = status == null ? "OPEN" : status;
+        }
+
+        void validate() {
+            items.forEach(OrderItem::validate);
+            billingAddress.validate();
+            shippingAddress.validate();
+            validateLength("invoice_number", invoiceNumber, 10, 13);
+        }
+
+        Document toDocument() {
+            return new Document("items", items.stream().map(OrderItem::toDocument).toList())
+                    .append("billing_address", billingAddress.toDocument())
+                    .append("shipping_address", shippingAddress.toDocument())
+                    .append("user_id", userId)
+                    .append("tax_rate", taxRate)
+                    .append("issued_at", issuedAt)
+                    .append("extra_info", extraInfo)
+                    .append("status", status)
+                    .append("invoice_number", invoiceNumber);
+        }
+    }
+
+    /** Inserts the invoice of the given JSON data and returns the ID of the inserted invoice. */
+    public static String createInvoice(byte[] jsonData) {
+        Invoice invoice = unmarshal(jsonData, Invoice.class);
+        invoice.validate();
+        return Db.getCollection()
+                .insertOne(invoice.toDocument())
+                .getInsertedId()
+                .asObjectId()
+                .getValue()
+                .toHexString();
+    }
+}
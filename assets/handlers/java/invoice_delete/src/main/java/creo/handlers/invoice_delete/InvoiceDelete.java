@@ -0,0 +1,12 @@
+package creo.handlers.invoice_delete;
+
+import com.mongodb.client.model.Filters;
+
+public final class InvoiceDelete {
+    private InvoiceDelete() {}
+
+    /** Deletes the invoice with the given ID and returns the number of deleted invoices. */
+    public static long deleteInvoiceById(long id) {
+        return Db.getCollection().deleteOne(Filters.eq("_id", id)).getDeletedCount();
+    }
+}
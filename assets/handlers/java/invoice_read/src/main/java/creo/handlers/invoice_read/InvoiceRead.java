@@ -0,0 +1,14 @@
+package creo.handlers.invoice_read;
+
+import com.mongodb.client.model.Filters;
+import com.mongodb.client.model.Projections;
+import org.bson.Document;
+
+public final class InvoiceRead {
+    private InvoiceRead() {}
+
+    /** Returns the invoice with the given ID, or null if there is no such invoice. */
+    public static Document readInvoiceById(long id) {
+        return Db.getCollection().find(Filters.eq("_id", id)).projection(Projections.excludeId()).first();
+    }
+}
@@ -0,0 +1,27 @@
+package creo.handlers.invoice_update;
+
+import com.mongodb.client.MongoClient;
+import com.mongodb.client.MongoClients;
+import com.mongodb.client.MongoCollection;
+import org.bson.Document;
+
+final class Db {
+    private static final String DATABASE = "invoice_db";
+    private static final String COLLECTION = "invoice_collection";
+
+    private Db() {}
+
+    /** Holds the client, which connects on first use. */
+    private static final class ClientHolder {
+        private static final MongoClient CLIENT = MongoClients.create(String.format(
+                "mongodb://%s:%s@%s:%s",
+                System.getenv("DB_MONGO_USER"),
+                System.getenv("DB_MONGO_PASSWORD"),
+                System.getenv("DB_MONGO_HOST"),
+                System.getenv("DB_MONGO_PORT")));
+    }
+
+    static MongoCollection<Document> getCollection() {
+        return ClientHolder.CLIENT.getDatabase(DATABASE).getCollection(COLLECTION);
+    }
+}
@@ -0,0 +1,65 @@
+package creo.handlers.invoice_update;
+
+import static creo.handlers.invoice_update.Validation.unmarshal;
+
+import java.util.List;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.mongodb.client.model.Filters;
+import creo.handlers.invoice_update.Models.Address;
+import creo.handlers.invoice_update.Models.OrderItem;
+import org.bson.Document;
+
+public final class InvoiceUpdate {
+    private InvoiceUpdate() {}
+
+    /** The optional invoice properties to update. */
+    record Update(
+            @JsonProperty("items") List<OrderItem> items,
+            @JsonProperty("billing_address") Address billingAddress,
+            @JsonProperty("shipping_address") Address shippingAddress,
+            @JsonProperty("tax_rate") Double taxRate,
+            @JsonProperty("extra_info") String extraInfo,
+            @JsonProperty("status") String status) {
+        Document toSetDocument() {
+            Document set = new Document();
+            if (items != null) {
+                items.forEach(OrderItem::validate);
+                set.append("items", items.stream().map(OrderItem::toDocument).toList());
+            }
+            if (billingAddress != null) {
+                billingAddress.validate();
+                set.append("billing_address", billingAddress.toDocument());
+            }
+            if (shippingAddress != null) {
+                shippingAddress.validate();
+                set.append("shipping_address", shippingAddress.toDocument());
+            }
+            if (taxRate != null) {
+                set.append("tax_rate", taxRate);
+            }
+            if (extraInfo != null) {
+                set.append("extra_info", extraInfo);
+            }
+            if (status != null) {
+                set.append("status", status);
+            }
+            return set;
+        }
+    }
+
+    /**
+     * Updates the given properties of the invoice with the given ID and returns the number of
+     * modified invoices.
+     */
+    public static long updateInvoice(long id, byte[] jsonData) {
+        Document set = unmarshal(jsonData, Update.class).toSetDocument();
+        if (set.isEmpty()) {
+            return 0;
+        }
+
+        return Db.getCollection()
+                .updateOne(Filters.eq("_id", id), new Document("$set", set))
+                .getModifiedCount();
+    }
+}
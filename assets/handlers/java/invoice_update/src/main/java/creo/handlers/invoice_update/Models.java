@@ -0,0 +1,58 @@
+package creo.handlers.invoice_update;
+
+import static creo.handlers.invoice_update.Validation.validateLength;
+import static creo.handlers.invoice_update.Validation.validateMinimum;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import org.bson.Document;
+
+final class Models {
+    private Models() {}
+
+    /** The billing or shipping address of an invoice. */
+    record Address(
+            @JsonProperty("first_name") String firstName,
+            @JsonProperty("last_name") String lastName,
+            @JsonProperty("street") String street,
+            @JsonProperty("number") long number,
+            @JsonProperty("zip_code") long zipCode,
+            @JsonProperty("city") String city,
+            @JsonProperty("country") String country) {
+        void validate() {
+            validateLength("first_name", firstName, 2, 64);
+            validateLength("last_name", lastName, 2, 64);
+            validateLength("street", street, 2, 128);
+            validateMinimum("number", number, 1);
+        }
+
+        Document toDocument() {
+            return new Document("first_name", firstName)
+                    .append("last_name", lastName)
+                    .append("street", street)
+                    .append("number", number)
+                    .append("zip_code", zipCode)
+                    .append("city", city)
+                    .append("country", country);
+        }
+    }
+
+    /** A purchasable item. */
+    record Item(@JsonProperty("price_in_cents") long priceInCents, @JsonProperty("name") String name) {
+        Document toDocument() {
+            return new Document("price_in_cents", priceInCents).append("name", name);
+        }
+    }
+
+    /** An ordered quantity of an item. */
+    record OrderItem(@JsonProperty("item") Item item, @JsonProperty("quantity") long quantity) {
+        void validate() {
+            validateMinimum("price_in_cents", item.priceInCents(), 1);
+            validateLength("name", item.name(), 1, 128);
+            validateMinimum("quantity", quantity, 1);
+        }
+
+        Document toDocument() {
+            return new Document("item", item.toDocument()).append("quantity", quantity);
+        }
+    }
+}
@@ -0,0 +1,41 @@
+package creo.handlers.invoice_update;
+
+import java.io.IOException;
+import java.io.UncheckedIOException;
+
+import com.fasterxml.jackson.databind.DeserializationFeature;
+import com.fasterxml.jackson.databind.ObjectMapper;
+
+final class Validation {
+    private static final ObjectMapper mapper =
+            new ObjectMapper().configure(DeserializationFeature.FAIL_ON_UNKNOWN_PROPERTIES, false);
+
+    private Validation() {}
+
+    /** Deserializes the given JSON data to the given type. */
+    static <T> T unmarshal(byte[] jsonData, Class<T> type) {
+        try {
+            return mapper.readValue(jsonData, type);
+        } catch (IOException e) {
+            throw new UncheckedIOException(e);
+        }
+    }
+
+    static void validateLength(String name, String value, int min, int max) {
+        if (value == null) {
+            throw new IllegalArgumentException("expected " + name + " to be present");
+        }
+        int length = value.codePointCount(0, value.length());
+        if (length < min || length > max) {
+            throw new IllegalArgumentException(String.format(
+                    "expected %s to have a length between %d and %d, but was %d", name, min, max, length));
+        }
+    }
+
+    static void validateMinimum(String name, long value, long min) {
+        if (value < min) {
+            throw new IllegalArgumentException(String.format(
+                    "expected %s to be at least %d, but was %d", name, min, value));
+        }
+    }
+}
@@ -0,0 +1,64 @@
+package creo.handlers.matrix;
+
+import java.util.concurrent.ThreadLocalRandom;
+
+public final class Matrix {
+    private Matrix() {}
+
+    /** Inverts a random matrix of the given size. */
+    public static double[][] invertRandomMatrix(int size) {
+        if (size < 1) {
+            throw new IllegalArgumentException(
+                    "Matrix size must be greater or equal to `1`, but was " + size + ".");
+        }
+
+        double[][] matrix = new double[size][size];
+        double[][] inverse = new double[size][size];
+        ThreadLocalRandom random = ThreadLocalRandom.current();
+        for (int i = 0; i < size; i++) {
+            for (int j = 0; j < size; j++) {
+                matrix[i][j] = random.nextDouble();
+            }
+            inverse[i][i] = 1;
+        }
+
+        // Gauss-Jordan elimination with partial pivoting
+        for (int col = 0; col < size; col++) {
+            int pivot = col;
+            for (int row = col + 1; row < size; row++) {
+                if (Math.abs(matrix[row][col]) > Math.abs(matrix[pivot][col])) {
+                    pivot = row;
+                }
+            }
+            if (matrix[pivot][col] == 0) {
+                throw new ArithmeticException("matrix is not invertible");
+            }
+            swap(matrix, col, pivot);
+            swap(inverse, col, pivot);
+
+            double scale = matrix[col][col];
+            for (int j = 0; j < size; j++) {
+                matrix[col][j] /= scale;
+                inverse[col][j] /= scale;
+            }
+            for (int row = 0; row < size; row++) {
+                if (row == col) {
+                    continue;
+                }
+                double factor = matrix[row][col];
+                for (int j = 0; j < size; j++) {
+                    matrix[row][j] -= factor * matrix[col][j];
+                    inverse[row][j] -= factor * inverse[col][j];
+                }
+            }
+        }
+
+        return inverse;
+    }
+
+    private static void swap(double[][] rows, int i, int j) {
+        double[] row = rows[i];
+        rows[i] = rows[j];
+        rows[j] = row;
+    }
+}
@@ -0,0 +1,43 @@
+package creo.handlers.primes;
+
+import java.util.ArrayList;
+import java.util.HashMap;
+import java.util.List;
+import java.util.Map;
+
+public final class Primes {
+    private Primes() {}
+
+    /** Generates the first n prime numbers. */
+    public static List<Long> generateFirstPrimes(int n) {
+        Sieve sieve = new Sieve();
+        List<Long> primes = new ArrayList<>(n);
+        for (int i = 0; i < n; i++) {
+            primes.add(sieve.next());
+        }
+        return primes;
+    }
+
+    /**
+     * An incremental sieve of Eratosthenes, which maps the next composite numbers to their prime
+     * factors.
+     */
+    private static final class Sieve {
+        private long q = 1;
+        private final Map<Long, List<Long>> seen = new HashMap<>();
+
+        long next() {
+            while (true) {
+                q++;
+                List<Long> factors = seen.remove(q);
+                if (factors == null) {
+                    seen.put(q * q, new ArrayList<>(List.of(q)));
+                    return q;
+                }
+                for (long p : factors) {
+                    seen.computeIfAbsent(q + p, key -> new ArrayList<>()).add(p);
+                }
+            }
+        }
+    }
+}
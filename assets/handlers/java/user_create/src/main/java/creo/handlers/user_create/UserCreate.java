@@ -0,0 +1,28 @@
+package creo.handlers.user_create;
+
+import static creo.handlers.user_create.Validation.unmarshal;
+import static creo.handlers.user_create.Validation.validateLength;
+
+import java.util.Date;
+
+import org.bson.Document;
+
+public final class UserCreate {
+    private UserCreate() {}
+
+    /** The user entity of the request body. */
+    record UserData(String username, String email, String password) {}
+
+    /** Inserts the user of the given JSON data and returns the ID of the inserted user. */
+    public static String createUser(byte[] jsonData) {
+        UserData data = unmarshal(jsonData, UserData.class);
+        validateLength("username", data.username(), 3, 64);
+        validateLength("email", data.email(), 3, 64);
+
+        Document user = new Document("username", data.username())
+                .append("email", data.email())
+                .append("password_hash", PasswordHash.hash(data.password()))
+                .append("created_at", new Date());
+        return Db.getCollection().insertOne(user).getInsertedId().asObjectId().getValue().toHexString();
+    }
+}
@@ -0,0 +1,12 @@
+package creo.handlers.user_delete;
+
+import com.mongodb.client.model.Filters;
+
+public final class UserDelete {
+    private UserDelete() {}
+
+    /** Deletes the user with the given ID and returns the number of deleted users. */
+    public static long deleteUserById(long id) {
+        return Db.getCollection().deleteOne(Filters.eq("_id", id)).getDeletedCount();
+    }
+}
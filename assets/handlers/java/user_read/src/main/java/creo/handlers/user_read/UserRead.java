@@ -0,0 +1,18 @@
+package creo.handlers.user_read;
+
+import com.mongodb.client.model.Filters;
+import com.mongodb.client.model.Projections;
+import org.bson.Document;
+
+public final class UserRead {
+    private UserRead() {}
+
+    /** Returns the user with the given ID without its password hash, or null if there is no such user. */
+    public static Document readUserById(long id) {
+        return Db.getCollection()
+                .find(Filters.eq("_id", id))
+                .projection(Projections.fields(
+                        Projections.include("username", "email", "created_at"), Projections.excludeId()))
+                .first();
+    }
+}
@@ -0,0 +1,42 @@
+package creo.handlers.user_update;
+
+import static creo.handlers.user_update.Validation.unmarshal;
+import static creo.handlers.user_update.Validation.validateLength;
+
+import com.mongodb.client.model.Filters;
+import org.bson.Document;
+
+public final class UserUpdate {
+    private UserUpdate() {}
+
+    /** The partial user entity of the request body. */
+    record UserData(String username, String email, String password) {}
+
+    /**
+     * Updates the given properties of the user with the given ID and returns the number of
+     * modified users.
+     */
+    public static long updateUserById(long id, byte[] jsonData) {
+        UserData data = unmarshal(jsonData, UserData.class);
+
+        Document update = new Document();
+        if (data.username() != null) {
+            validateLength("username", data.username(), 3, 64);
+            update.append("username", data.username());
+        }
+        if (data.email() != null) {
+            validateLength("email", data.email(), 3, 64);
+            update.append("email", data.email());
+        }
+        if (data.password() != null) {
+            update.append("password_hash", PasswordHash.hash(data.password()));
+        }
+        if (update.isEmpty()) {
+            return 0;
+        }
+
+        return Db.getCollection()
+                .updateOne(Filters.eq("_id", id), new Document("$set", update))
+                .getModifiedCount();
+    }
+}
@@ -0,0 +1,23 @@
+import argon2 from "argon2";
+
+const TIME_COST = 1;
+const MEMORY_COST = 6144;
+const PARALLELISM = 4;
+const SALT_LENGTH = 16;
+const KEY_LENGTH = 32;
+
+/**
+ * Hashes the given password using the Argon2id hash function and returns the hash in the PHC string
+ * format.
+ */
+export async function hashPassword(password) {
+  const hash = await argon2.hash(password, {
+    type: argon2.argon2id,
+    timeCost: TIME_COST,
+    memoryCost: MEMORY_COST,
+    parallelism: PARALLELISM,
+    saltLength: SALT_LENGTH,
+    hashLength: KEY_LENGTH,
+  });
+  return { hash };
+}
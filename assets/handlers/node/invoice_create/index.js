@@ -0,0 +1,11 @@
+import { getCollection } from "./db.js";
+import { newInvoice, validateInvoice } from "./models.js";
+
+/** Inserts the invoice of the given JSON data and returns the ID of the inserted invoice. */
+export async function createInvoice(jsonData) {
+  const invoice = newInvoice(JSON.parse(jsonData.toString("utf8")));
+  validateInvoice(invoice);
+
+  const result = await getCollection().insertOne(invoice);
+  return String(result.insertedId);
+}
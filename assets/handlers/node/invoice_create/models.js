@@ -0,0 +1,52 @@
+/**
+ * Returns the invoice of the given request body with the default tax rate, issue date and status
+ * for the properties missing in the request body.
+ */
+export function newInvoice(data) {
+  return {
+    items: data.items ?? [],
+    billing_address: data.billing_address,
+    shipping_address: data.shipping_address,
+    user_id: data.user_id ?? "",
+    tax_rate: data.tax_rate ?? 0.15,
+    issued_at: data.issued_at != null ? new Date(data.issued_at) : new Date(),
+    extra_info: data.extra_info ?? "",
+    status: data.status ?? "OPEN",
+    invoice_number: data.invoice_number ?? "",
+  };
+}
+
+export function validateInvoice(invoice) {
+  for (const orderItem of invoice.items) {
+    validateOrderItem(orderItem);
+  }
+  validateAddress(invoice.billing_address);
+  validateAddress(invoice.shipping_address);
+  validateLength("invoice_number", invoice.invoice_number, 10, 13);
+}
+
+function validateAddress(address) {
+  validateLength("first_name", address?.first_name, 2, 64);
+  validateLength("last_name", address?.last_name, 2, 64);
+  validateLength("street", address?.street, 2, 128);
+  validateMinimum("number", address?.number, 1);
+}
+
+function validateOrderItem(orderItem) {
+  validateMinimum("price_in_cents", orderItem?.item?.price_in_cents, 1);
+  validateLength("name", orderItem?.item?.name, 1, 128);
+  validateMinimum("quantity", orderItem?.quantity, 1);
+}
+
+function validateLength(name, value, min, max) {
+  const length = typeof value === "string" ? [...value].length : 0;
+  if (length < min || length > max) {
+    throw new Error(`expected ${name} to have a length between ${min} and ${max}, but was ${length}`);
+  }
+}
+
+function validateMinimum(name, value, min) {
+  if (!(value >= min)) {
+    throw new Error(`expected ${name} to be at least ${min}`);
+  }
+}
@@ -0,0 +1,7 @@
+import { getCollection } from "./db.js";
+
+/** Deletes the invoice with the given ID and returns the number of deleted invoices. */
+export async function deleteInvoiceById(id) {
+  const result = await getCollection().deleteOne({ _id: id });
+  return result.deletedCount;
+}
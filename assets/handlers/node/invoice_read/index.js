@@ -0,0 +1,6 @@
+import { getCollection } from "./db.js";
+
+/** Returns the invoice with the given ID, or null if there is no such invoice. */
+export async function readInvoiceById(id) {
+  return getCollection().findOne({ _id: id }, { projection: { _id: 0 } });
+}
@@ -0,0 +1,17 @@
+import { getCollection } from "./db.js";
+import { invoiceUpdate, validateInvoiceUpdate } from "./models.js";
+
+/**
+ * Updates the given properties of the invoice with the given ID and returns the number of modified
+ * invoices.
+ */
+export async function updateInvoice(id, jsonData) {
+  const update = invoiceUpdate(JSON.parse(jsonData.toString("utf8")));
+  validateInvoiceUpdate(update);
+  if (Object.keys(update).length === 0) {
+    return 0;
+  }
+
+  const result = await getCollection().updateOne({ _id: id }, { $set: update });
+  return result.modifiedCount;
+}
@@ -0,0 +1,57 @@
+const UPDATABLE_PROPERTIES = [
+  "items",
+  "billing_address",
+  "shipping_address",
+  "tax_rate",
+  "extra_info",
+  "status",
+];
+
+/** Returns the optional invoice properties of the given request body to update. */
+export function invoiceUpdate(data) {
+  const update = {};
+  for (const property of UPDATABLE_PROPERTIES) {
+    if (data[property] != null) {
+      update[property] = data[property];
+    }
+  }
+  return update;
+}
+
+export function validateInvoiceUpdate(update) {
+  for (const orderItem of update.items ?? []) {
+    validateOrderItem(orderItem);
+  }
+  if (update.billing_address != null) {
+    validateAddress(update.billing_address);
+  }
+  if (update.shipping_address != null) {
+    validateAddress(update.shipping_address);
+  }
+}
+
+function validateAddress(address) {
+  validateLength("first_name", address?.first_name, 2, 64);
+  validateLength("last_name", address?.last_name, 2, 64);
+  validateLength("street", address?.street, 2, 128);
+  validateMinimum("number", address?.number, 1);
+}
+
+function validateOrderItem(orderItem) {
+  validateMinimum("price_in_cents", orderItem?.item?.price_in_cents, 1);
+  validateLength("name", orderItem?.item?.name, 1, 128);
+  validateMinimum("quantity", orderItem?.quantity, 1);
+}
+
+function validateLength(name, value, min, max) {
+  const length = typeof value === "string" ? [...value].length : 0;
+  if (length < min || length > max) {
+    throw new Error(`expected ${name} to have a length between ${min} and ${max}, but was ${length}`);
+  }
+}
+
+function validateMinimum(name, value, min) {
+  if (!(value >= min)) {
+    throw new Error(`expected ${name} to be at least ${min}`);
+  }
+}
@@ -0,0 +1,43 @@
+/** Inverts a random matrix of the given size. */
+export function invertRandomMatrix(size) {
+  if (!Number.isInteger(size) || size < 1) {
+    throw new Error(`Matrix size must be greater or equal to \`1\`, but was ${size}.`);
+  }
+  const n = size;
+
+  const matrix = Array.from({ length: n }, () => Array.from({ length: n }, () => Math.random()));
+  const inverse = Array.from({ length: n }, (_, i) => Array.from({ length: n }, (_, j) => (i === j ? 1 : 0)));
+
+  // Gauss-Jordan elimination with partial pivoting
+  for (let col = 0; col < n; col++) {
+    let pivot = col;
+    for (let row = col + 1; row < n; row++) {
+      if (Math.abs(matrix[row][col]) > Math.abs(matrix[pivot][col])) {
+        pivot = row;
+      }
+    }
+    if (matrix[pivot][col] === 0) {
+      throw new Error("matrix is not invertible");
+    }
+    [matrix[col], matrix[pivot]] = [matrix[pivot], matrix[col]];
+    [inverse[col], inverse[pivot]] = [inverse[pivot], inverse[col]];
+
+    const scale = matrix[col][col];
+    for (let j = 0; j < n; j++) {
+      matrix[col][j] /= scale;
+      inverse[col][j] /= scale;
+    }
+    for (let row = 0; row < n; row++) {
+      if (row === col) {
+        continue;
+      }
+      const factor = matrix[row][col];
+      for (let j = 0; j < n; j++) {
+        matrix[row][j] -= factor * matrix[col][j];
+        inverse[row][j] -= factor * inverse[col][j];
+      }
+    }
+  }
+
+  return inverse;
+}
@@ -0,0 +1,34 @@
+/**
+ * An incremental sieve of Eratosthenes, which maps the next composite numbers to their prime
+ * factors.
+ */
+class Sieve {
+  q = 1;
+  seen = new Map();
+
+  next() {
+    for (;;) {
+      this.q++;
+      const factors = this.seen.get(this.q);
+      if (factors === undefined) {
+        this.seen.set(this.q * this.q, [this.q]);
+        return this.q;
+      }
+      for (const p of factors) {
+        const multiples = this.seen.get(this.q + p);
+        if (multiples === undefined) {
+          this.seen.set(this.q + p, [p]);
+        } else {
+          multiples.push(p);
+        }
+      }
+      this.seen.delete(this.q);
+    }
+  }
+}
+
+/** Generates the first n prime numbers. */
+export function generateFirstPrimes(n) {
+  const sieve = new Sieve();
+  return Array.from({ length: n }, () => sieve.next());
+}
@@ -0,0 +1,13 @@
+import { MongoClient } from "mongodb";
+
+const DATABASE = "user_db";
+const COLLECTION = "user_collection";
+
+let client;
+
+/** Returns the collection, whose client connects on first use. */
+export function getCollection() {
+  const { DB_MONGO_USER, DB_MONGO_PASSWORD, DB_MONGO_HOST, DB_MONGO_PORT } = process.env;
+  client ??= new MongoClient(`mongodb://${DB_MONGO_USER}:${DB_MONGO_PASSWORD}@${DB_MONGO_HOST}:${DB_MONGO_PORT}`);
+  return client.db(DATABASE).collection(COLLECTION);
+}
@@ -0,0 +1,24 @@
+import { getCollection } from "./db.js";
+import { hashPassword } from "./hash.js";
+
+/** Inserts the user of the given JSON data and returns the ID of the inserted user. */
+export async function createUser(jsonData) {
+  const data = JSON.parse(jsonData.toString("utf8"));
+  validateLength("username", data.username, 3, 64);
+  validateLength("email", data.email, 3, 64);
+
+  const result = await getCollection().insertOne({
+    username: data.username,
+    email: data.email,
+    password_hash: await hashPassword(String(data.password ?? "")),
+    created_at: new Date(),
+  });
+  return String(result.insertedId);
+}
+
+function validateLength(name, value, min, max) {
+  const length = typeof value === "string" ? [...value].length : 0;
+  if (length < min || length > max) {
+    throw new Error(`expected ${name} to have a length between ${min} and ${max}, but was ${length}`);
+  }
+}
@@ -0,0 +1,7 @@
+import { getCollection } from "./db.js";
+
+/** Deletes the user with the given ID and returns the number of deleted users. */
+export async function deleteUserById(id) {
+  const result = await getCollection().deleteOne({ _id: id });
+  return result.deletedCount;
+}
@@ -0,0 +1,9 @@
+import { getCollection } from "./db.js";
+
+/** Returns the user with the given ID without its password hash, or null if there is no such user. */
+export async function readUserById(id) {
+  return getCollection().findOne(
+    { _id: id },
+    { projection: { _id: 0, username: 1, email: 1, created_at: 1 } },
+  );
+}
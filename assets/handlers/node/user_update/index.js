@@ -0,0 +1,36 @@
+import { getCollection } from "./db.js";
+import { hashPassword } from "./hash.js";
+
+/**
+ * Updates the given properties of the user with the given ID and returns the number of modified
+ * users.
+ */
+export async function updateUserById(id, jsonData) {
+  const data = JSON.parse(jsonData.toString("utf8"));
+
+  const update = {};
+  if (data.username != null) {
+    validateLength("username", data.username, 3, 64);
+    update.username = data.username;
+  }
+  if (data.email != null) {
+    validateLength("email", data.email, 3, 64);
+    update.email = data.email;
+  }
+  if (data.password != null) {
+    update.password_hash = await hashPassword(String(data.password));
+  }
+  if (Object.keys(update).length === 0) {
+    return 0;
+  }
+
+  const result = await getCollection().updateOne({ _id: id }, { $set: update });
+  return result.modifiedCount;
+}
+
+function validateLength(name, value, min, max) {
+  const length = typeof value === "string" ? [...value].length : 0;
+  if (length < min || length > max) {
+    throw new Error(`expected ${name} to have a length between ${min} and ${max}, but was ${length}`);
+  }
+}
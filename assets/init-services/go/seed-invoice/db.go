@@ -0,0 +1,30 @@
+package main
+
+import (
+	"context"
+	"fmt"
+	"os"
+
+	"go.mongodb.org/mongo-driver/mongo"
+	"go.mongodb.org/mongo-driver/mongo/options"
+)
+
+const (
+	invoiceDB         = "invoice_db"
+	invoiceCollection = "invoice_collection"
+)
+
+func getCollection(ctx context.Context) (*mongo.Collection, error) {
+	uri := fmt.Sprintf(
+		"mongodb://%s:%s@%s:%s",
+		os.Getenv("DB_MONGO_USER"),
+		os.Getenv("DB_MONGO_PASSWORD"),
+		os.Getenv("DB_MONGO_HOST"),
+		os.Getenv("DB_MONGO_PORT"),
+	)
+	client, err := mongo.Connect(ctx, options.Client().ApplyURI(uri))
+	if err != nil {
+		return nil, err
+	}
+	return client.Database(invoiceDB).Collection(invoiceCollection), nil
+}
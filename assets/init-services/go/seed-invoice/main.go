@@ -0,0 +1,33 @@
+package main
+
+import (
+	"context"
+	"log"
+	"os"
+	"strconv"
+)
+
+const batchSize = 50000
+
+func main() {
+	seedCount, err := strconv.Atoi(os.Getenv("MG_SEED_COUNT"))
+	if err != nil {
+		seedCount = 0
+	}
+
+	ctx := context.Background()
+	collection, err := getCollection(ctx)
+	if err != nil {
+		log.Fatal(err)
+	}
+	invoices := make([]any, 0, batchSize)
+	for id := 1; id <= seedCount; id++ {
+		invoices = append(invoices, newInvoice(int64(id)))
+		if len(invoices) == batchSize || id == seedCount {
+			if _, err := collection.InsertMany(ctx, invoices); err != nil {
+				log.Fatal(err)
+			}
+			invoices = invoices[:0]
+		}
+	}
+}
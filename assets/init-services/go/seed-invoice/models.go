@@ -0,0 +1,81 @@
+package main
+
+import "time"
+
+// Address is the billing or shipping address of an invoice.
+type Address struct {
+	FirstName string `bson:"first_name"`
+	LastName  string `bson:"last_name"`
+	Street    string `bson:"street"`
+	Number    uint32 `bson:"number"`
+	ZipCode   uint32 `bson:"zip_code"`
+	City      string `bson:"city"`
+	Country   string `bson:"country"`
+}
+
+// Item is a purchasable item.
+type Item struct {
+	PriceInCents uint64 `bson:"price_in_cents"`
+	Name         string `bson:"name"`
+}
+
+// OrderItem is an ordered quantity of an item.
+type OrderItem struct {
+	Item     Item   `bson:"item"`
+	Quantity uint64 `bson:"quantity"`
+}
+
+// Invoice is the seeded invoice entity.
+type Invoice struct {
+	ID              int64       `bson:"_id"`
+	Items           []OrderItem `bson:"items"`
+	BillingAddress  Address     `bson:"billing_address"`
+	ShippingAddress Address     `bson:"shipping_address"`
+	UserID          string      `bson:"user_id"`
+	TaxRate         float32     `bson:"tax_rate"`
+	IssuedAt        time.Time   `bson:"issued_at"`
+	ExtraInfo       string      `bson:"extra_info"`
+	Status          string      `bson:"status"`
+	InvoiceNumber   string      `bson:"invoice_number"`
+}
+
+func newAddress() Address {
+	return Address{
+		FirstName: randomString(2, 64),
+		LastName:  randomString(2, 64),
+		Street:    randomString(2, 128),
+		Number:    uint32(randomInt(1, 2000)),
+		ZipCode:   uint32(randomInt(1000, 99999)),
+		City:      randomString(3, 64),
+		Country:   randomString(3, 64),
+	}
+}
+
+func newOrderItem() OrderItem {
+	return OrderItem{
+		Item: Item{
+			PriceInCents: uint64(randomInt(1, 1000000000)),
+			Name:         randomString(1, 128),
+		},
+		Quantity: uint64(randomInt(1, 10000)),
+	}
+}
+
+func newInvoice(id int64) Invoice {
+	items := make([]OrderItem, randomInt(1, 100))
+	for i := range items {
+		items[i] = newOrderItem()
+	}
+	return Invoice{
+		ID:              id,
+		Items:           items,
+		BillingAddress:  newAddress(),
+		ShippingAddress: newAddress(),
+		UserID:          randomString(10, 24),
+		TaxRate:         0.15,
+		IssuedAt:        time.Now().UTC(),
+		ExtraInfo:       randomString(0, 512),
+		Status:          "OPEN",
+		InvoiceNumber:   randomString(10, 13),
+	}
+}
@@ -0,0 +1,17 @@
+package main
+
+import "math/rand"
+
+const alphanumeric = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789"
+
+func randomString(minLength int, maxLength int) string {
+	value := make([]byte, randomInt(minLength, maxLength))
+	for i := range value {
+		value[i] = alphanumeric[rand.Intn(len(alphanumeric))]
+	}
+	return string(value)
+}
+
+func randomInt(min int, max int) int {
+	return min + rand.Intn(max-min)
+}
@@ -0,0 +1,33 @@
+package main
+
+import (
+	"context"
+	"log"
+	"os"
+	"strconv"
+)
+
+const batchSize = 50000
+
+func main() {
+	seedCount, err := strconv.Atoi(os.Getenv("MG_SEED_COUNT"))
+	if err != nil {
+		seedCount = 0
+	}
+
+	ctx := context.Background()
+	collection, err := getCollection(ctx)
+	if err != nil {
+		log.Fatal(err)
+	}
+	users := make([]any, 0, batchSize)
+	for id := 1; id <= seedCount; id++ {
+		users = append(users, newUser(int64(id)))
+		if len(users) == batchSize || id == seedCount {
+			if _, err := collection.InsertMany(ctx, users); err != nil {
+				log.Fatal(err)
+			}
+			users = users[:0]
+		}
+	}
+}
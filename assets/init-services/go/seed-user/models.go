@@ -0,0 +1,26 @@
+package main
+
+import "time"
+
+// User is the seeded user entity.
+type User struct {
+	ID           int64     `bson:"_id"`
+	Username     string    `bson:"username"`
+	Email        string    `bson:"email"`
+	PasswordHash []byte    `bson:"password_hash"`
+	CreatedAt    time.Time `bson:"created_at"`
+}
+
+func newUser(id int64) User {
+	passwordHash := make([]byte, 97)
+	for i := range passwordHash {
+		passwordHash[i] = byte(randomInt(0, 255))
+	}
+	return User{
+		ID:           id,
+		Username:     randomString(3, 64),
+		Email:        randomString(3, 64),
+		PasswordHash: passwordHash,
+		CreatedAt:    time.Now().UTC(),
+	}
+}
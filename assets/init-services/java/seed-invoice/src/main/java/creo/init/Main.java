@@ -0,0 +1,35 @@
+package creo.init;
+
+import java.util.ArrayList;
+import java.util.List;
+
+import com.mongodb.client.MongoClient;
+import com.mongodb.client.MongoCollection;
+import org.bson.Document;
+
+public final class Main {
+    private static final int BATCH_SIZE = 50000;
+
+    private Main() {}
+
+    public static void main(String[] args) {
+        int seedCount;
+        try {
+            seedCount = Integer.parseInt(System.getenv("MG_SEED_COUNT"));
+        } catch (NumberFormatException e) {
+            seedCount = 0;
+        }
+
+        try (MongoClient client = Db.connect()) {
+            MongoCollection<Document> collection = Db.getCollection(client);
+            List<Document> invoices = new ArrayList<>(Math.min(seedCount, BATCH_SIZE));
+            for (long id = 1; id <= seedCount; id++) {
+                invoices.add(Models.newInvoice(id));
+                if (invoices.size() == BATCH_SIZE || id == seedCount) {
+                    collection.insertMany(invoices);
+                    invoices.clear();
+                }
+            }
+        }
+    }
+}
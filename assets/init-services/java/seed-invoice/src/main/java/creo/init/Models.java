@@ -0,0 +1,51 @@
+package creo.init;
+
+import static creo.init.Random.randomInt;
+import static creo.init.Random.randomString;
+
+import java.util.ArrayList;
+import java.util.Date;
+import java.util.List;
+
+import org.bson.Document;
+
+final class Models {
+    private Models() {}
+
+    /** Returns a random billing or shipping address of an invoice. */
+    static Document newAddress() {
+        return new Document("first_name", randomString(2, 64))
+                .append("last_name", randomString(2, 64))
+                .append("street", randomString(2, 128))
+                .append("number", (long) randomInt(1, 2000))
+                .append("zip_code", (long) randomInt(1000, 99999))
+                .append("city", randomString(3, 64))
+                .append("country", randomString(3, 64));
+    }
+
+    /** Returns a random ordered quantity of a purchasable item. */
+    static Document newOrderItem() {
+        Document item = new Document("price_in_cents", (long) randomInt(1, 1000000000))
+                .append("name", randomString(1, 128));
+        return new Document("item", item).append("quantity", (long) randomInt(1, 10000));
+    }
+
+    /** Returns a seeded invoice entity with the given ID. */
+    static Document newInvoice(long id) {
+        int itemCount = randomInt(1, 100);
+        List<Document> items = new ArrayList<>(itemCount);
+        for (int i = 0; i < itemCount; i++) {
+            items.add(newOrderItem());
+        }
+        return new Document("_id", id)
+                .append("items", items)
+                .append("billing_address", newAddress())
+                .append("shipping_address", newAddress())
+                .append("user_id", randomString(10, 24))
+                .append("tax_rate", 0.15)
+                .append("issued_at", new Date())
+                .append("extra_info", randomString(0, 512))
+                .append("status", "OPEN")
+                .append("invoice_number", randomString(10, 13));
+    }
+}
@@ -0,0 +1,22 @@
+package creo.init;
+
+import java.util.concurrent.ThreadLocalRandom;
+
+final class Random {
+    private static final String ALPHANUMERIC =
+            "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+    private Random() {}
+
+    static String randomString(int minLength, int maxLength) {
+        char[] value = new char[randomInt(minLength, maxLength)];
+        for (int i = 0; i < value.length; i++) {
+            value[i] = ALPHANUMERIC.charAt(ThreadLocalRandom.current().nextInt(ALPHANUMERIC.length()));
+        }
+        return new String(value);
+    }
+
+    static int randomInt(int min, int max) {
+        return ThreadLocalRandom.current().nextInt(min, max);
+    }
+}
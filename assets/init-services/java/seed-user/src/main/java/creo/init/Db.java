@@ -0,0 +1,26 @@
+package creo.init;
+
+import com.mongodb.client.MongoClient;
+import com.mongodb.client.MongoClients;
+import com.mongodb.client.MongoCollection;
+import org.bson.Document;
+
+final class Db {
+    private static final String DATABASE = "user_db";
+    private static final String COLLECTION = "user_collection";
+
+    private Db() {}
+
+    static MongoClient connect() {
+        return MongoClients.create(String.format(
+                "mongodb://%s:%s@%s:%s",
+                System.getenv("DB_MONGO_USER"),
+                System.getenv("DB_MONGO_PASSWORD"),
+                System.getenv("DB_MONGO_HOST"),
+                System.getenv("DB_MONGO_PORT")));
+    }
+
+    static MongoCollection<Document> getCollection(MongoClient client) {
+        return client.getDatabase(DATABASE).getCollection(COLLECTION);
+    }
+}
@@ -0,0 +1,25 @@
+package creo.init;
+
+import static creo.init.Random.randomInt;
+import static creo.init.Random.randomString;
+
+import java.util.Date;
+
+import org.bson.Document;
+
+final class Models {
+    private Models() {}
+
+    /** Returns a seeded user entity with the given ID. */
+    static Document newUser(long id) {
+        byte[] passwordHash = new byte[97];
+        for (int i = 0; i < passwordHash.length; i++) {
+            passwordHash[i] = (byte) randomInt(0, 255);
+        }
+        return new Document("_id", id)
+                .append("username", randomString(3, 64))
+                .append("email", randomString(3, 64))
+                .append("password_hash", passwordHash)
+                .append("created_at", new Date());
+    }
+}
@@ -0,0 +1,21 @@
+import { getClient, getCollection } from "./db.js";
+import { newInvoice } from "./models.js";
+
+const BATCH_SIZE = 50000;
+
+const seedCount = Number.parseInt(process.env.MG_SEED_COUNT ?? "", 10) || 0;
+
+const client = getClient();
+try {
+  const collection = getCollection(client);
+  let invoices = [];
+  for (let id = 1; id <= seedCount; id++) {
+    invoices.push(newInvoice(id));
+    if (invoices.length === BATCH_SIZE || id === seedCount) {
+      await collection.insertMany(invoices);
+      invoices = [];
+    }
+  }
+} finally {
+  await client.close();
+}
@@ -0,0 +1,45 @@
+import { randomInt, randomString } from "./random.js";
+
+/** Returns the seeded invoice entity with the given ID. */
+export function newInvoice(id) {
+  const items = [];
+  for (let length = randomInt(1, 100); items.length < length; ) {
+    items.push(newOrderItem());
+  }
+  return {
+    _id: id,
+    items,
+    billing_address: newAddress(),
+    shipping_address: newAddress(),
+    user_id: randomString(10, 24),
+    tax_rate: 0.15,
+    issued_at: new Date(),
+    extra_info: randomString(0, 512),
+    status: "OPEN",
+    invoice_number: randomString(10, 13),
+  };
+}
+
+/** Returns a random billing or shipping address of an invoice. */
+function newAddress() {
+  return {
+    first_name: randomString(2, 64),
+    last_name: randomString(2, 64),
+    street: randomString(2, 128),
+    number: randomInt(1, 2000),
+    zip_code: randomInt(1000, 99999),
+    city: randomString(3, 64),
+    country: randomString(3, 64),
+  };
+}
+
+/** Returns a random ordered quantity of a purchasable item. */
+function newOrderItem() {
+  return {
+    item: {
+      price_in_cents: randomInt(1, 1000000000),
+      name: randomString(1, 128),
+    },
+    quantity: randomInt(1, 10000),
+  };
+}
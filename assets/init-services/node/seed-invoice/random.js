@@ -0,0 +1,13 @@
+const ALPHANUMERIC = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+export function randomString(minLength, maxLength) {
+  let value = "";
+  for (let length = randomInt(minLength, maxLength); length > 0; length--) {
+    value += ALPHANUMERIC[randomInt(0, ALPHANUMERIC.length)];
+  }
+  return value;
+}
+
+export function randomInt(min, max) {
+  return min + Math.floor(Math.random() * (max - min));
+}
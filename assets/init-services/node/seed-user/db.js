@@ -0,0 +1,13 @@
+import { MongoClient } from "mongodb";
+
+const DATABASE = "user_db";
+const COLLECTION = "user_collection";
+
+export function getClient() {
+  const { DB_MONGO_USER, DB_MONGO_PASSWORD, DB_MONGO_HOST, DB_MONGO_PORT } = process.env;
+  return new MongoClient(`mongodb://${DB_MONGO_USER}:${DB_MONGO_PASSWORD}@${DB_MONGO_HOST}:${DB_MONGO_PORT}`);
+}
+
+export function getCollection(client) {
+  return client.db(DATABASE).collection(COLLECTION);
+}
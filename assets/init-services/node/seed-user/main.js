@@ -0,0 +1,21 @@
+import { getClient, getCollection } from "./db.js";
+import { newUser } from "./models.js";
+
+const BATCH_SIZE = 50000;
+
+const seedCount = Number.parseInt(process.env.MG_SEED_COUNT ?? "", 10) || 0;
+
+const client = getClient();
+try {
+  const collection = getCollection(client);
+  let users = [];
+  for (let id = 1; id <= seedCount; id++) {
+    users.push(newUser(id));
+    if (users.length === BATCH_SIZE || id === seedCount) {
+      await collection.insertMany(users);
+      users = [];
+    }
+  }
+} finally {
+  await client.close();
+}
@@ -0,0 +1,16 @@
+import { randomInt, randomString } from "./random.js";
+
+/** Returns the seeded user entity with the given ID. */
+export function newUser(id) {
+  const passwordHash = Buffer.alloc(97);
+  for (let i = 0; i < passwordHash.length; i++) {
+    passwordHash[i] = randomInt(0, 255);
+  }
+  return {
+    _id: id,
+    username: randomString(3, 64),
+    email: randomString(3, 64),
+    password_hash: passwordHash,
+    created_at: new Date(),
+  };
+}
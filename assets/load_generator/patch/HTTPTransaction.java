@@ -0,0 +1,191 @@
+/*
+ * Patched HTTPTransaction of the HTTP Load Generator (https://github.com/joakimkistowski/HTTP-Load-Generator),
+ * licensed under the Apache License 2.0.
+ *
+ * In addition to GET, POST and PUT requests, the patched transaction issues requests with any HTTP method and custom
+ * headers. A request line of the Lua script has the format
+ *
+ *     [METHOD]url[HEADER]name:value[HEADER]name:value[JSON]body
+ *
+ * where the method defaults to GET and the headers and the JSON body are optional. The bundled httploadgenerator.jar
+ * contains the compiled class, which is rebuilt with:
+ *
+ *     javac --release 17 -cp httploadgenerator.jar -d build patch/HTTPTransaction.java
+ *     jar uf httploadgenerator.jar -C build tools/descartes/dlim/httploadgenerator/http
+ */
+package tools.descartes.dlim.httploadgenerator.http;
+
+import java.util.Locale;
+import java.util.concurrent.CancellationException;
+import java.util.concurrent.ExecutionException;
+import java.util.concurrent.TimeUnit;
+import java.util.concurrent.TimeoutException;
+import java.util.logging.Level;
+import java.util.logging.Logger;
+import java.util.regex.Pattern;
+
+import org.eclipse.jetty.client.ContentResponse;
+import org.eclipse.jetty.client.Request;
+import org.eclipse.jetty.client.StringRequestContent;
+
+import tools.descartes.dlim.httploadgenerator.generator.ResultTracker;
+import tools.descartes.dlim.httploadgenerator.generator.ResultTracker.TransactionState;
+import tools.descartes.dlim.httploadgenerator.transaction.Transaction;
+import tools.descartes.dlim.httploadgenerator.transaction.TransactionQueueSingleton;
+
+public class HTTPTransaction extends Transaction {
+
+	private static final String HEADER_SIGNAL = "[HEADER]";
+	private static final String JSON_SIGNAL = "[JSON]";
+
+	private static final Logger LOG = Logger.getLogger(HTTPTransaction.class.getName());
+
+	public HTTPTransactionResult process(HTTPInputGenerator generator) {
+		long processStartTime = System.currentTimeMillis();
+		long startTime = System.nanoTime();
+		int requestNum = generator.getCurrentCallNum();
+		if (generator.getTimeout() > 0 && processStartTime - getStartTime() > generator.getTimeout()) {
+			LOG.warning("Wait time in queue too long. " + (processStartTime - getStartTime())
+					+ " ms passed before transaction was even started.");
+			return new HTTPTransactionResult(getTargetTime(), TransactionState.DROPPED, requestNum);
+		}
+
+		String input = generator.getNextInput().trim();
+		requestNum = generator.getCurrentCallNum();
+		String method = "GET";
+		if (input.startsWith("[") && !input.startsWith(HEADER_SIGNAL) && !input.startsWith(JSON_SIGNAL)) {
+			int end = input.indexOf(']');
+			method = input.substring(1, end).toUpperCase(Locale.ROOT);
+			input = input.substring(end + 1);
+		}
+		String[] contents = input.split(Pattern.quote(JSON_SIGNAL), 2);
+		String[] parts = contents[0].split(Pattern.quote(HEADER_SIGNAL));
+		String url = parts[0].trim();
+
+		Request request = generator.initializeHTTPRequest(url, "GET").method(method);
+		for (int i = 1; i < parts.length; i++) {
+			int separator = parts[i].indexOf(':');
+			if (separator < 0) {
+				continue;
+			}
+			String name = parts[i].substring(0, separator).trim();
+			String value = parts[i].substring(separator + 1).trim();
+			request.headers(headers -> headers.add(name, value));
+		}
+		if (contents.length == 2) {
+			request.body(new StringRequestContent("application/json", contents[1]));
+		}
+
+		HTTPTransactionResult result = new HTTPTransactionResult(getTargetTime(), TransactionState.SUCCESS,
+				requestNum);
+		result.setMethod(method);
+		result.setRequestURI(url);
+		try {
+			ContentResponse response = request.send();
+			if (response.getStatus() >= 400) {
+				long responseTime = TimeUnit.NANOSECONDS.toMillis(System.nanoTime() - startTime);
+				generator.revertLastCall();
+				LOG.log(Level.FINEST, "Received error response code: " + response.getStatus());
+				result.setTransactionState(TransactionState.FAILED);
+				result.setResponseTime(responseTime);
+			} else {
+				String responseContent = response.getContentAsString();
+				long responseTime = TimeUnit.NANOSECONDS.toMillis(System.nanoTime() - startTime);
+				generator.resetHTMLFunctions(responseContent);
+				result.setResponseTime(responseTime);
+			}
+		} catch (TimeoutException e) {
+			generator.revertLastCall();
+			result.setResponseTime(generator.getTimeout());
+			result.setTransactionState(TransactionState.TIMEOUT);
+			LOG.warning("TimeoutException: " + e.getMessage());
+		} catch (ExecutionException e) {
+			long responseTime = TimeUnit.NANOSECONDS.toMillis(System.nanoTime() - startTime);
+			if (e.getCause() == null || !(e.getCause() instanceof TimeoutException)) {
+				LOG.log(Level.SEVERE, "ExecutionException in call for URL: " + url + "; Cause: " + e.getCause());
+			}
+			generator.revertLastCall();
+			result.setTransactionState(TransactionState.FAILED);
+			result.setResponseTime(responseTime);
+		} catch (CancellationException e) {
+			long responseTime = TimeUnit.NANOSECONDS.toMillis(System.nanoTime() - startTime);
+			LOG.log(Level.SEVERE, "CancellationException: " + url + "; " + e.getMessage());
+			generator.revertLastCall();
+			result.setTransactionState(TransactionState.FAILED);
+			result.setResponseTime(responseTime);
+		} catch (InterruptedException e) {
+			long responseTime = TimeUnit.NANOSECONDS.toMillis(System.nanoTime() - startTime);
+			LOG.log(Level.SEVERE, "InterruptedException: " + e.getMessage());
+			generator.revertLastCall();
+			result.setTransactionState(TransactionState.FAILED);
+			result.setResponseTime(responseTime);
+		}
+		return result;
+	}
+
+	@Override
+	public void run() {
+		HTTPInputGenerator generator = HTTPInputGeneratorPool.getPool().takeFromPool();
+		HTTPTransactionResult result = process(generator);
+		ResultTracker.TRACKER.logTransaction(result);
+		HTTPInputGeneratorPool.getPool().releaseBackToPool(generator);
+		TransactionQueueSingleton transactionQueue = TransactionQueueSingleton.getInstance();
+		transactionQueue.addQueueElement(this);
+	}
+
+	public class HTTPTransactionResult {
+		private long responseTime = 0;
+		private String requestURI = "";
+		private String method = "";
+		private double transactionTargetStartTime;
+		private TransactionState transactionState;
+		private int requestNum;
+
+		public HTTPTransactionResult(double transactionTargetStartTime, TransactionState transactionState,
+				int requestNum) {
+			this.transactionTargetStartTime = transactionTargetStartTime;
+			this.transactionState = transactionState;
+			this.requestNum = requestNum;
+		}
+
+		public int getRequestNum() {
+			return requestNum;
+		}
+
+		public double getTransactionTargetStartTime() {
+			return transactionTargetStartTime;
+		}
+
+		public TransactionState getTransactionState() {
+			return transactionState;
+		}
+
+		public void setTransactionState(TransactionState transactionState) {
+			this.transactionState = transactionState;
+		}
+
+		public long getResponseTime() {
+			return responseTime;
+		}
+
+		public void setResponseTime(long responseTime) {
+			this.responseTime = responseTime;
+		}
+
+		public String getRequestURI() {
+			return requestURI;
+		}
+
+		public void setRequestURI(String requestURI) {
+			this.requestURI = requestURI;
+		}
+
+		public String getMethod() {
+			return method;
+		}
+
+		public void setMethod(String method) {
+			this.method = method;
+		}
+	}
+}
@@ -1,14 +1,28 @@
-use crate::{graph::MicroServiceIndex, programming_language::ProgrammingLanguage};
+use crate::{
+    graph::MicroServiceIndex,
+    programming_language::{Framework, ProgrammingLanguage},
+};
 
 pub struct MicroService {
     pub id: MicroServiceIndex,
     pub language: ProgrammingLanguage,
+    pub framework: Framework,
     pub port: u32,
 }
 
 impl MicroService {
-    pub fn new(id: MicroServiceIndex, language: ProgrammingLanguage, port: u32) -> Self {
-        Self { id, language, port }
+    pub fn new(
+        id: MicroServiceIndex,
+        language: ProgrammingLanguage,
+        framework: Framework,
+        port: u32,
+    ) -> Self {
+        Self {
+            id,
+            language,
+            framework,
+            port,
+        }
     }
 
     pub fn as_dir_name(&self, digits: usize) -> String {
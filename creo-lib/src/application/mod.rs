@@ -5,4 +5,6 @@ mod service_call;
 pub use endpoint::Endpoint;
 pub use micro_service::get_host;
 pub use micro_service::MicroService;
-pub use service_call::ServiceCallEdge;
+pub use service_call::{
+    deserialize_timeout, CallMode, CallPolicy, CallSemantics, FailurePolicy, ServiceCallEdge,
+};
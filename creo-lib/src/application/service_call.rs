@@ -4,10 +4,147 @@ use crate::graph::EndpointIndex;
 pub struct ServiceCallEdge {
     pub source: EndpointIndex,
     pub target: EndpointIndex,
+    /// How the source endpoint issues the call.
+    pub semantics: CallSemantics,
 }
 
 impl ServiceCallEdge {
     pub fn new(source: EndpointIndex, target: EndpointIndex) -> Self {
-        Self { source, target }
+        Self {
+            source,
+            target,
+            semantics: CallSemantics::default(),
+        }
+    }
+
+    pub fn with_semantics(mut self, semantics: CallSemantics) -> Self {
+        self.semantics = semantics;
+        self
+    }
+}
+
+/// [`CallMode`] specifies, whether a service call waits for the preceding calls of its endpoint.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CallMode {
+    /// The call is issued concurrently with all other parallel calls of the endpoint.
+    #[default]
+    Parallel,
+    /// The call is issued after the preceding sequential calls of the endpoint succeeded.
+    Sequential,
+}
+
+impl std::fmt::Display for CallMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CallMode::Parallel => write!(f, "parallel"),
+            CallMode::Sequential => write!(f, "sequential"),
+        }
+    }
+}
+
+/// [`CallSemantics`] describes how an endpoint issues a single outgoing service call.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CallSemantics {
+    /// Whether the call is issued sequentially or in parallel.
+    pub mode: CallMode,
+    /// The probability with which the call is issued per request, in `(0, 1]`.
+    pub probability: f64,
+    /// The number of times the call is issued, if it is issued at all.
+    pub repetitions: usize,
+    /// How the call handles slow and failed requests.
+    pub policy: CallPolicy,
+}
+
+impl Default for CallSemantics {
+    fn default() -> Self {
+        Self {
+            mode: CallMode::default(),
+            probability: 1.0,
+            repetitions: 1,
+            policy: CallPolicy::default(),
+        }
+    }
+}
+
+/// [`FailurePolicy`] specifies, how an endpoint responds if one of its service calls failed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailurePolicy {
+    /// The endpoint logs the failed call, continues with the remaining calls and responds
+    /// successfully.
+    #[default]
+    Degrade,
+    /// The endpoint responds with `502 Bad Gateway`.
+    FailFast,
+}
+
+impl std::fmt::Display for FailurePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FailurePolicy::Degrade => write!(f, "degrade"),
+            FailurePolicy::FailFast => write!(f, "fail_fast"),
+        }
+    }
+}
+
+/// [`CallPolicy`] describes how a service call handles slow and failed requests.
+///
+/// A request fails, if it cannot be sent, responds with an error status code or exceeds the
+/// timeout. A failed request is retried up to `retries` times, where the `i`-th retry waits for
+/// `backoff * 2^i` milliseconds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct CallPolicy {
+    /// The optional number of milliseconds after which a request times out.
+    #[serde(deserialize_with = "deserialize_timeout")]
+    pub timeout: Option<u64>,
+    /// The number of times a failed request is retried.
+    pub retries: usize,
+    /// The number of milliseconds to wait before the first retry.
+    pub backoff: u64,
+    /// How the calling endpoint responds, if the call failed.
+    pub on_failure: FailurePolicy,
+}
+
+/// Deserializes and validates the optional timeout of a service call.
+///
+/// The timeout is invalid if it is zero, since no request completes within zero milliseconds.
+pub fn deserialize_timeout<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let this = <Option<u64> as serde::Deserialize>::deserialize(deserializer)?;
+    if this == Some(0) {
+        return Err(serde::de::Error::custom(
+            "expected a positive timeout, but was 0",
+        ));
+    }
+    Ok(this)
+}
+
+impl Default for CallPolicy {
+    fn default() -> Self {
+        Self {
+            timeout: None,
+            retries: 0,
+            backoff: 100,
+            on_failure: FailurePolicy::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::de::FromYamlStr;
+
+    use super::*;
+
+    #[test]
+    fn test_call_policy_rejects_zero_timeout() {
+        let policy = CallPolicy::from_yaml_str("timeout: 500").unwrap();
+        assert_eq!(policy.timeout, Some(500));
+        assert_eq!(policy.retries, 0);
+        assert!(CallPolicy::from_yaml_str("timeout: 0").is_err());
     }
 }
@@ -67,6 +67,12 @@ pub fn create_service_compose_with_build(
         }
     }
     environment.extend(call_services.iter().map(|(k, v)| format!("{}={}", k, v)));
+    if let Some(fault_injection) = application.get_fault_injection() {
+        environment.extend(fault_injection.environment(application, service.id));
+    }
+    if let Some(tracing) = application.get_tracing() {
+        environment.extend(tracing.environment(&service_host));
+    }
 
     let image_name = format!("{}-{}:{}", app_name.as_ref(), service_host, timestamp_tag);
     dct::Compose {
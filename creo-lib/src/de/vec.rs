@@ -37,9 +37,7 @@ impl<T> std::ops::DerefMut for NonEmptyVec<T> {
     }
 }
 
-#[derive(
-    Debug, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Deserialize, serde::Serialize, Default,
-)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Deserialize, serde::Serialize)]
 #[serde(remote = "Self")]
 /// A vector of unique elemets.
 ///
@@ -72,6 +70,12 @@ impl<'de, T: serde::Deserialize<'de> + Hash + Eq> serde::Deserialize<'de> for Un
     }
 }
 
+impl<T> Default for UniqueVec<T> {
+    fn default() -> Self {
+        Self(Vec::default())
+    }
+}
+
 impl<T> std::ops::Deref for UniqueVec<T> {
     type Target = Vec<T>;
 
@@ -0,0 +1,61 @@
+use std::fmt::Write;
+
+use super::ApplicationModel;
+
+/// Serializes the given application model in the DOT language of Graphviz.
+///
+/// Each microservice is rendered as a cluster containing its endpoints, while each inter-service
+/// call is rendered as an edge between two endpoints.
+pub fn to_dot(model: &ApplicationModel) -> String {
+    let mut dot = String::from("digraph application {\n    node [shape=box];\n");
+    for service in &model.services {
+        let mut label = format!(
+            "{} ({}, {})",
+            service.name, service.language, service.framework
+        );
+        if let Some(service_type) = &service.service_type {
+            write!(label, "\\n{}", service_type).unwrap();
+        }
+        writeln!(dot, "    subgraph cluster_{} {{", service.id).unwrap();
+        writeln!(dot, "        label=\"{}\";", escape(&label)).unwrap();
+        for endpoint in &service.endpoints {
+            writeln!(
+                dot,
+                "        e{} [label=\"{}\\n{}\"];",
+                endpoint.id,
+                escape(&endpoint.path),
+                escape(&endpoint.handler_function)
+            )
+            .unwrap();
+        }
+        dot.push_str("    }\n");
+    }
+    for call in &model.inter_service_calls {
+        writeln!(dot, "    e{} -> e{};", call.source, call.target).unwrap();
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+/// Escapes double quotes in a DOT string literal.
+fn escape(value: &str) -> String {
+    value.replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::tests::example_application;
+
+    #[test]
+    fn test_to_dot() {
+        let dot = to_dot(&ApplicationModel::new(&example_application()));
+
+        assert!(dot.starts_with("digraph application {"));
+        assert!(dot.contains("subgraph cluster_1 {"));
+        assert!(dot.contains("label=\"service-0 (Python, FastAPI)\\nCPU-HIGH (100%)\";"));
+        assert!(dot.contains("e2 [label=\"/endpoint2\\nc\"];"));
+        assert!(dot.contains("e0 -> e2;"));
+        assert!(dot.contains("e1 -> e2;"));
+    }
+}
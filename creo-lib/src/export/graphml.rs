@@ -0,0 +1,98 @@
+use std::fmt::Write;
+
+use super::ApplicationModel;
+
+/// The node attributes of the GraphML export as `(id, name)` pairs.
+const NODE_KEYS: [(&str, &str); 7] = [
+    ("d0", "service"),
+    ("d1", "language"),
+    ("d2", "service_type"),
+    ("d3", "path"),
+    ("d4", "handler_function"),
+    ("d5", "user_frontend"),
+    ("d6", "framework"),
+];
+
+/// Serializes the given application model in the GraphML format.
+///
+/// Each endpoint is rendered as a node, which carries its microservice and further properties as
+/// attributes, while each inter-service call is rendered as an edge between two endpoints.
+pub fn to_graphml(model: &ApplicationModel) -> String {
+    let mut xml = String::from(concat!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+        "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n"
+    ));
+    for (id, name) in NODE_KEYS {
+        let kind = if name == "user_frontend" {
+            "boolean"
+        } else {
+            "string"
+        };
+        writeln!(
+            xml,
+            "  <key id=\"{}\" for=\"node\" attr.name=\"{}\" attr.type=\"{}\"/>",
+            id, name, kind
+        )
+        .unwrap();
+    }
+    xml.push_str("  <graph id=\"application\" edgedefault=\"directed\">\n");
+    for service in &model.services {
+        let service_type = service
+            .service_type
+            .as_ref()
+            .map(ToString::to_string)
+            .unwrap_or_default();
+        for endpoint in &service.endpoints {
+            let values = [
+                service.name.clone(),
+                service.language.clone(),
+                service_type.clone(),
+                endpoint.path.clone(),
+                endpoint.handler_function.clone(),
+                endpoint.user_frontend.to_string(),
+                service.framework.clone(),
+            ];
+            writeln!(xml, "    <node id=\"e{}\">", endpoint.id).unwrap();
+            for ((id, _), value) in NODE_KEYS.iter().zip(values) {
+                writeln!(xml, "      <data key=\"{}\">{}</data>", id, escape(&value)).unwrap();
+            }
+            xml.push_str("    </node>\n");
+        }
+    }
+    for call in &model.inter_service_calls {
+        writeln!(
+            xml,
+            "    <edge source=\"e{}\" target=\"e{}\"/>",
+            call.source, call.target
+        )
+        .unwrap();
+    }
+    xml.push_str("  </graph>\n</graphml>\n");
+    xml
+}
+
+/// Escapes the special characters of XML.
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::tests::example_application;
+
+    #[test]
+    fn test_to_graphml() {
+        let xml = to_graphml(&ApplicationModel::new(&example_application()));
+
+        assert!(xml.contains("<graph id=\"application\" edgedefault=\"directed\">"));
+        assert_eq!(xml.matches("<node ").count(), 3);
+        assert!(xml.contains("<data key=\"d0\">service-1</data>"));
+        assert!(xml.contains("<data key=\"d3\">/endpoint2</data>"));
+        assert!(xml.contains("<edge source=\"e1\" target=\"e2\"/>"));
+    }
+}
@@ -0,0 +1,315 @@
+mod dot;
+mod graphml;
+
+pub use dot::to_dot;
+pub use graphml::to_graphml;
+
+use crate::{
+    application::{get_host, CallPolicy},
+    graph::{ApplicationGraph, MicroServiceIndex},
+    selection::SelectionExplanation,
+    service_types::{Intensity, ServiceType},
+};
+
+/// [`ApplicationModel`] is a self-contained description of the topology and workload of a
+/// generated application.
+#[derive(serde::Serialize, Debug, PartialEq)]
+pub struct ApplicationModel {
+    /// The microservices of the application.
+    pub services: Vec<ServiceModel>,
+    /// The inter-service calls between the endpoints of the application.
+    pub inter_service_calls: Vec<ServiceCallModel>,
+}
+
+#[derive(serde::Serialize, Debug, PartialEq)]
+pub struct ServiceModel {
+    pub id: usize,
+    /// The host name of the microservice.
+    pub name: String,
+    pub port: u32,
+    pub language: String,
+    /// The web framework, in which the microservice is implemented.
+    pub framework: String,
+    /// The service type of the microservice, if it was generated automatically.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_type: Option<ServiceTypeModel>,
+    pub endpoints: Vec<EndpointModel>,
+}
+
+#[derive(serde::Serialize, Debug, PartialEq)]
+pub struct EndpointModel {
+    pub id: usize,
+    pub path: String,
+    /// The directory name of the handler function of the endpoint.
+    pub handler_function: String,
+    /// Whether the endpoint is called by the users, i.e., not by another endpoint.
+    pub user_frontend: bool,
+}
+
+/// [`SelectionLogEntry`] explains the selection of the handler function of a single endpoint.
+#[derive(serde::Serialize, Debug, PartialEq)]
+pub struct SelectionLogEntry<'a> {
+    pub endpoint: usize,
+    /// The host name of the microservice of the endpoint.
+    pub service: String,
+    #[serde(flatten)]
+    pub explanation: &'a SelectionExplanation,
+}
+
+/// Returns the selection log of the given application, ordered by the endpoint IDs. The log is
+/// empty, if the handler functions were not selected by their distance to a target vector.
+pub fn selection_log(application: &ApplicationGraph) -> Vec<SelectionLogEntry<'_>> {
+    let mut log: Vec<SelectionLogEntry> = application
+        .iter_endpoints()
+        .filter_map(|endpoint| {
+            Some(SelectionLogEntry {
+                endpoint: endpoint.id.0,
+                service: get_host(application.get_service(endpoint.id)),
+                explanation: application.get_selection_explanation(endpoint.id)?,
+            })
+        })
+        .collect();
+    log.sort_by_key(|entry| entry.endpoint);
+    log
+}
+
+#[derive(serde::Serialize, Debug, PartialEq)]
+pub struct ServiceTypeModel {
+    pub fraction: u8,
+    pub properties: Vec<PropertyModel>,
+}
+
+#[derive(serde::Serialize, Debug, PartialEq)]
+pub struct PropertyModel {
+    pub label: String,
+    /// The bucket of the property, if it does not specify a numeric target.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bucket: Option<String>,
+    /// The numeric target utilization of the property, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<f64>,
+    /// The maximum deviation from the numeric target utilization, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tolerance: Option<f64>,
+    pub fraction: u8,
+}
+
+#[derive(serde::Serialize, Debug, PartialEq)]
+pub struct ServiceCallModel {
+    /// The ID of the calling endpoint.
+    pub source: usize,
+    /// The ID of the called endpoint.
+    pub target: usize,
+    /// Whether the call is issued sequentially or in parallel.
+    pub mode: String,
+    /// The probability with which the call is issued per request.
+    pub probability: f64,
+    /// The number of times the call is issued per request.
+    pub repetitions: usize,
+    /// The timeout, retry and failure policy of the call.
+    pub policy: CallPolicy,
+}
+
+impl ApplicationModel {
+    pub fn new(application: &ApplicationGraph) -> Self {
+        let services = application
+            .iter_micro_services()
+            .map(|service| ServiceModel {
+                id: service.id.0,
+                name: get_host(service.id),
+                port: service.port,
+                language: service.language.to_string(),
+                framework: service.framework.to_string(),
+                service_type: application
+                    .get_service_type(service.id)
+                    .map(ServiceTypeModel::from),
+                endpoints: endpoint_models(application, service.id),
+            })
+            .collect();
+        let inter_service_calls = application
+            .iter_endpoints()
+            .flat_map(|endpoint| application.iter_service_calls(endpoint.id))
+            .map(|call| ServiceCallModel {
+                source: call.source.0,
+                target: call.target.0,
+                mode: call.semantics.mode.to_string(),
+                probability: call.semantics.probability,
+                repetitions: call.semantics.repetitions,
+                policy: call.semantics.policy,
+            })
+            .collect();
+
+        Self {
+            services,
+            inter_service_calls,
+        }
+    }
+}
+
+/// Returns the endpoints of the given microservice, ordered by their IDs.
+fn endpoint_models(
+    application: &ApplicationGraph,
+    service: MicroServiceIndex,
+) -> Vec<EndpointModel> {
+    let mut endpoints: Vec<EndpointModel> = application
+        .iter_service_endpoints(service)
+        .map(|endpoint| EndpointModel {
+            id: endpoint.id.0,
+            path: application.get_endpoint_path(endpoint.id),
+            handler_function: endpoint
+                .handler_dir
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            user_frontend: application.is_user_frontend(endpoint.id),
+        })
+        .collect();
+    endpoints.sort_by_key(|endpoint| endpoint.id);
+    endpoints
+}
+
+impl From<&ServiceType> for ServiceTypeModel {
+    fn from(value: &ServiceType) -> Self {
+        Self {
+            fraction: value.fraction,
+            properties: value
+                .properties
+                .iter()
+                .map(|property| {
+                    let (bucket, target) = match &property.intensity {
+                        Intensity::Bucket(bucket) => (Some(bucket.to_string()), None),
+                        Intensity::Target(target) => (None, Some(*target)),
+                    };
+                    PropertyModel {
+                        label: property.label.to_string(),
+                        bucket,
+                        target: target.map(|target| target.value),
+                        tolerance: target.and_then(|target| target.tolerance),
+                        fraction: property.fraction,
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+impl std::fmt::Display for ServiceTypeModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let properties: Vec<String> = self
+            .properties
+            .iter()
+            .map(|p| {
+                let intensity = match (&p.bucket, p.target, p.tolerance) {
+                    (Some(bucket), _, _) => bucket.clone(),
+                    (None, Some(target), Some(tolerance)) => format!("{}±{}", target, tolerance),
+                    (None, Some(target), None) => target.to_string(),
+                    (None, None, _) => String::new(),
+                };
+                format!("{}-{} ({}%)", p.label, intensity, p.fraction)
+            })
+            .collect();
+        f.write_str(&properties.join(", "))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::{
+        application::{CallMode, CallSemantics, FailurePolicy},
+        graph,
+        programming_language::ProgrammingLanguage,
+        service_types,
+    };
+
+    /// Returns an application with two services, where `service-0` calls `service-1` twice.
+    pub(crate) fn example_application() -> ApplicationGraph {
+        let graph = graph::DiGraphBuilder::with_node_and_edge_count(3, 2)
+            .add_nodes(3)
+            .add_edges(&[(0, 2).into(), (1, 2).into()])
+            .build();
+        let coloring = [0, 0, 1]
+            .into_iter()
+            .map(graph::algorithms::coloring::ColorIndex)
+            .collect();
+        let graph = graph::ColoredGraphBuilder::new(graph, coloring, 2).build();
+        let service_type = ServiceType {
+            fraction: 100,
+            properties: vec![service_types::Property {
+                label: service_types::Label::Cpu,
+                fraction: 100,
+                intensity: service_types::Intensity::Bucket(service_types::Bucket::High),
+            }],
+        };
+        ApplicationGraph::new(
+            graph,
+            vec![ProgrammingLanguage::Python(1), ProgrammingLanguage::Rust(1)],
+            30100,
+            vec![
+                "assets/handlers/python/a".into(),
+                "assets/handlers/python/b".into(),
+                "assets/handlers/rust/c".into(),
+            ],
+        )
+        .with_service_types(vec![service_type.clone(), service_type])
+    }
+
+    #[test]
+    fn test_application_model() {
+        let semantics = CallSemantics {
+            mode: CallMode::Sequential,
+            probability: 0.5,
+            repetitions: 3,
+            policy: CallPolicy {
+                timeout: Some(500),
+                retries: 2,
+                backoff: 50,
+                on_failure: FailurePolicy::FailFast,
+            },
+        };
+        let application = example_application().with_call_semantics(
+            [(
+                (graph::EndpointIndex(1), graph::EndpointIndex(2)),
+                semantics,
+            )]
+            .into(),
+        );
+        let model = ApplicationModel::new(&application);
+
+        assert_eq!(model.services.len(), 2);
+        assert_eq!(model.services[0].name, "service-0");
+        assert_eq!(model.services[0].language, "Python");
+        assert_eq!(model.services[0].framework, "FastAPI");
+        assert_eq!(model.services[1].port, 30101);
+        let endpoint = &model.services[1].endpoints[0];
+        assert_eq!(endpoint.id, 2);
+        assert_eq!(endpoint.path, "/endpoint2");
+        assert_eq!(endpoint.handler_function, "c");
+        assert!(!endpoint.user_frontend);
+        assert_eq!(
+            model.services[0].service_type.as_ref().unwrap().to_string(),
+            "CPU-HIGH (100%)"
+        );
+        assert_eq!(
+            model.inter_service_calls,
+            [
+                ServiceCallModel {
+                    source: 0,
+                    target: 2,
+                    mode: "parallel".into(),
+                    probability: 1.0,
+                    repetitions: 1,
+                    policy: CallPolicy::default(),
+                },
+                ServiceCallModel {
+                    source: 1,
+                    target: 2,
+                    mode: "sequential".into(),
+                    probability: 0.5,
+                    repetitions: 3,
+                    policy: semantics.policy,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,254 @@
+use std::collections::BTreeMap;
+
+use crate::graph::{ApplicationGraph, MicroServiceIndex};
+
+/// [`FaultInjection`] describes the faults injected into the microservices of an application.
+///
+/// The faults are passed to the generated microservices as `FAULT_*` environment variables, so
+/// they can also be adjusted without generating the application again.
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default, remote = "Self")]
+pub struct FaultInjection {
+    /// The distribution of the additional latency of each request in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency: Option<LatencyDistribution>,
+    /// The fraction of requests, which are answered with `500 Internal Server Error`.
+    pub error_rate: f64,
+    /// The error rates of individual endpoints indexed by their IDs, overriding `error_rate`.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub endpoint_error_rates: BTreeMap<usize, f64>,
+    /// The additional latency right after the start of a microservice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slow_start: Option<SlowStart>,
+    /// The number of requests after which a microservice crashes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crash_after: Option<u64>,
+}
+
+/// [`LatencyDistribution`] describes the distribution of an injected latency in milliseconds.
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "distribution", rename_all = "snake_case")]
+pub enum LatencyDistribution {
+    /// Every request is delayed by the same latency.
+    Constant { value: f64 },
+    /// The latency is drawn uniformly from `[min, max)`.
+    Uniform { min: f64, max: f64 },
+    /// The latency is drawn from an exponential distribution with the given mean.
+    Exponential { mean: f64 },
+    /// The latency is drawn from a normal distribution, where negative latencies are cut off.
+    Normal { mean: f64, std_dev: f64 },
+}
+
+/// [`SlowStart`] describes an additional latency, which decreases linearly from `latency`
+/// milliseconds at the start of a microservice to zero after `duration` milliseconds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SlowStart {
+    /// The number of milliseconds until the microservice reaches its full speed.
+    pub duration: u64,
+    /// The additional latency in milliseconds right after the start.
+    pub latency: u64,
+}
+
+impl LatencyDistribution {
+    fn name(&self) -> &'static str {
+        match self {
+            LatencyDistribution::Constant { .. } => "constant",
+            LatencyDistribution::Uniform { .. } => "uniform",
+            LatencyDistribution::Exponential { .. } => "exponential",
+            LatencyDistribution::Normal { .. } => "normal",
+        }
+    }
+
+    fn parameters(&self) -> Vec<f64> {
+        match *self {
+            LatencyDistribution::Constant { value } => vec![value],
+            LatencyDistribution::Uniform { min, max } => vec![min, max],
+            LatencyDistribution::Exponential { mean } => vec![mean],
+            LatencyDistribution::Normal { mean, std_dev } => vec![mean, std_dev],
+        }
+    }
+
+    /// Returns the distribution in the `<distribution>:<parameters>` format of the `FAULT_LATENCY`
+    /// environment variable, e.g., `uniform:10:50`.
+    fn as_env_value(&self) -> String {
+        std::iter::once(self.name().to_string())
+            .chain(
+                self.parameters()
+                    .into_iter()
+                    .map(|parameter| parameter.to_string()),
+            )
+            .collect::<Vec<_>>()
+            .join(":")
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if let LatencyDistribution::Uniform { min, max } = self {
+            if min > max {
+                return Err(format!(
+                    "expected the minimum latency {} to be at most the maximum latency {}",
+                    min, max
+                ));
+            }
+        }
+        let parameters = self.parameters();
+        if parameters
+            .iter()
+            .any(|parameter| !parameter.is_finite() || *parameter < 0.0)
+        {
+            return Err(format!(
+                "expected the parameters of the {} latency distribution to be non-negative numbers",
+                self.name()
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl FaultInjection {
+    /// Returns the `FAULT_*` environment variables of the given microservice in the `KEY=VALUE`
+    /// format.
+    pub fn environment(
+        &self,
+        application: &ApplicationGraph,
+        service: MicroServiceIndex,
+    ) -> Vec<String> {
+        let mut environment = Vec::default();
+        if let Some(latency) = &self.latency {
+            environment.push(format!("FAULT_LATENCY={}", latency.as_env_value()));
+        }
+        if self.error_rate > 0.0 {
+            environment.push(format!("FAULT_ERROR_RATE={}", self.error_rate));
+        }
+        for endpoint in application.iter_service_endpoints(service) {
+            if let Some(error_rate) = self.endpoint_error_rates.get(&endpoint.id.0) {
+                environment.push(format!(
+                    "FAULT_ERROR_RATE_{}={}",
+                    application
+                        .get_endpoint_path(endpoint.id)
+                        .trim_start_matches('/')
+                        .to_uppercase(),
+                    error_rate
+                ));
+            }
+        }
+        if let Some(slow_start) = &self.slow_start {
+            environment.push(format!(
+                "FAULT_SLOW_START={}:{}",
+                slow_start.duration, slow_start.latency
+            ));
+        }
+        if let Some(crash_after) = self.crash_after {
+            environment.push(format!("FAULT_CRASH_AFTER={}", crash_after));
+        }
+        environment
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for FaultInjection {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let this = FaultInjection::deserialize(deserializer)?;
+
+        let is_rate = |rate: f64| (0.0..=1.0).contains(&rate);
+        if !is_rate(this.error_rate) {
+            return Err(serde::de::Error::custom(format!(
+                "expected the error rate to be in [0, 1], but got {}",
+                this.error_rate
+            )));
+        }
+        for (endpoint, rate) in &this.endpoint_error_rates {
+            if !is_rate(*rate) {
+                return Err(serde::de::Error::custom(format!(
+                    "expected the error rate of endpoint {} to be in [0, 1], but got {}",
+                    endpoint, rate
+                )));
+            }
+        }
+        if let Some(latency) = &this.latency {
+            latency.validate().map_err(serde::de::Error::custom)?;
+        }
+        if this.crash_after == Some(0) {
+            return Err(serde::de::Error::custom(
+                "expected the microservices to crash after at least 1 request",
+            ));
+        }
+
+        Ok(this)
+    }
+}
+
+impl serde::Serialize for FaultInjection {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        FaultInjection::serialize(self, serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::tests::example_application;
+
+    #[test]
+    fn test_environment() {
+        let faults = FaultInjection {
+            latency: Some(LatencyDistribution::Uniform {
+                min: 10.0,
+                max: 50.5,
+            }),
+            error_rate: 0.01,
+            endpoint_error_rates: BTreeMap::from([(2, 0.5)]),
+            slow_start: Some(SlowStart {
+                duration: 30000,
+                latency: 200,
+            }),
+            crash_after: Some(1000),
+        };
+        let application = example_application();
+
+        assert_eq!(
+            faults.environment(&application, MicroServiceIndex(0)),
+            [
+                "FAULT_LATENCY=uniform:10:50.5",
+                "FAULT_ERROR_RATE=0.01",
+                "FAULT_SLOW_START=30000:200",
+                "FAULT_CRASH_AFTER=1000",
+            ]
+        );
+        assert_eq!(
+            faults.environment(&application, MicroServiceIndex(1)),
+            [
+                "FAULT_LATENCY=uniform:10:50.5",
+                "FAULT_ERROR_RATE=0.01",
+                "FAULT_ERROR_RATE_ENDPOINT2=0.5",
+                "FAULT_SLOW_START=30000:200",
+                "FAULT_CRASH_AFTER=1000",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let faults: FaultInjection = serde_yaml::from_str(
+            "latency: { distribution: exponential, mean: 20 }\nendpoint_error_rates: { 3: 0.2 }",
+        )
+        .expect("should deserialize");
+        assert_eq!(
+            faults.latency,
+            Some(LatencyDistribution::Exponential { mean: 20.0 })
+        );
+        assert_eq!(faults.error_rate, 0.0);
+        assert_eq!(faults.endpoint_error_rates, BTreeMap::from([(3, 0.2)]));
+
+        assert!(serde_yaml::from_str::<FaultInjection>("error_rate: 1.5").is_err());
+        assert!(serde_yaml::from_str::<FaultInjection>(
+            "latency: { distribution: uniform, min: 50, max: 10 }"
+        )
+        .is_err());
+        assert!(serde_yaml::from_str::<FaultInjection>("crash_after: 0").is_err());
+    }
+}
@@ -6,5 +6,7 @@ pub trait FrameworkGenerator {
     fn to_service_calls_generator(&self) -> &dyn template::ServiceCallGenerator;
     fn to_main_generator(&self) -> &dyn template::MainGenerator;
     fn get_framework_requirements(&self) -> Vec<&'static str>;
+    /// Returns the additional requirements of the framework, if distributed tracing is enabled.
+    fn get_tracing_requirements(&self) -> Vec<&'static str>;
     fn get_docker_entrypoint(&self) -> &'static str;
 }
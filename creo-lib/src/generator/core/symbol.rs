@@ -53,6 +53,20 @@ pub(crate) trait SymbolGenerator {
     /// - *function_name* the name of the handler function
     fn generate_handler_function_import(&self, import_path: &str, function_name: &str) -> String;
 
+    /// Generates the expression referring to the given handler function in the router file.
+    ///
+    /// Languages, which import the handler function itself, should return the function name as
+    /// is. Languages, which import the module of the handler function, should qualify the function
+    /// name with the module, e.g. "hash.HashPassword".
+    ///
+    /// # Arguments
+    ///
+    /// - *import_path* the import path of the module/file, in which the handler function is
+    ///   defined
+    /// - *function_name* the name of the handler function
+    fn generate_handler_function_reference(&self, import_path: &str, function_name: &str)
+        -> String;
+
     /// Generates the unique function name for the given, individual service call.
     ///
     /// The returned function name will be used for the function calling the target endpoint of the
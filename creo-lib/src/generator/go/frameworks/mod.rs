@@ -0,0 +1,52 @@
+use crate::{generator::core::FrameworkGenerator, template};
+
+pub mod net_http;
+
+pub enum Frameworks {
+    NetHttp,
+}
+use Frameworks::*;
+
+impl FrameworkGenerator for Frameworks {
+    fn to_faker(&self) -> &dyn template::Fakeable {
+        match self {
+            NetHttp => &net_http::Faker,
+        }
+    }
+
+    fn to_router_generator(&self) -> &dyn template::RouterGenerator {
+        match self {
+            NetHttp => &net_http::RouterGenerator,
+        }
+    }
+
+    fn to_service_calls_generator(&self) -> &dyn template::ServiceCallGenerator {
+        match self {
+            NetHttp => &net_http::ServiceCallGenerator,
+        }
+    }
+
+    fn to_main_generator(&self) -> &dyn template::MainGenerator {
+        match self {
+            NetHttp => &net_http::MainGenerator,
+        }
+    }
+
+    fn get_framework_requirements(&self) -> Vec<&'static str> {
+        match self {
+            NetHttp => net_http::get_framework_dependencies(),
+        }
+    }
+
+    fn get_tracing_requirements(&self) -> Vec<&'static str> {
+        match self {
+            NetHttp => net_http::get_tracing_dependencies(),
+        }
+    }
+
+    fn get_docker_entrypoint(&self) -> &'static str {
+        match self {
+            NetHttp => net_http::DOCKER_ENTRYPOINT,
+        }
+    }
+}
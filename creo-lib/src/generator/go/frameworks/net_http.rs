@@ -0,0 +1,108 @@
+use crate::template;
+
+pub const DOCKER_ENTRYPOINT: &str = r#"["/usr/local/bin/service"]"#;
+
+pub struct Faker;
+
+impl template::Fakeable for Faker {
+    fn get_string_fake(&self, string_validation: &openapiv3::StringType) -> template::FakeFunction {
+        let min_length = string_validation.min_length.unwrap_or(0);
+        let max_length = string_validation
+            .max_length
+            .unwrap_or(template::DEFAULT_MAX_STRING_LENGTH);
+
+        template::FakeFunction::new(
+            "getRandomString".into(),
+            format!("{}, {}", min_length, max_length),
+        )
+    }
+
+    fn get_number_fake(&self, number_validation: &openapiv3::NumberType) -> template::FakeFunction {
+        let min_value = number_validation.minimum.unwrap_or_default();
+        let max_value = number_validation
+            .maximum
+            .unwrap_or(template::DEFAULT_MAX_NUMBER);
+        template::FakeFunction::new(
+            "getRandomNumber".into(),
+            format!("{}, {}", min_value, max_value),
+        )
+    }
+
+    fn get_integer_fake(
+        &self,
+        integer_validation: &openapiv3::IntegerType,
+    ) -> template::FakeFunction {
+        let min_value = integer_validation.minimum.unwrap_or_default();
+        let max_value = integer_validation
+            .maximum
+            .unwrap_or(template::DEFAULT_MAX_INTEGER);
+        template::FakeFunction::new(
+            "getRandomInteger".into(),
+            format!("{}, {}", min_value, max_value),
+        )
+    }
+
+    fn get_object_fake(&self, function_name: &str) -> template::FakeFunction {
+        template::FakeFunction::new(function_name.into(), String::new())
+    }
+
+    fn get_array_fake(&self, function_name: &str) -> template::FakeFunction {
+        template::FakeFunction::new(function_name.into(), String::new())
+    }
+
+    fn get_boolean_fake(
+        &self,
+        _boolean_validation: &openapiv3::BooleanType,
+    ) -> template::FakeFunction {
+        template::FakeFunction::new("getRandomBool".into(), String::new())
+    }
+}
+
+pub struct RouterGenerator;
+
+impl template::RouterGenerator for RouterGenerator {
+    fn create_router_template(&self) -> template::RouterTemplate {
+        template::RouterTemplate {
+            template_dir: "go/nethttp/router",
+            root_template_name: "router",
+        }
+    }
+}
+
+pub struct ServiceCallGenerator;
+
+impl template::ServiceCallGenerator for ServiceCallGenerator {
+    fn create_service_call_template(&self) -> template::ServiceCallTemplate {
+        template::ServiceCallTemplate {
+            template_dir: "go/nethttp/service_calls",
+            root_template_name: "service_calls",
+        }
+    }
+}
+
+pub struct MainGenerator;
+
+impl template::MainGenerator for MainGenerator {
+    fn create_main_template(&self) -> template::MainTemplate {
+        template::MainTemplate {
+            template_dir: "go/nethttp",
+            root_template_name: "main",
+            auxiliry_template_names: &[],
+        }
+    }
+}
+
+/// The framework only depends on the standard library, whose `net/http` server matches methods
+/// and path parameters since Go 1.22.
+pub fn get_framework_dependencies() -> Vec<&'static str> {
+    Vec::new()
+}
+
+pub fn get_tracing_dependencies() -> Vec<&'static str> {
+    vec![
+        "require go.opentelemetry.io/otel v1.24.0",
+        "require go.opentelemetry.io/otel/sdk v1.24.0",
+        "require go.opentelemetry.io/otel/exporters/otlp/otlptrace/otlptracegrpc v1.24.0",
+        "require go.opentelemetry.io/contrib/instrumentation/net/http/otelhttp v0.49.0",
+    ]
+}
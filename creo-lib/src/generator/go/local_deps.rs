@@ -0,0 +1,32 @@
+use super::HANDLER_MODULE_PREFIX;
+
+/// Requires the module of every handler function and replaces it with its local directory, as
+/// the handler function modules are not published.
+pub fn get_local_handler_dependencies(
+    lib_dir: impl AsRef<std::path::Path>,
+) -> std::io::Result<Vec<String>> {
+    let mut deps = Vec::default();
+    for entry in lib_dir.as_ref().read_dir()? {
+        let entry = entry?;
+        let ft = entry.file_type()?;
+        if ft.is_dir() {
+            let dir_name = entry
+                .file_name()
+                .to_str()
+                .expect("directory name should be valid UTF-8")
+                .to_string();
+            deps.push(format!(
+                "require {}{} v0.0.0",
+                HANDLER_MODULE_PREFIX, dir_name
+            ));
+            deps.push(format!(
+                "replace {}{} => ./lib/{}",
+                HANDLER_MODULE_PREFIX, dir_name, dir_name
+            ));
+        } else {
+            log::debug!("Skipping entry {}", entry.path().display());
+        }
+    }
+
+    Ok(deps)
+}
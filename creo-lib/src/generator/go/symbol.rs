@@ -0,0 +1,99 @@
+use crate::generator::core;
+
+pub struct SymbolGenerator;
+
+impl core::SymbolGenerator for SymbolGenerator {
+    fn generate_array_item_function_name(&self, name: &str) -> String {
+        format!("{}Item", name)
+    }
+
+    fn generate_object_property_function_name(&self, name: &str, prop_name: &str) -> String {
+        format!("{}Prop{}", name, to_pascal_case(prop_name))
+    }
+
+    fn generate_service_calls_function_name(
+        &self,
+        endpoint: crate::graph::EndpointIndex,
+    ) -> String {
+        format!("serviceCallsEndpoint{}", endpoint.0)
+    }
+
+    /// The service call file belongs to the `main` package of the router file, thus the service
+    /// call functions are accessible without an import.
+    fn generate_service_call_function_import(
+        &self,
+        _file_path: &str,
+        _function_name: &str,
+    ) -> String {
+        String::new()
+    }
+
+    fn generate_individual_service_call_function_name(
+        &self,
+        call: crate::application::ServiceCallEdge,
+    ) -> String {
+        format!(
+            "serviceCallEndpoint{}ToEndpoint{}",
+            call.source.0, call.target.0
+        )
+    }
+
+    fn generate_operation_function_name(&self, endpoint: crate::graph::EndpointIndex) -> String {
+        format!("operationEndpoint{}", endpoint.0)
+    }
+
+    fn generate_handler_function_import(&self, import_path: &str, _function_name: &str) -> String {
+        format!("{} \"{}\"", package_name(import_path), import_path)
+    }
+
+    fn generate_handler_function_reference(
+        &self,
+        import_path: &str,
+        function_name: &str,
+    ) -> String {
+        format!("{}.{}", package_name(import_path), function_name)
+    }
+
+    fn generate_query_data_function_name(
+        &self,
+        service_call: crate::application::ServiceCallEdge,
+    ) -> String {
+        format!(
+            "queryDataCallEndpoint{}ToEndpoint{}",
+            service_call.source.0, service_call.target.0
+        )
+    }
+
+    fn generate_parameter_function_name(
+        &self,
+        service_call: crate::application::ServiceCallEdge,
+        param_name: &str,
+    ) -> String {
+        format!(
+            "callEndpoint{}ToEndpoint{}Param{}",
+            service_call.source.0,
+            service_call.target.0,
+            to_pascal_case(param_name)
+        )
+    }
+}
+
+/// Returns the package name of the given import path, i.e., its last element.
+fn package_name(import_path: &str) -> &str {
+    import_path
+        .rsplit_once('/')
+        .map_or(import_path, |(_, name)| name)
+}
+
+/// Converts the given snake_case name to PascalCase, e.g., `user_id` to `UserId`.
+pub fn to_pascal_case(name: &str) -> String {
+    name.split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,52 @@
+use crate::{generator::core::FrameworkGenerator, template};
+
+pub mod spring_boot;
+
+pub enum Frameworks {
+    SpringBoot,
+}
+use Frameworks::*;
+
+impl FrameworkGenerator for Frameworks {
+    fn to_faker(&self) -> &dyn template::Fakeable {
+        match self {
+            SpringBoot => &spring_boot::Faker,
+        }
+    }
+
+    fn to_router_generator(&self) -> &dyn template::RouterGenerator {
+        match self {
+            SpringBoot => &spring_boot::RouterGenerator,
+        }
+    }
+
+    fn to_service_calls_generator(&self) -> &dyn template::ServiceCallGenerator {
+        match self {
+            SpringBoot => &spring_boot::ServiceCallGenerator,
+        }
+    }
+
+    fn to_main_generator(&self) -> &dyn template::MainGenerator {
+        match self {
+            SpringBoot => &spring_boot::MainGenerator,
+        }
+    }
+
+    fn get_framework_requirements(&self) -> Vec<&'static str> {
+        match self {
+            SpringBoot => spring_boot::get_framework_dependencies(),
+        }
+    }
+
+    fn get_tracing_requirements(&self) -> Vec<&'static str> {
+        match self {
+            SpringBoot => spring_boot::get_tracing_dependencies(),
+        }
+    }
+
+    fn get_docker_entrypoint(&self) -> &'static str {
+        match self {
+            SpringBoot => spring_boot::DOCKER_ENTRYPOINT,
+        }
+    }
+}
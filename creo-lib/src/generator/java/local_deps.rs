@@ -0,0 +1,29 @@
+use super::{HANDLER_GROUP_ID, HANDLER_VERSION};
+
+/// Depends on the Maven project of every handler function, which is installed into the local
+/// repository before the microservice is built, as the handler function projects are not
+/// published.
+pub fn get_local_handler_dependencies(
+    lib_dir: impl AsRef<std::path::Path>,
+) -> std::io::Result<Vec<String>> {
+    let mut deps = Vec::default();
+    for entry in lib_dir.as_ref().read_dir()? {
+        let entry = entry?;
+        let ft = entry.file_type()?;
+        if ft.is_dir() {
+            let dir_name = entry
+                .file_name()
+                .to_str()
+                .expect("directory name should be valid UTF-8")
+                .to_string();
+            deps.push(format!(
+                "<dependency><groupId>{}</groupId><artifactId>{}</artifactId><version>{}</version></dependency>",
+                HANDLER_GROUP_ID, dir_name, HANDLER_VERSION
+            ));
+        } else {
+            log::debug!("Skipping entry {}", entry.path().display());
+        }
+    }
+
+    Ok(deps)
+}
@@ -0,0 +1,25 @@
+mod data_type;
+mod file_name;
+mod frameworks;
+mod local_deps;
+mod symbol;
+
+pub use data_type::DataTypeMapper;
+pub use file_name::FileNameGenerator;
+pub use frameworks::Frameworks;
+pub use local_deps::get_local_handler_dependencies;
+pub use symbol::{to_camel_case, SymbolGenerator};
+
+pub const DOCKERFILE_TEMPLATE_PATH: &str = "java/Dockerfile.mgt";
+pub const DEPENDENCY_FILE_NAME: &str = "pom.xml";
+pub const DEPENDENCY_FILE_TEMPLATE_PATH: &str = "java/pom.mgt";
+/// The package prefix of every handler function, which is followed by the name of the handler
+/// function directory, e.g., `creo.handlers.hash`. The directory name is also the artifact id of
+/// the handler function's Maven project.
+pub const HANDLER_PACKAGE_PREFIX: &str = "creo.handlers.";
+/// The group id of the Maven project of every handler function.
+pub const HANDLER_GROUP_ID: &str = "creo.handlers";
+/// The version of the Maven project of every handler function.
+pub const HANDLER_VERSION: &str = "0.0.0";
+/// The source root of the Maven projects, which precedes the package directories.
+const SOURCE_ROOT: &str = "src/main/java/";
@@ -0,0 +1,100 @@
+use super::SOURCE_ROOT;
+use crate::generator::{core, go::to_pascal_case};
+
+pub struct SymbolGenerator;
+
+impl core::SymbolGenerator for SymbolGenerator {
+    fn generate_array_item_function_name(&self, name: &str) -> String {
+        format!("{}Item", name)
+    }
+
+    fn generate_object_property_function_name(&self, name: &str, prop_name: &str) -> String {
+        format!("{}Prop{}", name, to_pascal_case(prop_name))
+    }
+
+    fn generate_service_calls_function_name(
+        &self,
+        endpoint: crate::graph::EndpointIndex,
+    ) -> String {
+        format!("serviceCallsEndpoint{}", endpoint.0)
+    }
+
+    /// The service call functions are static methods of the service call class, which are
+    /// imported statically.
+    fn generate_service_call_function_import(
+        &self,
+        file_path: &str,
+        function_name: &str,
+    ) -> String {
+        let class_path = file_path.strip_prefix(SOURCE_ROOT).unwrap_or(file_path);
+        format!(
+            "import static {}.{};",
+            class_path.replace('/', "."),
+            function_name
+        )
+    }
+
+    fn generate_individual_service_call_function_name(
+        &self,
+        call: crate::application::ServiceCallEdge,
+    ) -> String {
+        format!(
+            "serviceCallEndpoint{}ToEndpoint{}",
+            call.source.0, call.target.0
+        )
+    }
+
+    fn generate_operation_function_name(&self, endpoint: crate::graph::EndpointIndex) -> String {
+        format!("operationEndpoint{}", endpoint.0)
+    }
+
+    /// The import path of a Java handler function is the fully qualified name of the class
+    /// declaring the handler function as static method.
+    fn generate_handler_function_import(&self, import_path: &str, _function_name: &str) -> String {
+        format!("import {};", import_path)
+    }
+
+    fn generate_handler_function_reference(
+        &self,
+        import_path: &str,
+        function_name: &str,
+    ) -> String {
+        let class_name = import_path
+            .rsplit_once('.')
+            .map_or(import_path, |(_, name)| name);
+        format!("{}.{}", class_name, function_name)
+    }
+
+    fn generate_query_data_function_name(
+        &self,
+        service_call: crate::application::ServiceCallEdge,
+    ) -> String {
+        format!(
+            "queryDataCallEndpoint{}ToEndpoint{}",
+            service_call.source.0, service_call.target.0
+        )
+    }
+
+    fn generate_parameter_function_name(
+        &self,
+        service_call: crate::application::ServiceCallEdge,
+        param_name: &str,
+    ) -> String {
+        format!(
+            "callEndpoint{}ToEndpoint{}Param{}",
+            service_call.source.0,
+            service_call.target.0,
+            to_pascal_case(param_name)
+        )
+    }
+}
+
+/// Converts the given snake_case name to camelCase, e.g., `user_id` to `userId`.
+pub fn to_camel_case(name: &str) -> String {
+    let pascal_case = to_pascal_case(name);
+    let mut chars = pascal_case.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
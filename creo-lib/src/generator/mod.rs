@@ -1,3 +1,6 @@
 pub(crate) mod core;
+pub mod go;
+pub mod java;
+pub mod node;
 pub mod python;
 pub mod rust;
@@ -0,0 +1,45 @@
+use crate::generator::core::{self, LanguageDataType};
+
+/// JavaScript is dynamically typed, thus the data types are the JSON schema types, which the
+/// router uses to validate and coerce the request parameters.
+pub struct DataTypeMapper;
+
+impl core::DataTypeMapper for DataTypeMapper {
+    fn get_string_type(&self) -> &'static str {
+        "string"
+    }
+
+    fn get_date_type(&self) -> LanguageDataType {
+        LanguageDataType {
+            type_name: "string".into(),
+            import: None,
+        }
+    }
+
+    fn get_date_time_type(&self) -> LanguageDataType {
+        LanguageDataType {
+            type_name: "string".into(),
+            import: None,
+        }
+    }
+
+    fn get_floating_point_number_type(&self) -> &'static str {
+        "number"
+    }
+
+    fn get_double_type(&self) -> &'static str {
+        "number"
+    }
+
+    fn get_signed_32_bit_integer_type(&self) -> &'static str {
+        "integer"
+    }
+
+    fn get_signed_64_bit_integer_type(&self) -> &'static str {
+        "integer"
+    }
+
+    fn get_boolean_type(&self) -> &'static str {
+        "boolean"
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{generator::core::FrameworkGenerator, template};
+
+pub mod fastify;
+
+pub enum Frameworks {
+    Fastify,
+}
+use Frameworks::*;
+
+impl FrameworkGenerator for Frameworks {
+    fn to_faker(&self) -> &dyn template::Fakeable {
+        match self {
+            Fastify => &fastify::Faker,
+        }
+    }
+
+    fn to_router_generator(&self) -> &dyn template::RouterGenerator {
+        match self {
+            Fastify => &fastify::RouterGenerator,
+        }
+    }
+
+    fn to_service_calls_generator(&self) -> &dyn template::ServiceCallGenerator {
+        match self {
+            Fastify => &fastify::ServiceCallGenerator,
+        }
+    }
+
+    fn to_main_generator(&self) -> &dyn template::MainGenerator {
+        match self {
+            Fastify => &fastify::MainGenerator,
+        }
+    }
+
+    fn get_framework_requirements(&self) -> Vec<&'static str> {
+        match self {
+            Fastify => fastify::get_framework_dependencies(),
+        }
+    }
+
+    fn get_tracing_requirements(&self) -> Vec<&'static str> {
+        match self {
+            Fastify => fastify::get_tracing_dependencies(),
+        }
+    }
+
+    fn get_docker_entrypoint(&self) -> &'static str {
+        match self {
+            Fastify => fastify::DOCKER_ENTRYPOINT,
+        }
+    }
+}
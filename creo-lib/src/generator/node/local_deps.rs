@@ -0,0 +1,28 @@
+use super::HANDLER_PACKAGE_SCOPE;
+
+/// Depends on the package of every handler function by its local directory, as the handler
+/// function packages are not published.
+pub fn get_local_handler_dependencies(
+    lib_dir: impl AsRef<std::path::Path>,
+) -> std::io::Result<Vec<String>> {
+    let mut deps = Vec::default();
+    for entry in lib_dir.as_ref().read_dir()? {
+        let entry = entry?;
+        let ft = entry.file_type()?;
+        if ft.is_dir() {
+            let dir_name = entry
+                .file_name()
+                .to_str()
+                .expect("directory name should be valid UTF-8")
+                .to_string();
+            deps.push(format!(
+                "\"{}{}\": \"file:lib/{}\"",
+                HANDLER_PACKAGE_SCOPE, dir_name, dir_name
+            ));
+        } else {
+            log::debug!("Skipping entry {}", entry.path().display());
+        }
+    }
+
+    Ok(deps)
+}
@@ -0,0 +1,18 @@
+mod data_type;
+mod file_name;
+mod frameworks;
+mod local_deps;
+mod symbol;
+
+pub use data_type::DataTypeMapper;
+pub use file_name::FileNameGenerator;
+pub use frameworks::Frameworks;
+pub use local_deps::get_local_handler_dependencies;
+pub use symbol::SymbolGenerator;
+
+pub const DOCKERFILE_TEMPLATE_PATH: &str = "node/Dockerfile.mgt";
+pub const DEPENDENCY_FILE_NAME: &str = "package.json";
+pub const DEPENDENCY_FILE_TEMPLATE_PATH: &str = "node/package.mgt";
+/// The scope of the package name of every handler function, which is followed by the name of the
+/// handler function directory, e.g., `@creo-handlers/hash`.
+pub const HANDLER_PACKAGE_SCOPE: &str = "@creo-handlers/";
@@ -0,0 +1,87 @@
+use crate::generator::{core, go::to_pascal_case};
+
+pub struct SymbolGenerator;
+
+impl core::SymbolGenerator for SymbolGenerator {
+    fn generate_array_item_function_name(&self, name: &str) -> String {
+        format!("{}Item", name)
+    }
+
+    fn generate_object_property_function_name(&self, name: &str, prop_name: &str) -> String {
+        format!("{}Prop{}", name, to_pascal_case(prop_name))
+    }
+
+    fn generate_service_calls_function_name(
+        &self,
+        endpoint: crate::graph::EndpointIndex,
+    ) -> String {
+        format!("serviceCallsEndpoint{}", endpoint.0)
+    }
+
+    /// The router file and the service call file are modules in the same directory, thus the
+    /// service call functions are imported by the relative path of the service call file.
+    fn generate_service_call_function_import(
+        &self,
+        file_path: &str,
+        function_name: &str,
+    ) -> String {
+        let file_name = file_path
+            .rsplit_once('/')
+            .map_or(file_path, |(_, name)| name);
+        format!(
+            "import {{ {} }} from \"./{}.js\";",
+            function_name, file_name
+        )
+    }
+
+    fn generate_individual_service_call_function_name(
+        &self,
+        call: crate::application::ServiceCallEdge,
+    ) -> String {
+        format!(
+            "serviceCallEndpoint{}ToEndpoint{}",
+            call.source.0, call.target.0
+        )
+    }
+
+    fn generate_operation_function_name(&self, endpoint: crate::graph::EndpointIndex) -> String {
+        format!("operationEndpoint{}", endpoint.0)
+    }
+
+    /// The import path of a handler function is the name of its package, which exports the
+    /// handler function.
+    fn generate_handler_function_import(&self, import_path: &str, function_name: &str) -> String {
+        format!("import {{ {} }} from \"{}\";", function_name, import_path)
+    }
+
+    fn generate_handler_function_reference(
+        &self,
+        _import_path: &str,
+        function_name: &str,
+    ) -> String {
+        function_name.into()
+    }
+
+    fn generate_query_data_function_name(
+        &self,
+        service_call: crate::application::ServiceCallEdge,
+    ) -> String {
+        format!(
+            "queryDataCallEndpoint{}ToEndpoint{}",
+            service_call.source.0, service_call.target.0
+        )
+    }
+
+    fn generate_parameter_function_name(
+        &self,
+        service_call: crate::application::ServiceCallEdge,
+        param_name: &str,
+    ) -> String {
+        format!(
+            "callEndpoint{}ToEndpoint{}Param{}",
+            service_call.source.0,
+            service_call.target.0,
+            to_pascal_case(param_name)
+        )
+    }
+}
@@ -12,7 +12,9 @@ impl template::Fakeable for Faker {
             format!(
                 "{}, {}",
                 string_validation.min_length.unwrap_or_default(),
-                string_validation.max_length.unwrap_or(20)
+                string_validation
+                    .max_length
+                    .unwrap_or(template::DEFAULT_MAX_STRING_LENGTH)
             ),
         )
     }
@@ -23,7 +25,9 @@ impl template::Fakeable for Faker {
             format!(
                 "min_value={}, max_value={}",
                 number_validation.minimum.unwrap_or_default(),
-                number_validation.maximum.unwrap_or(5000.0)
+                number_validation
+                    .maximum
+                    .unwrap_or(template::DEFAULT_MAX_NUMBER)
             ),
         )
     }
@@ -37,7 +41,9 @@ impl template::Fakeable for Faker {
             format!(
                 "{}, {}",
                 integer_validation.minimum.unwrap_or_default(),
-                integer_validation.maximum.unwrap_or(9999)
+                integer_validation
+                    .maximum
+                    .unwrap_or(template::DEFAULT_MAX_INTEGER)
             ),
         )
     }
@@ -103,3 +109,12 @@ pub fn get_framework_dependencies() -> Vec<&'static str> {
         "Faker==22.2.*",
     ]
 }
+
+pub fn get_tracing_dependencies() -> Vec<&'static str> {
+    vec![
+        "opentelemetry-sdk==1.22.*",
+        "opentelemetry-exporter-otlp-proto-grpc==1.22.*",
+        "opentelemetry-instrumentation-fastapi==0.43b0",
+        "opentelemetry-instrumentation-httpx==0.43b0",
+    ]
+}
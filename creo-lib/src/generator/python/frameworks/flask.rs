@@ -0,0 +1,46 @@
+use crate::template;
+
+/// The worker and thread counts of gunicorn can be overridden with the `GUNICORN_CMD_ARGS`
+/// environment variable.
+pub const DOCKER_ENTRYPOINT: &str = r#"[ "gunicorn", "main:app", "--bind", "0.0.0.0:80", "--worker-class", "gthread", "--threads", "8" ]"#;
+
+pub struct RouterGenerator;
+
+impl template::RouterGenerator for RouterGenerator {
+    fn create_router_template(&self) -> template::RouterTemplate {
+        template::RouterTemplate {
+            template_dir: "python/flask/router",
+            root_template_name: "router",
+        }
+    }
+}
+
+pub struct MainGenerator;
+
+impl template::MainGenerator for MainGenerator {
+    fn create_main_template(&self) -> template::MainTemplate {
+        template::MainTemplate {
+            template_dir: "python/flask",
+            root_template_name: "main",
+            auxiliry_template_names: &[],
+        }
+    }
+}
+
+pub fn get_framework_dependencies() -> Vec<&'static str> {
+    vec![
+        "Flask==3.0.*",
+        "gunicorn==22.0.*",
+        "httpx==0.26.*",
+        "Faker==22.2.*",
+    ]
+}
+
+pub fn get_tracing_dependencies() -> Vec<&'static str> {
+    vec![
+        "opentelemetry-sdk==1.22.*",
+        "opentelemetry-exporter-otlp-proto-grpc==1.22.*",
+        "opentelemetry-instrumentation-flask==0.43b0",
+        "opentelemetry-instrumentation-httpx==0.43b0",
+    ]
+}
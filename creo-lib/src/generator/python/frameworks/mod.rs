@@ -1,49 +1,61 @@
-use rand_derive::Rand;
-
 use crate::{generator::core::FrameworkGenerator, template};
 
 pub mod fastapi;
+pub mod flask;
 
-#[derive(Rand)]
 pub enum Frameworks {
     FastAPI,
+    Flask,
 }
 use Frameworks::*;
 
+// The service calls of both frameworks are issued with the asynchronous httpx client, thus Flask
+// shares the faker and service call templates of FastAPI.
 impl FrameworkGenerator for Frameworks {
     fn to_faker(&self) -> &dyn template::Fakeable {
         match self {
-            FastAPI => &fastapi::Faker,
+            FastAPI | Flask => &fastapi::Faker,
         }
     }
 
     fn to_router_generator(&self) -> &dyn template::RouterGenerator {
         match self {
             FastAPI => &fastapi::RouterGenerator,
+            Flask => &flask::RouterGenerator,
         }
     }
 
     fn to_service_calls_generator(&self) -> &dyn template::ServiceCallGenerator {
         match self {
-            FastAPI => &fastapi::ServiceCallGenerator,
+            FastAPI | Flask => &fastapi::ServiceCallGenerator,
         }
     }
 
     fn to_main_generator(&self) -> &dyn template::MainGenerator {
         match self {
             FastAPI => &fastapi::MainGenerator,
+            Flask => &flask::MainGenerator,
         }
     }
 
     fn get_framework_requirements(&self) -> Vec<&'static str> {
         match self {
             FastAPI => fastapi::get_framework_dependencies(),
+            Flask => flask::get_framework_dependencies(),
+        }
+    }
+
+    fn get_tracing_requirements(&self) -> Vec<&'static str> {
+        match self {
+            FastAPI => fastapi::get_tracing_dependencies(),
+            Flask => flask::get_tracing_dependencies(),
         }
     }
 
     fn get_docker_entrypoint(&self) -> &'static str {
         match self {
             FastAPI => fastapi::DOCKER_ENTRYPOINT,
+            Flask => flask::DOCKER_ENTRYPOINT,
         }
     }
 }
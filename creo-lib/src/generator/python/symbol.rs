@@ -50,6 +50,14 @@ impl core::SymbolGenerator for SymbolGenerator {
         format!("from {} import {}", import_path, function_name)
     }
 
+    fn generate_handler_function_reference(
+        &self,
+        _import_path: &str,
+        function_name: &str,
+    ) -> String {
+        function_name.into()
+    }
+
     fn generate_query_data_function_name(
         &self,
         service_call: crate::application::ServiceCallEdge,
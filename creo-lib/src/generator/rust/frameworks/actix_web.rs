@@ -0,0 +1,49 @@
+use crate::template;
+
+pub const DOCKER_ENTRYPOINT: &str = r#"["./service"]"#;
+
+pub struct RouterGenerator;
+
+impl template::RouterGenerator for RouterGenerator {
+    fn create_router_template(&self) -> template::RouterTemplate {
+        template::RouterTemplate {
+            template_dir: "rust/actix_web/router",
+            root_template_name: "router",
+        }
+    }
+}
+
+pub struct MainGenerator;
+
+impl template::MainGenerator for MainGenerator {
+    fn create_main_template(&self) -> template::MainTemplate {
+        template::MainTemplate {
+            template_dir: "rust/actix_web",
+            root_template_name: "main",
+            auxiliry_template_names: &[],
+        }
+    }
+}
+
+/// The service calls are issued with the hyper client, which runs on the Tokio runtime of the
+/// Actix Web workers.
+pub fn get_framework_dependencies() -> Vec<&'static str> {
+    vec![
+        r#"actix-web = "4.9""#,
+        r#"serde = { version = "1.0", features = ["derive"] }"#,
+        r#"tokio = { version = "1", features = ["full"] }"#,
+        r#"hyper = { version = "0.14", features = ["client", "http1", "tcp"] }"#,
+        r#"rand = "0.8.5""#,
+        r#"serde_json = "1.0""#,
+        r#"serde_urlencoded = "0.7.1""#,
+        r#"serde_valid = "0.24.0""#,
+    ]
+}
+
+pub fn get_tracing_dependencies() -> Vec<&'static str> {
+    vec![
+        r#"opentelemetry = "0.21""#,
+        r#"opentelemetry_sdk = { version = "0.21", features = ["rt-tokio"] }"#,
+        r#"opentelemetry-otlp = "0.14""#,
+    ]
+}
@@ -7,7 +7,9 @@ pub struct Faker;
 impl template::Fakeable for Faker {
     fn get_string_fake(&self, string_validation: &openapiv3::StringType) -> template::FakeFunction {
         let min_length = string_validation.min_length.unwrap_or(0);
-        let max_length = string_validation.max_length.unwrap_or(20);
+        let max_length = string_validation
+            .max_length
+            .unwrap_or(template::DEFAULT_MAX_STRING_LENGTH);
 
         template::FakeFunction::new(
             "get_random_string".into(),
@@ -17,7 +19,9 @@ impl template::Fakeable for Faker {
 
     fn get_number_fake(&self, number_validation: &openapiv3::NumberType) -> template::FakeFunction {
         let min_value = number_validation.minimum.unwrap_or_default();
-        let max_value = number_validation.maximum.unwrap_or(5000.0);
+        let max_value = number_validation
+            .maximum
+            .unwrap_or(template::DEFAULT_MAX_NUMBER);
         template::FakeFunction::new(
             "get_random_numeric_value".into(),
             format!("{}, {}", min_value, max_value),
@@ -29,7 +33,9 @@ impl template::Fakeable for Faker {
         integer_validation: &openapiv3::IntegerType,
     ) -> template::FakeFunction {
         let min_value = integer_validation.minimum.unwrap_or_default();
-        let max_value = integer_validation.maximum.unwrap_or(9999);
+        let max_value = integer_validation
+            .maximum
+            .unwrap_or(template::DEFAULT_MAX_INTEGER);
         template::FakeFunction::new(
             "get_random_numeric_value".into(),
             format!("{}, {}", min_value, max_value),
@@ -98,3 +104,11 @@ pub fn get_framework_dependencies() -> Vec<&'static str> {
         r#"serde_valid = "0.24.0""#,
     ]
 }
+
+pub fn get_tracing_dependencies() -> Vec<&'static str> {
+    vec![
+        r#"opentelemetry = "0.21""#,
+        r#"opentelemetry_sdk = { version = "0.21", features = ["rt-tokio"] }"#,
+        r#"opentelemetry-otlp = "0.14""#,
+    ]
+}
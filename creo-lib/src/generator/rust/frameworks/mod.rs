@@ -1,49 +1,61 @@
-use rand_derive::Rand;
-
 use crate::{generator::core::FrameworkGenerator, template};
 
+pub mod actix_web;
 pub mod axum;
 
-#[derive(Rand)]
 pub enum Frameworks {
     Axum,
+    ActixWeb,
 }
 use Frameworks::*;
 
+// The service calls of both frameworks are issued with the hyper client on the Tokio runtime,
+// thus Actix Web shares the faker and service call templates of Axum.
 impl FrameworkGenerator for Frameworks {
     fn to_faker(&self) -> &dyn template::Fakeable {
         match self {
-            Axum => &axum::Faker,
+            Axum | ActixWeb => &axum::Faker,
         }
     }
 
     fn to_router_generator(&self) -> &dyn template::RouterGenerator {
         match self {
             Axum => &axum::RouterGenerator,
+            ActixWeb => &actix_web::RouterGenerator,
         }
     }
 
     fn to_service_calls_generator(&self) -> &dyn template::ServiceCallGenerator {
         match self {
-            Axum => &axum::ServiceCallGenerator,
+            Axum | ActixWeb => &axum::ServiceCallGenerator,
         }
     }
 
     fn to_main_generator(&self) -> &dyn template::MainGenerator {
         match self {
             Axum => &axum::MainGenerator,
+            ActixWeb => &actix_web::MainGenerator,
         }
     }
 
     fn get_framework_requirements(&self) -> Vec<&'static str> {
         match self {
             Axum => axum::get_framework_dependencies(),
+            ActixWeb => actix_web::get_framework_dependencies(),
+        }
+    }
+
+    fn get_tracing_requirements(&self) -> Vec<&'static str> {
+        match self {
+            Axum => axum::get_tracing_dependencies(),
+            ActixWeb => actix_web::get_tracing_dependencies(),
         }
     }
 
     fn get_docker_entrypoint(&self) -> &'static str {
         match self {
             Axum => axum::DOCKER_ENTRYPOINT,
+            ActixWeb => actix_web::DOCKER_ENTRYPOINT,
         }
     }
 }
@@ -2,9 +2,24 @@ use std::collections::{HashMap, HashSet};
 
 use indexmap::{IndexMap, IndexSet};
 
-use super::{ColorIndex, Error, Result};
+use super::{ColorIndex, Colorer, Error, Result};
 use crate::graph::{DiGraph, DiGraphBuilder, Edge, NodeIndex, NodeView, Successors};
 
+/// [`EquitableColorer`] colors a graph with [`equitable_coloring`], i.e., the sizes of any two
+/// color classes differ by at most one.
+pub struct EquitableColorer;
+
+impl Colorer for EquitableColorer {
+    fn color(
+        &self,
+        graph: &DiGraph,
+        color_count: usize,
+        _rng: &mut dyn rand::RngCore,
+    ) -> Result<Vec<ColorIndex>> {
+        equitable_coloring(graph, color_count)
+    }
+}
+
 pub fn equitable_coloring(graph: &DiGraph, color_count: usize) -> Result<Vec<ColorIndex>> {
     let maximum_degree = graph.maximum_degree();
     if maximum_degree >= color_count {
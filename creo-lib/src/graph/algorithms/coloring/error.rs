@@ -10,6 +10,18 @@ pub enum Error {
     CyclicGraph,
     #[error("invalid coloring: vertices of edge {edge} have the same color {color}")]
     InvalidColoring { edge: Edge, color: ColorIndex },
+    #[error("cannot use all {colors} colors for a graph with only {nodes} vertices.")]
+    NotEnoughNodes { nodes: usize, colors: usize },
+    #[error("could not find a coloring with {colors} colors, in which every color is used.")]
+    NoColoringFound { colors: usize },
+    #[error("invalid color class sizes {sizes:?}: expected {colors} sizes of at least 1 summing up to {nodes}.")]
+    InvalidColorSizes {
+        sizes: Vec<usize>,
+        colors: usize,
+        nodes: usize,
+    },
+    #[error("could not find a coloring with the color class sizes {sizes:?}.")]
+    ColorSizesNotReached { sizes: Vec<usize> },
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
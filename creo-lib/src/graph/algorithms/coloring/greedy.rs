@@ -0,0 +1,185 @@
+use std::collections::HashSet;
+
+use rand::seq::SliceRandom;
+
+use super::{ColorIndex, Colorer, Error, Result};
+use crate::graph::DiGraph;
+
+/// The number of randomized colorings tried before giving up.
+const MAXIMUM_ATTEMPTS: usize = 16;
+
+/// [`GreedyColorer`] colors a graph with the DSatur heuristic.
+///
+/// Each node receives the least used color among the colors not used by its neighbours. Hence,
+/// the color class sizes are roughly, but not necessarily exactly balanced.
+pub struct GreedyColorer;
+
+impl Colorer for GreedyColorer {
+    fn color(
+        &self,
+        graph: &DiGraph,
+        color_count: usize,
+        rng: &mut dyn rand::RngCore,
+    ) -> Result<Vec<ColorIndex>> {
+        let quotas = vec![graph.node_count(); color_count];
+        dsatur_coloring(graph, &quotas, false, rng)
+    }
+}
+
+/// Colors the given graph with the DSatur heuristic, such that every color is used.
+///
+/// The nodes are colored one after another, always picking the uncolored node with the most
+/// distinctly colored neighbours (its saturation) and, secondly, the highest degree. Each node
+/// receives the color with the most remaining quota, i.e., `quotas[color]` minus the number of
+/// nodes already having this color, among the colors not used by its neighbours. If `exact` is
+/// true, no color may exceed its quota. Remaining ties are broken randomly and failed attempts are
+/// retried up to [`MAXIMUM_ATTEMPTS`] times.
+pub(super) fn dsatur_coloring(
+    graph: &DiGraph,
+    quotas: &[usize],
+    exact: bool,
+    rng: &mut dyn rand::RngCore,
+) -> Result<Vec<ColorIndex>> {
+    let color_count = quotas.len();
+    let node_count = graph.node_count();
+    if node_count < color_count {
+        return Err(Error::NotEnoughNodes {
+            nodes: node_count,
+            colors: color_count,
+        });
+    }
+
+    let neighbours = neighbourhoods(graph);
+    for _ in 0..MAXIMUM_ATTEMPTS {
+        if let Some(coloring) = try_dsatur_coloring(&neighbours, quotas, exact, rng) {
+            return Ok(coloring);
+        }
+    }
+
+    Err(Error::NoColoringFound {
+        colors: color_count,
+    })
+}
+
+/// Returns the undirected neighbourhood of each node of the given graph.
+fn neighbourhoods(graph: &DiGraph) -> Vec<Vec<usize>> {
+    let mut neighbours = vec![Vec::new(); graph.node_count()];
+    for edge in graph.iter_edges() {
+        neighbours[edge.source.0].push(edge.target.0);
+        neighbours[edge.target.0].push(edge.source.0);
+    }
+    neighbours
+}
+
+/// Performs a single randomized DSatur coloring.
+///
+/// Returns `None`, if some node cannot be colored or some color remains unused.
+fn try_dsatur_coloring(
+    neighbours: &[Vec<usize>],
+    quotas: &[usize],
+    exact: bool,
+    rng: &mut dyn rand::RngCore,
+) -> Option<Vec<ColorIndex>> {
+    let node_count = neighbours.len();
+    let color_count = quotas.len();
+    let mut colors: Vec<Option<usize>> = vec![None; node_count];
+    let mut saturation: Vec<HashSet<usize>> = vec![HashSet::new(); node_count];
+    let mut sizes = vec![0_usize; color_count];
+
+    for _ in 0..node_count {
+        let node = select_node(neighbours, &colors, &saturation, rng)?;
+
+        let remaining = |color: usize| quotas[color] as isize - sizes[color] as isize;
+        let candidates: Vec<usize> = (0..color_count)
+            .filter(|color| !saturation[node].contains(color))
+            .filter(|&color| !exact || remaining(color) > 0)
+            .collect();
+        let best = candidates.iter().map(|&color| remaining(color)).max()?;
+        let candidates: Vec<usize> = candidates
+            .into_iter()
+            .filter(|&color| remaining(color) == best)
+            .collect();
+        let color = *candidates.choose(rng)?;
+
+        colors[node] = Some(color);
+        sizes[color] += 1;
+        for &neighbour in &neighbours[node] {
+            saturation[neighbour].insert(color);
+        }
+    }
+
+    if sizes.contains(&0) {
+        return None;
+    }
+
+    colors
+        .into_iter()
+        .map(|color| color.map(ColorIndex))
+        .collect()
+}
+
+/// Selects the uncolored node with the highest saturation and, secondly, the highest degree.
+///
+/// Ties are broken randomly.
+fn select_node(
+    neighbours: &[Vec<usize>],
+    colors: &[Option<usize>],
+    saturation: &[HashSet<usize>],
+    rng: &mut dyn rand::RngCore,
+) -> Option<usize> {
+    let priority = |node: usize| (saturation[node].len(), neighbours[node].len());
+    let uncolored = (0..colors.len()).filter(|&node| colors[node].is_none());
+    let best = uncolored.clone().map(priority).max()?;
+    let candidates: Vec<usize> = uncolored.filter(|&node| priority(node) == best).collect();
+    candidates.choose(rng).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::graph::{
+        algorithms::{coloring::is_coloring, random},
+        DiGraphBuilder,
+    };
+
+    #[test]
+    fn test_greedy_coloring() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let params = random::GNMParameters {
+            node_count: 40,
+            edge_count: 60,
+            maximum_degree: Some(6),
+        };
+        let graph = random::random_gnm_graph(&params, &mut rng).expect("should generate a graph");
+
+        let coloring = GreedyColorer
+            .color(&graph, 6, &mut rng)
+            .expect("should find a coloring");
+        assert_eq!(coloring.len(), 40);
+        assert!(is_coloring(graph.iter_edges(), &coloring).is_ok());
+        for color in 0..6 {
+            assert!(
+                coloring.contains(&ColorIndex(color)),
+                "expected color {} to be used",
+                color
+            );
+        }
+    }
+
+    #[test]
+    fn test_greedy_coloring_with_not_enough_nodes() {
+        let graph = DiGraphBuilder::new().add_nodes(2).build();
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let result = GreedyColorer.color(&graph, 3, &mut rng);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::NotEnoughNodes {
+                nodes: 2,
+                colors: 3
+            }
+        );
+    }
+}
@@ -1,12 +1,16 @@
 mod equitable;
 mod error;
+mod greedy;
+mod sized;
 
 use std::fmt::Display;
 
 use crate::graph::DiGraph;
 
-pub use equitable::{equitable_coloring, is_coloring, is_equitable};
+pub use equitable::{equitable_coloring, is_coloring, is_equitable, EquitableColorer};
 pub use error::{Error, Result};
+pub use greedy::GreedyColorer;
+pub use sized::{zipf_sizes, HistogramColorer, ZipfColorer};
 
 #[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub struct ColorIndex(pub usize);
@@ -28,7 +32,16 @@ impl Display for ColorIndex {
     }
 }
 
+/// [`Colorer`] assigns each node of a graph one of `color_count` colors, such that the nodes of an
+/// edge have distinct colors.
 pub trait Colorer {
-    fn new(graph: DiGraph, color_count: usize) -> Self;
-    fn color() -> Vec<ColorIndex>;
+    /// Colors the nodes of the given graph with the given number of colors.
+    ///
+    /// Returns the color of each node, indexed by the node's ID.
+    fn color(
+        &self,
+        graph: &DiGraph,
+        color_count: usize,
+        rng: &mut dyn rand::RngCore,
+    ) -> Result<Vec<ColorIndex>>;
 }
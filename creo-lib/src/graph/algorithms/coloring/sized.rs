@@ -0,0 +1,185 @@
+use super::{greedy::dsatur_coloring, ColorIndex, Colorer, Error, Result};
+use crate::graph::DiGraph;
+
+/// [`ZipfColorer`] colors a graph, such that the color class sizes approximately follow Zipf's
+/// law.
+///
+/// The `i`-th color class receives roughly `1 / i^exponent` times the nodes of the first color
+/// class, e.g., a single large monolith with several small satellite services. The sizes are only
+/// targets, since an edge may force one of its nodes into another color class.
+pub struct ZipfColorer {
+    /// The exponent of the distribution, where `0` results in equally sized color classes.
+    pub exponent: f64,
+}
+
+impl Colorer for ZipfColorer {
+    fn color(
+        &self,
+        graph: &DiGraph,
+        color_count: usize,
+        rng: &mut dyn rand::RngCore,
+    ) -> Result<Vec<ColorIndex>> {
+        let quotas = zipf_sizes(graph.node_count(), color_count, self.exponent);
+        dsatur_coloring(graph, &quotas, false, rng)
+    }
+}
+
+/// [`HistogramColorer`] colors a graph with exactly the given color class sizes.
+pub struct HistogramColorer {
+    /// The number of nodes of each color.
+    pub sizes: Vec<usize>,
+}
+
+impl Colorer for HistogramColorer {
+    fn color(
+        &self,
+        graph: &DiGraph,
+        color_count: usize,
+        rng: &mut dyn rand::RngCore,
+    ) -> Result<Vec<ColorIndex>> {
+        let node_count = graph.node_count();
+        if self.sizes.len() != color_count
+            || self.sizes.contains(&0)
+            || self.sizes.iter().sum::<usize>() != node_count
+        {
+            return Err(Error::InvalidColorSizes {
+                sizes: self.sizes.clone(),
+                colors: color_count,
+                nodes: node_count,
+            });
+        }
+
+        dsatur_coloring(graph, &self.sizes, true, rng).map_err(|err| match err {
+            Error::NoColoringFound { .. } => Error::ColorSizesNotReached {
+                sizes: self.sizes.clone(),
+            },
+            err => err,
+        })
+    }
+}
+
+/// Returns the color class sizes for the given number of nodes and colors following Zipf's law
+/// with the given exponent.
+///
+/// Each color class receives at least one node, if there are enough nodes. The remaining nodes
+/// are distributed proportionally to `1 / i^exponent` for the `i`-th color class using the
+/// largest remainder method, so the sizes always sum up to the number of nodes.
+pub fn zipf_sizes(node_count: usize, color_count: usize, exponent: f64) -> Vec<usize> {
+    if color_count == 0 {
+        return Vec::new();
+    }
+    let mut sizes = vec![usize::from(node_count >= color_count); color_count];
+    let remaining = node_count - sizes.iter().sum::<usize>();
+
+    let weights: Vec<f64> = (1..=color_count)
+        .map(|rank| (rank as f64).powf(-exponent))
+        .collect();
+    let total: f64 = weights.iter().sum();
+    let shares: Vec<f64> = weights
+        .iter()
+        .map(|weight| remaining as f64 * weight / total)
+        .collect();
+    for (size, share) in sizes.iter_mut().zip(&shares) {
+        *size += share.floor() as usize;
+    }
+
+    let mut by_remainder: Vec<usize> = (0..color_count).collect();
+    by_remainder.sort_by(|&a, &b| {
+        let a = shares[a] - shares[a].floor();
+        let b = shares[b] - shares[b].floor();
+        b.total_cmp(&a)
+    });
+    let distributed: usize = sizes.iter().sum();
+    for &color in by_remainder.iter().take(node_count - distributed) {
+        sizes[color] += 1;
+    }
+
+    sizes
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::graph::{algorithms::coloring::is_coloring, DiGraphBuilder};
+
+    #[test]
+    fn test_zipf_sizes() {
+        let sizes = zipf_sizes(60, 6, 1.0);
+        assert_eq!(sizes.iter().sum::<usize>(), 60);
+        assert!(sizes.windows(2).all(|pair| pair[0] >= pair[1]));
+        assert!(sizes.iter().all(|&size| size >= 1));
+        assert_eq!(zipf_sizes(12, 4, 0.0), [3, 3, 3, 3]);
+    }
+
+    #[test]
+    fn test_zipf_coloring() {
+        let graph = DiGraphBuilder::with_node_and_edge_count(20, 3)
+            .add_nodes(20)
+            .add_edges(&[(0, 1).into(), (2, 3).into(), (4, 5).into()])
+            .build();
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let coloring = ZipfColorer { exponent: 1.5 }
+            .color(&graph, 4, &mut rng)
+            .expect("should find a coloring");
+        let mut sizes = [0; 4];
+        for color in coloring {
+            sizes[color.0] += 1;
+        }
+        assert_eq!(&sizes, &zipf_sizes(20, 4, 1.5)[..]);
+    }
+
+    #[test]
+    fn test_histogram_coloring() {
+        let graph = DiGraphBuilder::with_node_and_edge_count(6, 3)
+            .add_nodes(6)
+            .add_edges(&[(0, 1).into(), (2, 3).into(), (4, 5).into()])
+            .build();
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let coloring = HistogramColorer {
+            sizes: vec![3, 2, 1],
+        }
+        .color(&graph, 3, &mut rng)
+        .expect("should find a coloring");
+        assert!(is_coloring(graph.iter_edges(), &coloring).is_ok());
+        let mut sizes = [0; 3];
+        for color in coloring {
+            sizes[color.0] += 1;
+        }
+        assert_eq!(sizes, [3, 2, 1]);
+    }
+
+    #[test]
+    fn test_histogram_coloring_with_invalid_sizes() {
+        let graph = DiGraphBuilder::new().add_nodes(6).build();
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let result = HistogramColorer { sizes: vec![3, 2] }.color(&graph, 3, &mut rng);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::InvalidColorSizes {
+                sizes: vec![3, 2],
+                colors: 3,
+                nodes: 6
+            }
+        );
+    }
+
+    #[test]
+    fn test_histogram_coloring_with_unreachable_sizes() {
+        let graph = DiGraphBuilder::with_node_and_edge_count(4, 3)
+            .add_nodes(4)
+            .add_edges(&[(0, 1).into(), (1, 2).into(), (2, 3).into()])
+            .build();
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let result = HistogramColorer { sizes: vec![3, 1] }.color(&graph, 2, &mut rng);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::ColorSizesNotReached { sizes: vec![3, 1] }
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use std::collections::HashSet;
+
+use crate::graph;
+
+/// [`EdgeSet`] collects the edges of a randomly generated graph, while keeping track of the
+/// node degrees and already inserted edges.
+///
+/// The set rejects self loops, parallel edges, and edges that would raise the degree of either
+/// of its nodes to the optional maximum degree. Acyclicity is left to the caller, which usually
+/// only proposes edges consistent with some topological order.
+pub(super) struct EdgeSet {
+    degrees: Vec<usize>,
+    maximum_degree: Option<usize>,
+    seen: HashSet<(usize, usize)>,
+    edges: Vec<graph::Edge>,
+}
+
+impl EdgeSet {
+    pub(super) fn new(node_count: usize, edge_count: usize, maximum_degree: Option<usize>) -> Self {
+        Self {
+            degrees: vec![0; node_count],
+            maximum_degree,
+            seen: HashSet::with_capacity(edge_count),
+            edges: Vec::with_capacity(edge_count),
+        }
+    }
+
+    /// Returns the number of inserted edges.
+    pub(super) fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Returns true, if the given node can take at least one more edge.
+    pub(super) fn has_capacity(&self, node: usize) -> bool {
+        match self.maximum_degree {
+            None => true,
+            Some(maximum_degree) => self.degrees[node] + 1 < maximum_degree,
+        }
+    }
+
+    /// Returns true, if the edge from `source` to `target` can be inserted.
+    pub(super) fn allows(&self, source: usize, target: usize) -> bool {
+        source != target
+            && self.has_capacity(source)
+            && self.has_capacity(target)
+            && !self.seen.contains(&(source, target))
+    }
+
+    /// Returns true, if the edge from `source` to `target` was inserted.
+    pub(super) fn contains(&self, source: usize, target: usize) -> bool {
+        self.seen.contains(&(source, target))
+    }
+
+    /// Inserts the edge from `source` to `target`.
+    ///
+    /// Returns false and leaves the set untouched, if the edge is not allowed.
+    pub(super) fn insert(&mut self, source: usize, target: usize) -> bool {
+        if !self.allows(source, target) {
+            return false;
+        }
+        self.seen.insert((source, target));
+        self.degrees[source] += 1;
+        self.degrees[target] += 1;
+        self.edges.push((source, target).into());
+        true
+    }
+
+    /// Removes the edge from `source` to `target`.
+    ///
+    /// Returns false, if the edge was not inserted.
+    pub(super) fn remove(&mut self, source: usize, target: usize) -> bool {
+        if !self.seen.remove(&(source, target)) {
+            return false;
+        }
+        self.degrees[source] -= 1;
+        self.degrees[target] -= 1;
+        let index = self
+            .edges
+            .iter()
+            .position(|edge| edge.source.0 == source && edge.target.0 == target)
+            .expect("should contain every seen edge");
+        self.edges.swap_remove(index);
+        true
+    }
+
+    /// Builds the graph with the given number of nodes from the inserted edges.
+    pub(super) fn into_graph(self) -> graph::DiGraph {
+        let node_count = self.degrees.len();
+        graph::DiGraphBuilder::with_node_and_edge_count(node_count, self.edges.len())
+            .add_nodes(node_count)
+            .add_edges(&self.edges)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edge_set_rejects_invalid_edges() {
+        let mut edges = EdgeSet::new(3, 3, Some(2));
+
+        assert!(!edges.insert(0, 0), "should reject self loops");
+        assert!(edges.insert(0, 1));
+        assert!(!edges.insert(0, 1), "should reject parallel edges");
+        assert!(
+            !edges.insert(1, 2),
+            "should reject edges exceeding the maximum degree"
+        );
+        assert_eq!(edges.len(), 1);
+    }
+
+    #[test]
+    fn test_edge_set_remove() {
+        let mut edges = EdgeSet::new(3, 2, Some(2));
+
+        assert!(edges.insert(0, 1));
+        assert!(!edges.insert(1, 2));
+        assert!(edges.remove(0, 1));
+        assert!(!edges.remove(0, 1), "should only remove inserted edges");
+        assert!(
+            edges.insert(1, 2),
+            "should free the degree of removed edges"
+        );
+        assert_eq!(edges.len(), 1);
+    }
+}
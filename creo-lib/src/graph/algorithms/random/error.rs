@@ -0,0 +1,13 @@
+#[derive(thiserror::Error, PartialEq, Debug)]
+pub enum Error {
+    #[error("cannot place {edge_count} edges between {node_count} nodes, since at most {maximum_edge_count} edges fit without violating the degree or acyclicity constraints")]
+    Infeasible {
+        node_count: usize,
+        edge_count: usize,
+        maximum_edge_count: usize,
+    },
+    #[error("could only place {placed} of {requested} edges without violating the degree or acyclicity constraints")]
+    EdgeCountNotReached { requested: usize, placed: usize },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
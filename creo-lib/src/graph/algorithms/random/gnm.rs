@@ -1,5 +1,10 @@
+use rand::seq::SliceRandom;
+
+use super::{edge_set::EdgeSet, Error, Result};
 use crate::graph;
-use rand::distributions::{self as dist, Distribution};
+
+/// The number of random topological orders tried before giving up on placing all edges.
+const MAXIMUM_ATTEMPTS: usize = 8;
 
 /// [`GNMParameters`] contains parameters for generating a random graph with `n` nodes and `m`
 /// edges.
@@ -12,70 +17,161 @@ pub struct GNMParameters {
     pub maximum_degree: Option<usize>,
 }
 
-/// Generate a random graph with the given number of nodes and edges.
+/// Generate a random, acyclic graph with the given number of nodes and edges.
 ///
 /// Optionally restrict the maximum degree in the graph.
-/// The edges are uniformly drawn at random from the set of all possible edges, until the graph
-/// contains the specified number of edges.
-pub fn random_gnm_graph<R: rand::Rng>(params: &GNMParameters, rng: &mut R) -> graph::DiGraph {
-    loop {
-        let mut builder =
-            graph::DiGraphBuilder::with_node_and_edge_count(params.node_count, params.edge_count)
-                .add_nodes(params.node_count);
-
-        let distribution = uniform_node_distribution(params.node_count);
-        while builder.edge_count() < params.edge_count {
-            let (source, target) = select_random_edge(distribution, rng);
-            builder = builder.add_edge((source, target).into());
-        }
+/// The nodes are put into a random topological order and the edges are uniformly drawn at random
+/// from the set of all node pairs, which are directed along this order and do not exceed the
+/// degree budget of either node. Hence, the graph is acyclic by construction.
+///
+/// Returns [`Error::Infeasible`], if no graph satisfying the parameters exists, and
+/// [`Error::EdgeCountNotReached`], if the random placement repeatedly got stuck before placing
+/// all edges.
+pub fn random_gnm_graph<R: rand::Rng>(
+    params: &GNMParameters,
+    rng: &mut R,
+) -> Result<graph::DiGraph> {
+    super::check_feasibility(params.node_count, params.edge_count, params.maximum_degree)?;
 
-        let graph = builder.build();
-        if !graph.is_acyclic() {
-            continue;
-        }
-        match params.maximum_degree {
-            None => break graph,
-            Some(maximum_degree) => {
-                if graph.maximum_degree() < maximum_degree {
-                    break graph;
-                }
-            }
+    let mut placed = 0;
+    for _ in 0..MAXIMUM_ATTEMPTS {
+        match place_edges(params, rng) {
+            Ok(edges) => return Ok(edges.into_graph()),
+            Err(count) => placed = placed.max(count),
         }
     }
+
+    Err(Error::EdgeCountNotReached {
+        requested: params.edge_count,
+        placed,
+    })
 }
 
-/// Create a Uniform distribution for the range of `0` and the given number of nodes.
-///
-/// This distribution can be used for randomly sampling NodeIDs for a graph with NodeIDs starting
-/// from `0` without any gaps.
+/// Places the requested number of edges along a random topological order.
 ///
-/// # Arguments
-///
-/// * `node_count` - the number of nodes
-fn uniform_node_distribution(node_count: usize) -> dist::Uniform<usize> {
-    dist::Uniform::new(0_usize, node_count)
+/// Returns the number of placed edges as error, if no valid edge was left before reaching the
+/// requested number of edges.
+fn place_edges<R: rand::Rng>(
+    params: &GNMParameters,
+    rng: &mut R,
+) -> std::result::Result<EdgeSet, usize> {
+    let node_count = params.node_count;
+    let mut order: Vec<usize> = (0..node_count).collect();
+    order.shuffle(rng);
+    let mut ranks = vec![0; node_count];
+    for (rank, &node) in order.iter().enumerate() {
+        ranks[node] = rank;
+    }
+
+    let mut edges = EdgeSet::new(node_count, params.edge_count, params.maximum_degree);
+    while edges.len() < params.edge_count {
+        let inserted = super::insert_random_edge(
+            &mut edges,
+            node_count,
+            rng,
+            |rng| {
+                let (source, target) = select_random_pair(node_count, rng);
+                if ranks[source] < ranks[target] {
+                    (source, target)
+                } else {
+                    (target, source)
+                }
+            },
+            |source, target| ranks[source] < ranks[target],
+        );
+        if !inserted {
+            return Err(edges.len());
+        }
+    }
+
+    Ok(edges)
 }
 
-/// Selects a random edge using the given distribution for generating random NodeIDs.
-///
-/// Returns the selected edge in the form a of (source, target) tuple. This function assumes that
-/// `distribution` always yields valid NodeIDs. The selected edge will not be a self loop, i.e., an
-/// edge with equal source and target ID.
+/// Selects a random pair of distinct NodeIDs for a graph with NodeIDs starting from `0` without
+/// any gaps.
 ///
 /// # Arguments
 ///
-/// * `distribution` - the distribution for randomly sampling NodeIDs
+/// * `node_count` - the number of nodes, which must be at least `2`
 /// * `rng` - the source of randomness
-fn select_random_edge<R: rand::Rng>(
-    distribution: dist::Uniform<usize>,
-    rng: &mut R,
-) -> (usize, usize) {
-    let source = distribution.sample(rng);
-    let mut target = distribution.sample(rng);
-
-    while source == target {
-        target = distribution.sample(rng);
+fn select_random_pair<R: rand::Rng>(node_count: usize, rng: &mut R) -> (usize, usize) {
+    let source = rng.gen_range(0..node_count);
+    let mut target = rng.gen_range(0..node_count - 1);
+    if target >= source {
+        target += 1;
     }
 
     (source, target)
 }
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_random_gnm_graph() {
+        let params = GNMParameters {
+            node_count: 30,
+            edge_count: 60,
+            maximum_degree: Some(8),
+        };
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let graph = random_gnm_graph(&params, &mut rng).expect("should generate a graph");
+        assert_eq!(graph.node_count(), 30);
+        assert_eq!(graph.iter_edges().count(), 60);
+        assert!(graph.maximum_degree() < 8);
+        assert!(graph.is_acyclic());
+    }
+
+    #[test]
+    fn test_random_gnm_graph_dense() {
+        let params = GNMParameters {
+            node_count: 200,
+            edge_count: 600,
+            maximum_degree: Some(8),
+        };
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let graph = random_gnm_graph(&params, &mut rng).expect("should generate a graph");
+        assert_eq!(graph.iter_edges().count(), 600);
+        assert!(graph.maximum_degree() < 8);
+        assert!(graph.is_acyclic());
+    }
+
+    #[test]
+    fn test_random_gnm_graph_complete() {
+        let params = GNMParameters {
+            node_count: 6,
+            edge_count: 15,
+            maximum_degree: None,
+        };
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let graph = random_gnm_graph(&params, &mut rng).expect("should generate a graph");
+        assert_eq!(graph.iter_edges().count(), 15);
+        assert!(graph.is_acyclic());
+    }
+
+    #[test]
+    fn test_random_gnm_graph_infeasible() {
+        let params = GNMParameters {
+            node_count: 10,
+            edge_count: 21,
+            maximum_degree: Some(5),
+        };
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let result = random_gnm_graph(&params, &mut rng);
+        assert_eq!(
+            result.err(),
+            Some(Error::Infeasible {
+                node_count: 10,
+                edge_count: 21,
+                maximum_edge_count: 20,
+            })
+        );
+    }
+}
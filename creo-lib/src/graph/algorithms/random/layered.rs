@@ -0,0 +1,377 @@
+use rand::seq::{index, SliceRandom};
+
+use super::{edge_set::EdgeSet, Error, Result};
+use crate::graph;
+
+/// The probability with which an additional edge targets the directly following layer instead of
+/// any deeper layer.
+const NEXT_LAYER_PROBABILITY: f64 = 0.7;
+
+/// [`LayeredParameters`] contains parameters for generating a random layered graph with `n` nodes,
+/// `m` edges, and `l` layers.
+pub struct LayeredParameters {
+    /// The number of nodes to generate.
+    pub node_count: usize,
+    /// The number of edges to generate.
+    pub edge_count: usize,
+    /// The number of layers (tiers) to split the nodes into.
+    pub layer_count: usize,
+    /// The optional maximum allowed degree of each node
+    pub maximum_degree: Option<usize>,
+}
+
+/// Generate a random, acyclic, layered graph with the given number of nodes, edges, and layers.
+///
+/// Optionally restrict the maximum degree in the graph.
+/// The nodes are randomly split into non-empty layers, e.g., a gateway tier, a business logic tier,
+/// and a storage tier. Edges only point from a layer to a deeper layer. First, every node outside
+/// the first layer is called from the layer directly above it, as long as there are edges left.
+/// The remaining edges preferably connect adjacent layers, but may also skip layers.
+///
+/// Since a very uneven split may not admit enough edges, the split is balanced until it does. If
+/// even the balanced split does not admit enough edges, [`Error::EdgeCountNotReached`] reports the
+/// number of edges it admits.
+pub fn random_layered_graph<R: rand::Rng>(
+    params: &LayeredParameters,
+    rng: &mut R,
+) -> Result<graph::DiGraph> {
+    super::check_feasibility(params.node_count, params.edge_count, params.maximum_degree)?;
+
+    let node_count = params.node_count;
+    let layers = split_into_layers(
+        node_count,
+        params.layer_count,
+        params.edge_count,
+        params.maximum_degree,
+        rng,
+    );
+    let sizes: Vec<usize> = layers.iter().map(Vec::len).collect();
+    let capacity = edge_capacity(&sizes, params.maximum_degree);
+    if capacity < params.edge_count {
+        return Err(Error::EdgeCountNotReached {
+            requested: params.edge_count,
+            placed: capacity,
+        });
+    }
+
+    let mut edges = EdgeSet::new(node_count, params.edge_count, params.maximum_degree);
+    let mut layer_of = vec![0; node_count];
+    for (layer_index, layer) in layers.iter().enumerate() {
+        for &node in layer {
+            layer_of[node] = layer_index;
+        }
+    }
+
+    let mut lower_nodes: Vec<usize> = layers.iter().skip(1).flatten().copied().collect();
+    lower_nodes.shuffle(rng);
+    for target in lower_nodes {
+        if edges.len() == params.edge_count {
+            break;
+        }
+        let parents = &layers[layer_of[target] - 1];
+        let parent = parents
+            .iter()
+            .filter(|&&source| edges.allows(source, target))
+            .copied()
+            .collect::<Vec<_>>()
+            .choose(rng)
+            .copied();
+        if let Some(source) = parent {
+            edges.insert(source, target);
+        }
+    }
+
+    while edges.len() < params.edge_count {
+        let inserted = super::insert_random_edge(
+            &mut edges,
+            node_count,
+            rng,
+            |rng| {
+                let source_layer = rng.gen_range(0..layers.len() - 1);
+                let target_layer = if rng.gen_bool(NEXT_LAYER_PROBABILITY) {
+                    source_layer + 1
+                } else {
+                    rng.gen_range(source_layer + 1..layers.len())
+                };
+                let source = *layers[source_layer].choose(rng).expect("non empty layer");
+                let target = *layers[target_layer].choose(rng).expect("non empty layer");
+                (source, target)
+            },
+            |source, target| layer_of[source] < layer_of[target],
+        );
+        // The random placement may saturate the degree budgets before reaching the capacity
+        if !inserted && !augment(&mut edges, &layer_of) {
+            return Err(Error::EdgeCountNotReached {
+                requested: params.edge_count,
+                placed: edges.len(),
+            });
+        }
+    }
+
+    Ok(edges.into_graph())
+}
+
+/// Splits the nodes into at most `layer_count` non-empty layers of random size, which admit at
+/// least `edge_count` edges, if possible.
+///
+/// The nodes are assigned to the layers in ascending order, i.e., the first layer contains the
+/// nodes with the lowest indices. As long as the random split does not admit enough edges, a node
+/// is moved from the largest to the smallest layer. The balanced split admits the most edges.
+fn split_into_layers<R: rand::Rng>(
+    node_count: usize,
+    layer_count: usize,
+    edge_count: usize,
+    maximum_degree: Option<usize>,
+    rng: &mut R,
+) -> Vec<Vec<usize>> {
+    let layer_count = layer_count.clamp(1, node_count.max(1));
+    if node_count <= 1 {
+        return vec![(0..node_count).collect()];
+    }
+    // Choose `layer_count - 1` distinct cut points between the nodes.
+    let mut cuts: Vec<usize> = index::sample(rng, node_count - 1, layer_count - 1)
+        .into_iter()
+        .map(|cut| cut + 1)
+        .collect();
+    cuts.sort_unstable();
+    cuts.push(node_count);
+    let mut sizes = Vec::with_capacity(layer_count);
+    let mut start = 0;
+    for cut in cuts {
+        sizes.push(cut - start);
+        start = cut;
+    }
+
+    while edge_capacity(&sizes, maximum_degree) < edge_count {
+        let (largest, _) = sizes
+            .iter()
+            .enumerate()
+            .max_by_key(|&(_, size)| *size)
+            .expect("at least one layer");
+        let (smallest, _) = sizes
+            .iter()
+            .enumerate()
+            .min_by_key(|&(_, size)| *size)
+            .expect("at least one layer");
+        if sizes[largest] <= sizes[smallest] + 1 {
+            break;
+        }
+        sizes[largest] -= 1;
+        sizes[smallest] += 1;
+    }
+
+    let mut layers = Vec::with_capacity(layer_count);
+    let mut start = 0;
+    for size in sizes {
+        layers.push((start..start + size).collect());
+        start += size;
+    }
+    layers
+}
+
+/// Returns the maximum number of edges between layers of the given sizes, in which the degree of
+/// each node is less than the optional maximum degree.
+///
+/// Since there are no edges within a layer, a node of a layer with `s` nodes has at most
+/// `min(k, n - s)` edges, where `k` is the degree limit. Each edge takes two of these degrees.
+/// Moreover, for any set of layers, every edge either connects two layers of the set, or touches a
+/// node outside the set. The capacity is the least of these bounds, where the minimum over all
+/// sets of layers is computed per total size of the set.
+fn edge_capacity(sizes: &[usize], maximum_degree: Option<usize>) -> usize {
+    let node_count: usize = sizes.iter().sum();
+    let pairs = (node_count * node_count - sizes.iter().map(|size| size * size).sum::<usize>()) / 2;
+    let Some(maximum_degree) = maximum_degree else {
+        return pairs;
+    };
+    let degree = maximum_degree.saturating_sub(1);
+    let degrees: Vec<usize> = sizes
+        .iter()
+        .map(|size| size * degree.min(node_count - size))
+        .collect();
+    let total: usize = degrees.iter().sum();
+
+    // `bounds[t]` is the least of `2 * outside degrees - squared sizes` over all sets of layers
+    // with `t` nodes, such that the set admits `(t^2 + bounds[t]) / 2` edges.
+    let mut bounds: Vec<Option<isize>> = vec![None; node_count + 1];
+    bounds[0] = Some(2 * total as isize);
+    for (&size, &layer_degree) in sizes.iter().zip(&degrees) {
+        for t in (0..=node_count - size).rev() {
+            if let Some(bound) = bounds[t] {
+                let bound = bound - 2 * layer_degree as isize - (size * size) as isize;
+                bounds[t + size] = Some(bounds[t + size].map_or(bound, |other| other.min(bound)));
+            }
+        }
+    }
+    bounds
+        .iter()
+        .enumerate()
+        .filter_map(|(t, bound)| bound.map(|bound| ((t * t) as isize + bound) as usize / 2))
+        .fold(pairs.min(total / 2), usize::min)
+}
+
+/// Inserts one more edge by an augmenting path, if no edge can be inserted directly.
+///
+/// The path starts at a node with a spare degree and alternately inserts a missing and removes an
+/// existing edge, until it reaches another node with a spare degree. The degrees of all inner nodes
+/// remain unchanged. Paths are searched by increasing length.
+///
+/// Returns false, if there is no augmenting path.
+fn augment(edges: &mut EdgeSet, layer_of: &[usize]) -> bool {
+    let node_count = layer_of.len();
+    for depth in 0..node_count {
+        for start in 0..node_count {
+            if edges.has_capacity(start) && augment_from(edges, layer_of, start, depth) {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Searches an augmenting path from `node`, which removes at most `depth` edges.
+fn augment_from(edges: &mut EdgeSet, layer_of: &[usize], node: usize, depth: usize) -> bool {
+    let node_count = layer_of.len();
+    let orient = |a: usize, b: usize| {
+        if layer_of[a] < layer_of[b] {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    };
+    for other in 0..node_count {
+        if layer_of[other] != layer_of[node] {
+            let (source, target) = orient(node, other);
+            if edges.insert(source, target) {
+                return true;
+            }
+        }
+    }
+    if depth == 0 {
+        return false;
+    }
+
+    for other in 0..node_count {
+        let (source, target) = orient(node, other);
+        if layer_of[other] == layer_of[node] || edges.contains(source, target) {
+            continue;
+        }
+        for next in 0..node_count {
+            if next == node || layer_of[next] == layer_of[other] {
+                continue;
+            }
+            let (removed_source, removed_target) = orient(other, next);
+            if !edges.remove(removed_source, removed_target) {
+                continue;
+            }
+            if edges.insert(source, target) {
+                if augment_from(edges, layer_of, next, depth - 1) {
+                    return true;
+                }
+                edges.remove(source, target);
+            }
+            edges.insert(removed_source, removed_target);
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_split_into_layers() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let layers = split_into_layers(10, 4, 0, None, &mut rng);
+        assert_eq!(layers.len(), 4);
+        assert!(layers.iter().all(|layer| !layer.is_empty()));
+        let nodes: Vec<usize> = layers.into_iter().flatten().collect();
+        assert_eq!(nodes, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_edge_capacity() {
+        assert_eq!(edge_capacity(&[1, 3, 3], None), 15);
+        assert_eq!(edge_capacity(&[1, 3, 3], Some(5)), 13);
+        assert_eq!(edge_capacity(&[1, 1, 5], Some(4)), 6);
+        assert_eq!(edge_capacity(&[2, 2], Some(2)), 2);
+        assert_eq!(edge_capacity(&[5], None), 0);
+    }
+
+    #[test]
+    fn test_layered_graph_reaches_capacity() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for node_count in 1..=8 {
+            for layer_count in 1..=node_count {
+                let balanced: Vec<usize> = (0..layer_count)
+                    .map(|layer| (node_count + layer) / layer_count)
+                    .collect();
+                for maximum_degree in std::iter::once(None).chain((2..=node_count).map(Some)) {
+                    let capacity = edge_capacity(&balanced, maximum_degree);
+                    for edge_count in [capacity / 2, capacity] {
+                        let params = LayeredParameters {
+                            node_count,
+                            edge_count,
+                            layer_count,
+                            maximum_degree,
+                        };
+                        let graph = random_layered_graph(&params, &mut rng)
+                            .expect("should generate a graph for a feasible split");
+                        assert_eq!(graph.iter_edges().count(), edge_count);
+                        assert!(graph.is_acyclic());
+                        if let Some(maximum_degree) = maximum_degree {
+                            assert!(graph.maximum_degree() < maximum_degree);
+                        }
+                    }
+                    let params = LayeredParameters {
+                        node_count,
+                        edge_count: capacity + 1,
+                        layer_count,
+                        maximum_degree,
+                    };
+                    assert!(random_layered_graph(&params, &mut rng).is_err());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_layered_graph() {
+        let params = LayeredParameters {
+            node_count: 40,
+            edge_count: 70,
+            layer_count: 4,
+            maximum_degree: Some(10),
+        };
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let graph = random_layered_graph(&params, &mut rng).expect("should generate a graph");
+        assert_eq!(graph.node_count(), 40);
+        assert_eq!(graph.iter_edges().count(), 70);
+        assert!(graph.maximum_degree() < 10);
+        assert!(graph.is_acyclic());
+    }
+
+    #[test]
+    fn test_layered_graph_with_single_layer() {
+        let params = LayeredParameters {
+            node_count: 5,
+            edge_count: 1,
+            layer_count: 1,
+            maximum_degree: None,
+        };
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let result = random_layered_graph(&params, &mut rng);
+        assert_eq!(
+            result.err(),
+            Some(Error::EdgeCountNotReached {
+                requested: 1,
+                placed: 0
+            })
+        );
+    }
+}
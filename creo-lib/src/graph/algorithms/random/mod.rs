@@ -1,3 +1,89 @@
+mod edge_set;
+mod error;
 mod gnm;
+mod layered;
+mod scale_free;
+mod tree;
 
+use rand::seq::IteratorRandom;
+
+pub use error::{Error, Result};
 pub use gnm::{random_gnm_graph, GNMParameters};
+pub use layered::{random_layered_graph, LayeredParameters};
+pub use scale_free::{random_scale_free_graph, ScaleFreeParameters};
+pub use tree::{random_tree_graph, TreeDirection, TreeParameters};
+
+/// The number of proposals drawn for a single edge before falling back to an exhaustive search.
+const MAXIMUM_PROPOSALS: usize = 64;
+
+/// Returns the maximum number of edges of an acyclic graph with the given number of nodes, in which
+/// the degree of each node is less than the optional maximum degree.
+///
+/// Any simple undirected graph can be turned into an acyclic graph by directing its edges along an
+/// arbitrary order of its nodes. Since an undirected graph with `n` nodes and a degree of at most
+/// `k` exists for every number of edges up to `floor(n * k / 2)`, this bound is tight.
+pub fn maximum_edge_count(node_count: usize, maximum_degree: Option<usize>) -> usize {
+    let degree = node_count.saturating_sub(1);
+    let degree = match maximum_degree {
+        None => degree,
+        Some(maximum_degree) => degree.min(maximum_degree.saturating_sub(1)),
+    };
+    node_count * degree / 2
+}
+
+/// Returns [`Error::Infeasible`], if there is no acyclic graph with the given number of nodes and
+/// edges, in which the degree of each node is less than the optional maximum degree.
+fn check_feasibility(
+    node_count: usize,
+    edge_count: usize,
+    maximum_degree: Option<usize>,
+) -> Result<()> {
+    let maximum_edge_count = maximum_edge_count(node_count, maximum_degree);
+    if edge_count > maximum_edge_count {
+        return Err(Error::Infeasible {
+            node_count,
+            edge_count,
+            maximum_edge_count,
+        });
+    }
+    Ok(())
+}
+
+/// Inserts a single random edge into the given edge set.
+///
+/// The edge is first drawn from the model-specific `propose` function. If none of the proposals is
+/// accepted, the edge is drawn uniformly from all remaining edges `(source, target)` with
+/// `is_ordered(source, target)`, which guarantees termination even for dense graphs.
+///
+/// Returns false, if there is no valid edge left to insert.
+fn insert_random_edge<R, P, O>(
+    edges: &mut edge_set::EdgeSet,
+    node_count: usize,
+    rng: &mut R,
+    mut propose: P,
+    is_ordered: O,
+) -> bool
+where
+    R: rand::Rng,
+    P: FnMut(&mut R) -> (usize, usize),
+    O: Fn(usize, usize) -> bool,
+{
+    if node_count < 2 {
+        return false;
+    }
+    for _ in 0..MAXIMUM_PROPOSALS {
+        let (source, target) = propose(rng);
+        if is_ordered(source, target) && edges.insert(source, target) {
+            return true;
+        }
+    }
+
+    let candidate = (0..node_count)
+        .flat_map(|source| (0..node_count).map(move |target| (source, target)))
+        .filter(|&(source, target)| is_ordered(source, target) && edges.allows(source, target))
+        .choose(rng);
+    match candidate {
+        Some((source, target)) => edges.insert(source, target),
+        None => false,
+    }
+}
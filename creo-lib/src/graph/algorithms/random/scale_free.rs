@@ -0,0 +1,160 @@
+use rand::seq::index;
+
+use super::{edge_set::EdgeSet, Error, Result};
+use crate::graph;
+
+/// [`ScaleFreeParameters`] contains parameters for generating a random scale-free graph with `n`
+/// nodes and `m` edges.
+pub struct ScaleFreeParameters {
+    /// The number of nodes to generate.
+    pub node_count: usize,
+    /// The number of edges to generate.
+    pub edge_count: usize,
+    /// The optional maximum allowed degree of each node
+    pub maximum_degree: Option<usize>,
+}
+
+/// Generate a random, acyclic, scale-free graph with the given number of nodes and edges.
+///
+/// Optionally restrict the maximum degree in the graph.
+/// The graph grows by preferential attachment: Nodes are added one after another and each new node
+/// calls already existing nodes with a probability proportional to their in-degree plus one.
+/// Consequently, a few early nodes become heavily called hubs, while most nodes are only called
+/// rarely. The edges are spread evenly over the added nodes, with edges that cannot be placed
+/// being carried over to the next node.
+pub fn random_scale_free_graph<R: rand::Rng>(
+    params: &ScaleFreeParameters,
+    rng: &mut R,
+) -> Result<graph::DiGraph> {
+    super::check_feasibility(params.node_count, params.edge_count, params.maximum_degree)?;
+
+    let node_count = params.node_count;
+    let mut edges = EdgeSet::new(node_count, params.edge_count, params.maximum_degree);
+    let mut in_degrees = vec![0_usize; node_count];
+
+    let quotas = distribute_edges(node_count, params.edge_count, rng);
+    let mut carry = 0;
+    for (source, quota) in quotas.into_iter().enumerate().skip(1) {
+        let wanted = quota + carry;
+        carry = wanted;
+        if wanted == 0 || !edges.has_capacity(source) {
+            continue;
+        }
+        let candidates: Vec<usize> = (0..source)
+            .filter(|&target| edges.allows(source, target))
+            .collect();
+        let amount = wanted.min(candidates.len());
+        let selection = index::sample_weighted(
+            rng,
+            candidates.len(),
+            |idx| (in_degrees[candidates[idx]] + 1) as f64,
+            amount,
+        )
+        .expect("all weights should be positive and finite");
+        for idx in selection {
+            let target = candidates[idx];
+            if edges.insert(source, target) {
+                in_degrees[target] += 1;
+                carry -= 1;
+            }
+        }
+    }
+
+    while edges.len() < params.edge_count {
+        let inserted = super::insert_random_edge(
+            &mut edges,
+            node_count,
+            rng,
+            |rng| {
+                let source = rng.gen_range(1..node_count);
+                (source, rng.gen_range(0..source))
+            },
+            |source, target| source > target,
+        );
+        if !inserted {
+            return Err(Error::EdgeCountNotReached {
+                requested: params.edge_count,
+                placed: edges.len(),
+            });
+        }
+    }
+
+    Ok(edges.into_graph())
+}
+
+/// Distributes the given number of edges as evenly as possible over all nodes, except the first.
+///
+/// Returns the number of outgoing edges for each node. The remainder of the division is assigned
+/// to randomly chosen nodes.
+fn distribute_edges<R: rand::Rng>(node_count: usize, edge_count: usize, rng: &mut R) -> Vec<usize> {
+    let mut quotas = vec![0; node_count];
+    if node_count < 2 {
+        return quotas;
+    }
+    let sources = node_count - 1;
+    let share = edge_count / sources;
+    for quota in quotas.iter_mut().skip(1) {
+        *quota = share;
+    }
+    for idx in index::sample(rng, sources, edge_count % sources) {
+        quotas[idx + 1] += 1;
+    }
+    quotas
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_scale_free_graph() {
+        let params = ScaleFreeParameters {
+            node_count: 50,
+            edge_count: 120,
+            maximum_degree: None,
+        };
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let graph = random_scale_free_graph(&params, &mut rng).expect("should generate a graph");
+        assert_eq!(graph.node_count(), 50);
+        assert_eq!(graph.iter_edges().count(), 120);
+        assert!(graph.is_acyclic());
+    }
+
+    #[test]
+    fn test_scale_free_graph_with_maximum_degree() {
+        let params = ScaleFreeParameters {
+            node_count: 30,
+            edge_count: 40,
+            maximum_degree: Some(5),
+        };
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let graph = random_scale_free_graph(&params, &mut rng).expect("should generate a graph");
+        assert_eq!(graph.iter_edges().count(), 40);
+        assert!(graph.maximum_degree() < 5);
+        assert!(graph.is_acyclic());
+    }
+
+    #[test]
+    fn test_scale_free_graph_with_too_many_edges() {
+        let params = ScaleFreeParameters {
+            node_count: 3,
+            edge_count: 4,
+            maximum_degree: None,
+        };
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let result = random_scale_free_graph(&params, &mut rng);
+        assert_eq!(
+            result.err(),
+            Some(Error::Infeasible {
+                node_count: 3,
+                edge_count: 4,
+                maximum_edge_count: 3,
+            })
+        );
+    }
+}
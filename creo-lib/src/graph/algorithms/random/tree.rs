@@ -0,0 +1,158 @@
+use rand::seq::SliceRandom;
+
+use super::{edge_set::EdgeSet, Error, Result};
+use crate::graph;
+
+/// [`TreeDirection`] specifies the direction of the edges of a random tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TreeDirection {
+    /// The edges point from the root(s) to the leaves, i.e., a root calls its children.
+    FanOut,
+    /// The edges point from the leaves to the root(s), i.e., the children call their root.
+    FanIn,
+}
+
+/// [`TreeParameters`] contains parameters for generating a random tree-like graph with `n` nodes
+/// and `m` edges.
+pub struct TreeParameters {
+    /// The number of nodes to generate.
+    pub node_count: usize,
+    /// The number of edges to generate.
+    pub edge_count: usize,
+    /// The direction of the tree edges.
+    pub direction: TreeDirection,
+    /// The optional maximum allowed degree of each node
+    pub maximum_degree: Option<usize>,
+}
+
+/// Generate a random, acyclic, tree-like graph with the given number of nodes and edges.
+///
+/// Optionally restrict the maximum degree in the graph.
+/// If there are less than `n - 1` edges, the graph is a forest with `n - m` roots. Otherwise, the
+/// graph is a random recursive tree, i.e., each node is attached to a uniformly chosen earlier
+/// node, extended by additional edges that give nodes more than one parent. For
+/// [`TreeDirection::FanIn`], all edges are reversed afterwards.
+pub fn random_tree_graph<R: rand::Rng>(
+    params: &TreeParameters,
+    rng: &mut R,
+) -> Result<graph::DiGraph> {
+    super::check_feasibility(params.node_count, params.edge_count, params.maximum_degree)?;
+
+    let node_count = params.node_count;
+    let mut edges = EdgeSet::new(node_count, params.edge_count, params.maximum_degree);
+    let root_count = match node_count {
+        0 => 0,
+        _ => node_count.saturating_sub(params.edge_count).max(1),
+    };
+
+    for child in root_count..node_count {
+        let parents: Vec<usize> = (0..child)
+            .filter(|&parent| edges.allows(parent, child))
+            .collect();
+        if let Some(&parent) = parents.choose(rng) {
+            edges.insert(parent, child);
+        }
+    }
+
+    while edges.len() < params.edge_count {
+        let inserted = super::insert_random_edge(
+            &mut edges,
+            node_count,
+            rng,
+            |rng| {
+                let child = rng.gen_range(1..node_count);
+                (rng.gen_range(0..child), child)
+            },
+            |parent, child| parent < child,
+        );
+        if !inserted {
+            return Err(Error::EdgeCountNotReached {
+                requested: params.edge_count,
+                placed: edges.len(),
+            });
+        }
+    }
+
+    let graph = edges.into_graph();
+    match params.direction {
+        TreeDirection::FanOut => Ok(graph),
+        TreeDirection::FanIn => Ok(reverse(&graph)),
+    }
+}
+
+/// Returns a copy of the given graph with all edges reversed.
+fn reverse(graph: &graph::DiGraph) -> graph::DiGraph {
+    let edges: Vec<graph::Edge> = graph
+        .iter_edges()
+        .map(|edge| (edge.target, edge.source).into())
+        .collect();
+    graph::DiGraphBuilder::with_node_and_edge_count(graph.node_count(), edges.len())
+        .add_nodes(graph.node_count())
+        .add_edges(&edges)
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_fan_out_tree() {
+        let params = TreeParameters {
+            node_count: 20,
+            edge_count: 19,
+            direction: TreeDirection::FanOut,
+            maximum_degree: None,
+        };
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let graph = random_tree_graph(&params, &mut rng).expect("should generate a graph");
+        assert_eq!(graph.iter_edges().count(), 19);
+        assert!(graph.is_acyclic());
+        let roots = graph
+            .iter_nodes()
+            .filter(|&node| graph.predecssors(node).count() == 0)
+            .count();
+        assert_eq!(roots, 1, "a tree should have exactly one root");
+    }
+
+    #[test]
+    fn test_fan_out_forest() {
+        let params = TreeParameters {
+            node_count: 20,
+            edge_count: 15,
+            direction: TreeDirection::FanOut,
+            maximum_degree: None,
+        };
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let graph = random_tree_graph(&params, &mut rng).expect("should generate a graph");
+        assert_eq!(graph.iter_edges().count(), 15);
+        assert!(graph
+            .iter_nodes()
+            .all(|node| graph.predecssors(node).count() <= 1));
+    }
+
+    #[test]
+    fn test_fan_in_tree_with_additional_edges() {
+        let params = TreeParameters {
+            node_count: 20,
+            edge_count: 30,
+            direction: TreeDirection::FanIn,
+            maximum_degree: Some(8),
+        };
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let graph = random_tree_graph(&params, &mut rng).expect("should generate a graph");
+        assert_eq!(graph.iter_edges().count(), 30);
+        assert!(graph.maximum_degree() < 8);
+        assert!(graph.is_acyclic());
+        let sinks = graph
+            .iter_nodes()
+            .filter(|&node| graph.successors(node).count() == 0)
+            .count();
+        assert_eq!(sinks, 1, "a fan-in tree should have exactly one sink");
+    }
+}
@@ -1,4 +1,14 @@
-use crate::programming_language::ProgrammingLanguage;
+use std::collections::HashMap;
+
+use crate::{
+    application::{CallPolicy, CallSemantics},
+    fault_injection::FaultInjection,
+    handler::KnobSetting,
+    metrics::Tracing,
+    programming_language::{Framework, ProgrammingLanguage},
+    selection::{BudgetReport, SelectionExplanation},
+    service_types::ServiceType,
+};
 
 use super::{
     algorithms::coloring::ColorIndex,
@@ -21,7 +31,7 @@ impl From<MicroServiceIndex> for ColorIndex {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct EndpointIndex(pub usize);
 
 impl From<NodeIndex> for EndpointIndex {
@@ -39,8 +49,17 @@ impl From<EndpointIndex> for NodeIndex {
 pub struct ApplicationGraph {
     pub(super) graph: ColoredGraph,
     pub(super) languages: Vec<ProgrammingLanguage>,
+    pub(super) frameworks: Vec<Framework>,
     pub(super) start_port: u32,
     pub(super) handler_definitions: Vec<std::path::PathBuf>,
+    pub(super) service_types: Vec<ServiceType>,
+    pub(super) call_semantics: HashMap<(EndpointIndex, EndpointIndex), CallSemantics>,
+    pub(super) call_policy: CallPolicy,
+    pub(super) fault_injection: Option<FaultInjection>,
+    pub(super) tracing: Option<Tracing>,
+    pub(super) selection_log: Vec<SelectionExplanation>,
+    pub(super) budget_report: Option<BudgetReport>,
+    pub(super) knob_settings: HashMap<EndpointIndex, KnobSetting>,
 }
 
 impl ApplicationGraph {
@@ -53,11 +72,82 @@ impl ApplicationGraph {
         Self {
             graph,
             languages,
+            frameworks: Vec::new(),
             start_port,
             handler_definitions,
+            service_types: Vec::new(),
+            call_semantics: HashMap::new(),
+            call_policy: CallPolicy::default(),
+            fault_injection: None,
+            tracing: None,
+            selection_log: Vec::new(),
+            budget_report: None,
+            knob_settings: HashMap::new(),
         }
     }
 
+    /// Records the framework of each microservice, indexed by the microservice's ID. Microservices
+    /// without an entry use the default framework of their programming language.
+    pub fn with_frameworks(mut self, frameworks: Vec<Framework>) -> Self {
+        self.frameworks = frameworks;
+        self
+    }
+
+    /// Records the service type of each microservice, indexed by the microservice's ID.
+    pub fn with_service_types(mut self, service_types: Vec<ServiceType>) -> Self {
+        self.service_types = service_types;
+        self
+    }
+
+    /// Records the semantics of the inter-service calls, indexed by the calling and called
+    /// endpoint. Calls without an entry are issued once, in parallel and unconditionally with the
+    /// application's call policy.
+    pub fn with_call_semantics(
+        mut self,
+        call_semantics: HashMap<(EndpointIndex, EndpointIndex), CallSemantics>,
+    ) -> Self {
+        self.call_semantics = call_semantics;
+        self
+    }
+
+    /// Records the call policy of all inter-service calls without explicit semantics.
+    pub fn with_call_policy(mut self, call_policy: CallPolicy) -> Self {
+        self.call_policy = call_policy;
+        self
+    }
+
+    /// Records the faults injected into the microservices.
+    pub fn with_fault_injection(mut self, fault_injection: Option<FaultInjection>) -> Self {
+        self.fault_injection = fault_injection;
+        self
+    }
+
+    /// Records the distributed tracing of the microservices.
+    pub fn with_tracing(mut self, tracing: Option<Tracing>) -> Self {
+        self.tracing = tracing;
+        self
+    }
+
+    /// Records why the handler function of each endpoint was selected, indexed by the endpoint's
+    /// ID.
+    pub fn with_selection_log(mut self, selection_log: Vec<SelectionExplanation>) -> Self {
+        self.selection_log = selection_log;
+        self
+    }
+
+    /// Records the predicted and budgeted total utilization of the application.
+    pub fn with_budget_report(mut self, budget_report: Option<BudgetReport>) -> Self {
+        self.budget_report = budget_report;
+        self
+    }
+
+    /// Records the values of the knobs of the handler function of each endpoint, indexed by the
+    /// endpoint. The knobs of endpoints without an entry are sent with each request.
+    pub fn with_knob_settings(mut self, knob_settings: HashMap<EndpointIndex, KnobSetting>) -> Self {
+        self.knob_settings = knob_settings;
+        self
+    }
+
     pub fn service_count(&self) -> usize {
         self.graph.color_count()
     }
@@ -93,6 +183,72 @@ impl ApplicationGraph {
         self.graph.graph.predecssors(endpoint.into()).count() == 0
     }
 
+    /// Returns the service type of the given microservice, if it was generated automatically.
+    pub fn get_service_type(&self, service: MicroServiceIndex) -> Option<&ServiceType> {
+        self.service_types.get(service.0)
+    }
+
+    /// Returns the semantics of the inter-service call from `source` to `target`.
+    pub fn get_call_semantics(
+        &self,
+        source: EndpointIndex,
+        target: EndpointIndex,
+    ) -> CallSemantics {
+        self.call_semantics
+            .get(&(source, target))
+            .copied()
+            .unwrap_or(CallSemantics {
+                policy: self.call_policy,
+                ..CallSemantics::default()
+            })
+    }
+
+    /// Returns the values of the knobs of the handler function of the given endpoint, if its knobs
+    /// are set.
+    pub fn get_knob_setting(&self, endpoint: EndpointIndex) -> Option<&KnobSetting> {
+        self.knob_settings.get(&endpoint)
+    }
+
+    /// Returns the parameters of the handler function of the given endpoint, which are sent with
+    /// each request, i.e., all parameters, whose knob is not set.
+    pub fn get_request_parameters<'a>(
+        &self,
+        endpoint: EndpointIndex,
+        function: &'a crate::handler::Function,
+    ) -> Vec<&'a crate::handler::Param> {
+        let setting = self.get_knob_setting(endpoint);
+        function
+            .signature
+            .parameters
+            .iter()
+            .filter(|param| setting.and_then(|setting| setting.get(&param.arg)).is_none())
+            .collect()
+    }
+
+    /// Returns the faults injected into the microservices, if any.
+    pub fn get_fault_injection(&self) -> Option<&FaultInjection> {
+        self.fault_injection.as_ref()
+    }
+
+    /// Returns the distributed tracing of the microservices, if it is enabled.
+    pub fn get_tracing(&self) -> Option<&Tracing> {
+        self.tracing.as_ref()
+    }
+
+    /// Returns why the handler function of the given endpoint was selected, if it was selected by
+    /// its distance to the target vector of its service type.
+    pub fn get_selection_explanation(
+        &self,
+        endpoint: EndpointIndex,
+    ) -> Option<&SelectionExplanation> {
+        self.selection_log.get(endpoint.0)
+    }
+
+    /// Returns the predicted and budgeted total utilization, if the application has a budget.
+    pub fn get_budget_report(&self) -> Option<&BudgetReport> {
+        self.budget_report.as_ref()
+    }
+
     pub fn get_host_env_var(&self, service: MicroServiceIndex) -> String {
         format!("HOST_SERVICE_{}", service.0)
     }
@@ -22,11 +22,21 @@ impl<'graph> Iterator for MicroServiceView<'graph> {
     fn next(&mut self) -> Option<Self::Item> {
         match self.color_view.next() {
             None => None,
-            Some(color_index) => Some(MicroService::new(
-                color_index.into(),
-                self.graph.languages[color_index.0],
-                self.graph.start_port + (color_index.0 as u32),
-            )),
+            Some(color_index) => {
+                let language = self.graph.languages[color_index.0];
+                let framework = self
+                    .graph
+                    .frameworks
+                    .get(color_index.0)
+                    .copied()
+                    .unwrap_or_else(|| language.default_framework());
+                Some(MicroService::new(
+                    color_index.into(),
+                    language,
+                    framework,
+                    self.graph.start_port + (color_index.0 as u32),
+                ))
+            }
         }
     }
 }
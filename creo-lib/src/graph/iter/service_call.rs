@@ -6,6 +6,7 @@ use crate::{
 use super::Successors;
 
 pub struct ServiceCallView<'graph> {
+    graph: &'graph ApplicationGraph,
     successor: Successors<'graph>,
     endpoint: EndpointIndex,
 }
@@ -13,6 +14,7 @@ pub struct ServiceCallView<'graph> {
 impl<'graph> ServiceCallView<'graph> {
     pub fn new(graph: &'graph ApplicationGraph, endpoint: EndpointIndex) -> Self {
         Self {
+            graph,
             successor: graph.graph.graph.successors(endpoint.into()),
             endpoint,
         }
@@ -23,9 +25,8 @@ impl<'graph> Iterator for ServiceCallView<'graph> {
     type Item = ServiceCallEdge;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.successor.next() {
-            None => None,
-            Some(node_index) => Some(ServiceCallEdge::new(self.endpoint, node_index.into())),
-        }
+        let target = self.successor.next()?.into();
+        let semantics = self.graph.get_call_semantics(self.endpoint, target);
+        Some(ServiceCallEdge::new(self.endpoint, target).with_semantics(semantics))
     }
 }
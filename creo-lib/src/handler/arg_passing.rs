@@ -1,5 +1,5 @@
 /// PassingType represents the expected type of argument passing of a particular parameter.
-#[derive(serde::Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[derive(serde::Deserialize, serde::Serialize, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
 #[serde(untagged, remote = "Self")]
 pub enum PassingType {
     /// The Pos variant refers to passing a positional argument.
@@ -31,6 +31,15 @@ impl<'de> serde::Deserialize<'de> for PassingType {
     }
 }
 
+impl serde::Serialize for PassingType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        Self::serialize(self, serializer)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
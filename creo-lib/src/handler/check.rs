@@ -0,0 +1,403 @@
+use std::fmt::Display;
+
+use strum::IntoEnumIterator;
+
+use crate::{
+    programming_language::ProgrammingLanguage,
+    schema::{Schema, SchemaKind, Type},
+    service_types::{Label, Utilization},
+    template,
+};
+
+use super::Function;
+
+/// The labels, which handler functions profiled by older versions of Creo do not contain.
+const OPTIONAL_LABELS: [Label; 1] = [Label::Latency];
+
+/// [`Severity`] specifies, whether a [`Finding`] prevents the handler function from being used.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    /// The handler function can be used, but may not behave as expected.
+    Warning,
+    /// The handler function breaks the generation or the generated application.
+    Error,
+}
+
+/// [`Finding`] is a single problem of a handler function directory.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Finding {
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Finding {
+    fn error(message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Error,
+            message: message.into(),
+        }
+    }
+
+    fn warning(message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for Finding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.severity {
+            Severity::Warning => write!(f, "warning: {}", self.message),
+            Severity::Error => write!(f, "error: {}", self.message),
+        }
+    }
+}
+
+/// Checks the handler function in the given directory, i.e., that
+///
+/// - its definition file parses, including the names of its dependencies,
+/// - the schemas of its parameters are supported by the fakers and the load generator,
+/// - the function of its signature is declared in its source files,
+/// - its utilization file parses and contains all labels, unless it is marked as unprofiled,
+/// - the profiled knob settings match the knobs of its definition file.
+pub fn check_handler_function(
+    handler_dir: impl AsRef<std::path::Path>,
+    lang: &ProgrammingLanguage,
+) -> Vec<Finding> {
+    let handler_dir = handler_dir.as_ref();
+    let mut findings = Vec::new();
+
+    let function = match crate::io::parse_handler_function(handler_dir) {
+        Ok(function) => {
+            for param in &function.signature.parameters {
+                check_schema(&param.as_name(), &param.schema, &mut findings);
+            }
+            findings.extend(check_function_declaration(handler_dir, &function, lang));
+            Some(function)
+        }
+        Err(err) => {
+            findings.push(Finding::error(format!("invalid definition file: {}", err)));
+            None
+        }
+    };
+
+    match crate::io::parse_utilization_file(handler_dir) {
+        Ok(utilization) => {
+            check_utilization(&utilization, &mut findings);
+            if let Some(function) = &function {
+                findings.extend(check_knobs(function, &utilization));
+            }
+        }
+        Err(err) => findings.push(Finding::error(format!("invalid utilization file: {}", err))),
+    }
+
+    findings
+}
+
+fn check_knobs(function: &Function, utilization: &Utilization) -> Option<Finding> {
+    if function.knobs.is_empty() || utilization.unprofiled {
+        return None;
+    }
+    if utilization.knobs.is_empty() {
+        return Some(Finding::warning(
+            "the knobs were not profiled, the handler function is selected without setting them",
+        ));
+    }
+    let settings = function.knob_settings();
+    utilization
+        .knobs
+        .iter()
+        .find(|knob| !settings.contains(&knob.setting))
+        .map(|knob| {
+            Finding::error(format!(
+                "the profiled knob setting {} is not declared by the definition file",
+                knob.setting
+            ))
+        })
+}
+
+fn check_utilization(utilization: &Utilization, findings: &mut Vec<Finding>) {
+    if utilization.unprofiled {
+        findings.push(Finding::warning(
+            "the handler function was not profiled yet and is skipped by the handler selection",
+        ));
+        return;
+    }
+    for label in Label::iter() {
+        match utilization.averages.get(&label) {
+            None if OPTIONAL_LABELS.contains(&label) => findings.push(Finding::warning(format!(
+                "missing {} utilization, the handler function cannot be selected by it",
+                label
+            ))),
+            None => findings.push(Finding::error(format!("missing {} utilization", label))),
+            Some(value) if !value.is_finite() || *value < 0.0 => {
+                findings.push(Finding::error(format!(
+                    "expected a non-negative {} utilization, but was {}",
+                    label, value
+                )))
+            }
+            Some(_) => (),
+        }
+    }
+}
+
+fn check_function_declaration(
+    handler_dir: &std::path::Path,
+    function: &Function,
+    lang: &ProgrammingLanguage,
+) -> Option<Finding> {
+    let name = &function.signature.function;
+    let mut sources = Vec::new();
+    if let Err(err) = collect_source_files(handler_dir, lang.source_file_extension(), &mut sources)
+    {
+        return Some(Finding::error(format!(
+            "failed to read the source files: {}",
+            err
+        )));
+    }
+    let declaration = sources.iter().find_map(|path| {
+        let source = std::fs::read_to_string(path).ok()?;
+        lang.find_function_declaration(&source, name)
+    });
+    match declaration {
+        None => Some(Finding::error(format!(
+            "function `{}` is not declared in any .{} file",
+            name,
+            lang.source_file_extension()
+        ))),
+        Some(declaration) if declaration.is_async != function.is_async => {
+            Some(Finding::error(format!(
+                "function `{}` is declared {}, but the definition file specifies `is_async: {}`",
+                name,
+                if declaration.is_async {
+                    "async"
+                } else {
+                    "synchronous"
+                },
+                function.is_async
+            )))
+        }
+        Some(_) => None,
+    }
+}
+
+/// Collects all files with the given extension in the directory, skipping hidden and build
+/// directories.
+fn collect_source_files(
+    dir: &std::path::Path,
+    extension: &str,
+    files: &mut Vec<std::path::PathBuf>,
+) -> std::io::Result<()> {
+    for entry in dir.read_dir()? {
+        let path = entry?.path();
+        if path.is_dir() {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            if !name.starts_with('.') && name != "target" {
+                collect_source_files(&path, extension, files)?;
+            }
+        } else if path.extension().is_some_and(|ext| ext == extension) {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the fakers and the load generator can generate values within the bounds of the
+/// schema. Both fall back to default bounds, if a bound is missing.
+fn check_schema(name: &str, schema: &Schema, findings: &mut Vec<Finding>) {
+    let SchemaKind::Type(type_schema) = &schema.schema_kind;
+    match type_schema {
+        Type::String(string_type) => {
+            let min = string_type.min_length.unwrap_or_default();
+            match string_type.max_length {
+                Some(0) => findings.push(Finding::error(format!(
+                    "parameter `{}` has a maxLength of 0",
+                    name
+                ))),
+                Some(max) if min > max => findings.push(Finding::error(format!(
+                    "parameter `{}` has a minLength {} greater than its maxLength {}",
+                    name, min, max
+                ))),
+                None if min > template::DEFAULT_MAX_STRING_LENGTH => {
+                    findings.push(Finding::error(format!(
+                        "parameter `{}` has a minLength {} greater than the default maxLength {}, please specify a maxLength",
+                        name,
+                        min,
+                        template::DEFAULT_MAX_STRING_LENGTH
+                    )))
+                }
+                _ => (),
+            }
+        }
+        Type::Number(number_type) => {
+            let min = number_type.minimum.unwrap_or_default();
+            let max = number_type.maximum.unwrap_or(template::DEFAULT_MAX_NUMBER);
+            if min > max {
+                findings.push(bound_error(
+                    name,
+                    "minimum",
+                    min,
+                    "maximum",
+                    max,
+                    number_type.maximum.is_none(),
+                ));
+            }
+        }
+        Type::Integer(integer_type) => {
+            let min = integer_type.minimum.unwrap_or_default();
+            let max = integer_type
+                .maximum
+                .unwrap_or(template::DEFAULT_MAX_INTEGER);
+            if min > max {
+                findings.push(bound_error(
+                    name,
+                    "minimum",
+                    min,
+                    "maximum",
+                    max,
+                    integer_type.maximum.is_none(),
+                ));
+            }
+        }
+        Type::Array(array_type) => {
+            if let (Some(min), Some(max)) = (array_type.min_items, array_type.max_items) {
+                if min > max {
+                    findings.push(bound_error(name, "minItems", min, "maxItems", max, false));
+                }
+            }
+            check_schema(&format!("{}[]", name), &array_type.items, findings);
+        }
+        Type::Object(object_type) => {
+            for (property, schema) in &object_type.properties {
+                check_schema(&format!("{}.{}", name, property), schema, findings);
+            }
+        }
+        Type::Boolean(_) => (),
+    }
+}
+
+fn bound_error(
+    name: &str,
+    min_key: &str,
+    min: impl Display,
+    max_key: &str,
+    max: impl Display,
+    is_default: bool,
+) -> Finding {
+    if is_default {
+        Finding::error(format!(
+            "parameter `{}` has a {} {} greater than the default {} {}, please specify a {}",
+            name, min_key, min, max_key, max, max_key
+        ))
+    } else {
+        Finding::error(format!(
+            "parameter `{}` has a {} {} greater than its {} {}",
+            name, min_key, min, max_key, max
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::de::FromYamlStr;
+
+    #[test]
+    fn test_check_schema() {
+        let function = Function::from_yaml_str(
+            "
+            import_path: handler
+            is_async: false
+            signature:
+              function: handler
+              parameters:
+                - arg: 0
+                  type: string
+                  minLength: 8
+                  maxLength: 4
+                - arg: 1
+                  type: integer
+                  minimum: 10000
+                - arg: 2
+                  type: object
+                  properties:
+                    ok:
+                      type: number
+                      minimum: 1
+                      maximum: 2
+                    empty:
+                      type: string
+                      maxLength: 0
+            ",
+        )
+        .unwrap();
+        let mut findings = Vec::new();
+        for param in &function.signature.parameters {
+            check_schema(&param.as_name(), &param.schema, &mut findings);
+        }
+        assert_eq!(findings.len(), 3, "unexpected findings: {:?}", findings);
+        assert!(findings[2].message.contains("positional2.empty"));
+    }
+
+    #[test]
+    fn test_check_utilization() {
+        let utilization: Utilization = serde_yaml::from_str(
+            "{ CPU: 1.0, MEMORY: -1.0, NETWORK_RECEIVE: 1.0, NETWORK_TRANSMIT: 1.0, DISK_READ: 1.0 }",
+        )
+        .unwrap();
+        let mut findings = Vec::new();
+        check_utilization(&utilization, &mut findings);
+        let severities: Vec<_> = findings.iter().map(|f| f.severity).collect();
+        assert_eq!(
+            severities,
+            vec![Severity::Error, Severity::Error, Severity::Warning],
+            "unexpected findings: {:?}",
+            findings
+        );
+    }
+
+    #[test]
+    fn test_check_knobs() {
+        let function = Function::from_yaml_str(
+            "
+            import_path: handler
+            is_async: false
+            signature:
+              function: handler
+              parameters:
+                - arg: 0
+                  type: integer
+                  minimum: 1
+                  maximum: 50
+            knobs:
+              - arg: 0
+                minimum: 10
+                maximum: 20
+                steps: 2
+            ",
+        )
+        .unwrap();
+        let unprofiled: Utilization = serde_yaml::from_str("{ CPU: 1.0 }").unwrap();
+        assert_eq!(
+            check_knobs(&function, &unprofiled).map(|f| f.severity),
+            Some(Severity::Warning)
+        );
+
+        let utilization: Utilization = serde_yaml::from_str(
+            "
+            CPU: 1.0
+            knobs:
+              - setting: [{ arg: 0, value: 10.0 }]
+                utilization: { CPU: 0.5 }
+              - setting: [{ arg: 0, value: 15.0 }]
+                utilization: { CPU: 1.5 }
+            ",
+        )
+        .unwrap();
+        let finding = check_knobs(&function, &utilization).unwrap();
+        assert_eq!(finding.severity, Severity::Error);
+        assert!(finding.message.contains("[0=15]"), "{}", finding);
+    }
+}
@@ -1,37 +1,91 @@
 use std::{collections::HashMap, fmt::Display};
 
-use crate::service_types::{Label, Utilization};
+use crate::service_types::{Label, Utilization, UtilizationCurve};
 
-use super::Function;
+use super::{Function, KnobSetting};
 
 #[derive(Clone, Debug)]
 pub struct Definition {
     pub directory: std::path::PathBuf,
     pub utilization: HashMap<Label, f64>,
+    pub curves: HashMap<Label, UtilizationCurve>,
+    /// The values of the handler function's knobs, which were profiled with the utilization.
+    pub knobs: KnobSetting,
 }
 
 impl Definition {
     pub fn new<P: AsRef<std::path::Path>>(handler_dir: P, utilization: Utilization) -> Self {
         Self {
             directory: handler_dir.as_ref().to_path_buf(),
-            utilization: utilization.0,
+            utilization: utilization.averages,
+            curves: utilization.curves,
+            knobs: KnobSetting::default(),
         }
     }
 
+    /// Returns a definition for each profiled knob setting of the handler function, or a single
+    /// definition, if the knobs of the handler function were not profiled.
+    pub fn with_knob_settings<P: AsRef<std::path::Path>>(
+        handler_dir: P,
+        mut utilization: Utilization,
+    ) -> Vec<Self> {
+        if utilization.knobs.is_empty() {
+            return vec![Self::new(handler_dir, utilization)];
+        }
+        std::mem::take(&mut utilization.knobs)
+            .into_iter()
+            .map(|knob| Self {
+                knobs: knob.setting,
+                ..Self::new(handler_dir.as_ref(), knob.utilization)
+            })
+            .collect()
+    }
+
+    /// Returns the name of the handler function, including its knob setting, if any.
+    pub fn name(&self) -> String {
+        let name = self
+            .directory
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        if self.knobs.is_empty() {
+            name
+        } else {
+            format!("{}{}", name, self.knobs)
+        }
+    }
+
+    /// Returns the definition with the utilization it is predicted to show at the given request
+    /// rate. Labels without a profiled [`UtilizationCurve`] keep their average utilization.
+    pub fn at_request_rate(&self, rps: f64) -> Self {
+        let mut this = self.clone();
+        for (label, curve) in &self.curves {
+            this.utilization.insert(label.clone(), curve.predict(rps));
+        }
+        this
+    }
+
+    /// Returns whether the utilization of the given resource was profiled.
+    pub fn has_utilization(&self, resource: &Label) -> bool {
+        self.utilization.contains_key(resource)
+    }
+
+    /// Returns the profiled utilization of the given resource.
+    pub fn get_utilization(&self, resource: &Label) -> f64 {
+        *self
+            .utilization
+            .get(resource)
+            .unwrap_or_else(|| assert_utilization(self, resource))
+    }
+
     pub fn compare_by_resource_type(
         &self,
         other: &Definition,
         resource: &Label,
     ) -> std::cmp::Ordering {
-        let self_util = self
-            .utilization
-            .get(resource)
-            .unwrap_or_else(|| assert_utilization(self, resource));
-        let other_util = other
-            .utilization
-            .get(resource)
-            .unwrap_or_else(|| assert_utilization(other, resource));
-        self_util.partial_cmp(other_util).unwrap_or_else(|| {
+        let self_util = self.get_utilization(resource);
+        let other_util = other.get_utilization(resource);
+        self_util.partial_cmp(&other_util).unwrap_or_else(|| {
             panic!(
                 "non NAN value for utilization of resource {} for {} and {}",
                 resource, self_util, other_util
@@ -66,7 +120,7 @@ impl TryInto<Function> for &Definition {
 
 impl PartialEq for Definition {
     fn eq(&self, other: &Self) -> bool {
-        self.directory == other.directory
+        self.directory == other.directory && self.knobs == other.knobs
     }
 }
 
@@ -74,7 +128,16 @@ impl Eq for Definition {}
 
 impl Display for Definition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Definition ({})", self.directory.display())
+        if self.knobs.is_empty() {
+            write!(f, "Definition ({})", self.directory.display())
+        } else {
+            write!(
+                f,
+                "Definition ({} with knobs {})",
+                self.directory.display(),
+                self.knobs
+            )
+        }
     }
 }
 
@@ -89,10 +152,14 @@ mod tests {
         let one = Definition {
             directory: std::path::PathBuf::from("test/path/1"),
             utilization: HashMap::from_iter([(service_types::Label::Cpu, 0.5)]),
+            curves: HashMap::default(),
+            knobs: Default::default(),
         };
         let two = Definition {
             directory: std::path::PathBuf::from("test/path/2"),
             utilization: HashMap::from_iter([(service_types::Label::Cpu, 1.5)]),
+            curves: HashMap::default(),
+            knobs: Default::default(),
         };
 
         let cmp = one.compare_by_resource_type(&two, &service_types::Label::Cpu);
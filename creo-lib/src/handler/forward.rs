@@ -0,0 +1,19 @@
+/// [`Forward`] is the part of the result of a handler function, which is forwarded as an argument
+/// of a downstream service call instead of a fake value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Forward {
+    /// The whole result is forwarded.
+    Result,
+    /// The given required property of an object result is forwarded.
+    Property(String),
+}
+
+impl Forward {
+    /// Returns the forwarded property, if only a property of the result is forwarded.
+    pub fn property(&self) -> Option<&str> {
+        match self {
+            Self::Result => None,
+            Self::Property(name) => Some(name),
+        }
+    }
+}
@@ -1,8 +1,13 @@
-use crate::{dependencies::Dependency, http_method::HTTPMethod};
+use crate::{dependencies::Dependency, http_method::HTTPMethod, schema};
 
-use super::signature::Signature;
+use super::{
+    knob::{Knob, KnobSetting, KnobValue, MAX_KNOB_SETTINGS},
+    signature::Signature,
+    Forward, Param,
+};
 
 #[derive(serde::Deserialize, Clone, Debug)]
+#[serde(remote = "Self")]
 pub struct Function {
     pub import_path: String,
     #[serde(default)]
@@ -13,10 +18,171 @@ pub struct Function {
     pub depends_on: Vec<Dependency>,
     #[serde(default)]
     pub returns: bool,
+    #[serde(default)]
+    pub knobs: Vec<Knob>,
+    /// The HTTP method of the endpoints serving the handler function. Defaults to POST, if the
+    /// handler function has an object or array parameter, and to GET otherwise.
+    #[serde(default)]
+    pub method: Option<HTTPMethod>,
+}
+
+impl<'de> serde::Deserialize<'de> for Function {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let this = Self::deserialize(deserializer)?;
+        if this.signature.returns.is_some() && !this.returns {
+            return Err(serde::de::Error::custom(
+                "expected `returns: true` for a handler function with a return schema",
+            ));
+        }
+        let method = this.get_http_method();
+        if !method.allows_body()
+            && this
+                .signature
+                .parameters
+                .iter()
+                .any(|p| !p.is_primitive_type())
+        {
+            return Err(serde::de::Error::custom(format!(
+                "expected no object or array parameter for a handler function served by {} requests",
+                method
+            )));
+        }
+        for (idx, knob) in this.knobs.iter().enumerate() {
+            if this.knobs[..idx].iter().any(|other| other.arg == knob.arg) {
+                return Err(serde::de::Error::custom(format!(
+                    "expected at most one knob per parameter, but found multiple knobs for parameter {:?}",
+                    knob.arg
+                )));
+            }
+            let param = this.get_knob_parameter(knob).ok_or_else(|| {
+                serde::de::Error::custom(format!(
+                    "expected knob to tune a parameter of the signature, but found no parameter {:?}",
+                    knob.arg
+                ))
+            })?;
+            validate_knob(knob, param).map_err(serde::de::Error::custom)?;
+        }
+        let settings = this
+            .knobs
+            .iter()
+            .map(|knob| knob.steps.max(1))
+            .try_fold(1usize, |acc, steps| acc.checked_mul(steps))
+            .unwrap_or(usize::MAX);
+        if settings > MAX_KNOB_SETTINGS {
+            return Err(serde::de::Error::custom(format!(
+                "expected at most {} knob settings, but the knobs have {} settings",
+                MAX_KNOB_SETTINGS, settings
+            )));
+        }
+        Ok(this)
+    }
+}
+
+fn validate_knob(knob: &Knob, param: &Param) -> Result<(), String> {
+    if !knob.minimum.is_finite() || !knob.maximum.is_finite() || knob.minimum > knob.maximum {
+        return Err(format!(
+            "expected a finite knob range with minimum <= maximum for parameter {}, but was [{}, {}]",
+            param.as_name(),
+            knob.minimum,
+            knob.maximum
+        ));
+    }
+    let schema::SchemaKind::Type(type_schema) = &param.schema.schema_kind;
+    let (minimum, maximum) = match type_schema {
+        schema::Type::Integer(integer) => (
+            integer.minimum.map(|min| min as f64),
+            integer.maximum.map(|max| max as f64),
+        ),
+        schema::Type::Number(number) => (number.minimum, number.maximum),
+        _ => {
+            return Err(format!(
+                "expected knob to tune an integer or number parameter, but parameter {} is not",
+                param.as_name()
+            ))
+        }
+    };
+    if minimum.is_some_and(|min| knob.minimum < min)
+        || maximum.is_some_and(|max| knob.maximum > max)
+    {
+        return Err(format!(
+            "expected the knob range [{}, {}] to be within the bounds of parameter {}",
+            knob.minimum,
+            knob.maximum,
+            param.as_name()
+        ));
+    }
+    Ok(())
 }
 
 impl Function {
+    fn get_knob_parameter(&self, knob: &Knob) -> Option<&Param> {
+        self.signature
+            .parameters
+            .iter()
+            .find(|param| param.arg == knob.arg)
+    }
+
+    /// Returns all combinations of the values of the knobs, or no setting, if the handler function
+    /// has no knobs.
+    pub fn knob_settings(&self) -> Vec<KnobSetting> {
+        if self.knobs.is_empty() {
+            return Vec::new();
+        }
+        let mut settings = vec![KnobSetting::default()];
+        for knob in &self.knobs {
+            let is_integer = self.get_knob_parameter(knob).is_some_and(|param| {
+                matches!(
+                    param.schema.schema_kind,
+                    schema::SchemaKind::Type(schema::Type::Integer(_))
+                )
+            });
+            let values = knob.values(is_integer);
+            settings = settings
+                .into_iter()
+                .flat_map(|setting| {
+                    values.iter().map(move |value| {
+                        let mut setting = setting.clone();
+                        setting.0.push(KnobValue {
+                            arg: knob.arg.clone(),
+                            value: *value,
+                        });
+                        setting
+                    })
+                })
+                .collect();
+        }
+        settings
+    }
+
+    /// Returns the part of the result, which is a valid value of the given schema, if any.
+    ///
+    /// The whole result is preferred over its properties. Only required properties of an object
+    /// result are forwarded, such that the forwarded value is always present.
+    pub fn find_forward(&self, target: &schema::Schema) -> Option<Forward> {
+        let return_schema = self.signature.returns.as_ref()?;
+        if return_schema.is_compatible_with(target) {
+            return Some(Forward::Result);
+        }
+        if return_schema.schema_data.nullable {
+            return None;
+        }
+        let object_type = return_schema.get_object_schema()?;
+        object_type
+            .properties
+            .iter()
+            .find(|(name, property)| {
+                object_type.required.contains(name) && property.is_compatible_with(target)
+            })
+            .map(|(name, _)| Forward::Property(name.clone()))
+    }
+
     pub fn get_http_method(&self) -> HTTPMethod {
+        if let Some(method) = self.method {
+            return method;
+        }
         for param in &self.signature.parameters {
             if param.schema.get_object_schema().is_some() {
                 return HTTPMethod::Post;
@@ -33,7 +199,47 @@ impl Function {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::de::FromYamlStr;
+    use crate::{de::FromYamlStr, handler::PassingType};
+
+    #[test]
+    fn test_knob_settings() {
+        const INPUT: &str = "
+            import_path: matrix
+            is_async: false
+            signature:
+              function: invert_random_matrix
+              parameters:
+                - arg: 0
+                  type: integer
+                  minimum: 1
+                  maximum: 50
+                - arg: 1
+                  type: number
+            knobs:
+              - arg: 0
+                minimum: 10
+                maximum: 50
+                steps: 3
+              - arg: 1
+                minimum: 0.5
+                maximum: 1.0
+                steps: 2
+        ";
+        let function = Function::from_yaml_str(INPUT).unwrap();
+        let settings = function.knob_settings();
+        assert_eq!(settings.len(), 6);
+        assert_eq!(settings[0].get(&PassingType::Pos(0)), Some(10.0));
+        assert_eq!(settings[5].get(&PassingType::Pos(0)), Some(50.0));
+        assert_eq!(settings[5].get(&PassingType::Pos(1)), Some(1.0));
+
+        let out_of_bounds = INPUT.replace("maximum: 50\n                steps: 3", "maximum: 60");
+        assert!(Function::from_yaml_str(&out_of_bounds).is_err());
+        let unknown_param = INPUT.replace(
+            "- arg: 1\n                minimum: 0.5",
+            "- arg: 2\n                minimum: 0.5",
+        );
+        assert!(Function::from_yaml_str(&unknown_param).is_err());
+    }
 
     #[test]
     fn test_valid_handler_definition() {
@@ -82,4 +288,71 @@ mod tests {
                     - MONGO_PASSWORD=supers3cret";
         Function::from_yaml_str(INPUT).unwrap();
     }
+
+    #[test]
+    fn test_find_forward() {
+        const INPUT: &str = "
+            import_path: hash
+            is_async: false
+            returns: true
+            signature:
+              function: hash_password
+              parameters: []
+              returns:
+                type: object
+                properties:
+                  algorithm:
+                    type: string
+                    maxLength: 8
+                  hash:
+                    type: string
+                    minLength: 16
+                    maxLength: 32
+                required:
+                  - hash
+        ";
+        let function = Function::from_yaml_str(INPUT).unwrap();
+        let password: schema::Schema =
+            serde_yaml::from_str("{ type: string, minLength: 6, maxLength: 48 }").unwrap();
+        assert_eq!(
+            function.find_forward(&password),
+            Some(Forward::Property("hash".into()))
+        );
+        let object: schema::Schema = serde_yaml::from_str("{ type: object }").unwrap();
+        assert_eq!(function.find_forward(&object), Some(Forward::Result));
+        let integer: schema::Schema = serde_yaml::from_str("{ type: integer }").unwrap();
+        assert_eq!(function.find_forward(&integer), None);
+
+        let without_returns = INPUT.replace("returns: true", "returns: false");
+        assert!(Function::from_yaml_str(&without_returns).is_err());
+    }
+
+    #[test]
+    fn test_http_method() {
+        const INPUT: &str = "
+            import_path: update_user
+            is_async: true
+            signature:
+              function: update_user_by_id
+              parameters:
+                - arg: 0
+                  type: integer
+                  in: path
+                - arg: 1
+                  type: object
+        ";
+        let function = Function::from_yaml_str(INPUT).unwrap();
+        assert_eq!(function.get_http_method(), HTTPMethod::Post);
+
+        let put = INPUT.replace("is_async: true", "is_async: true\n            method: PUT");
+        assert_eq!(
+            Function::from_yaml_str(&put).unwrap().get_http_method(),
+            HTTPMethod::Put
+        );
+        let delete = INPUT.replace(
+            "is_async: true",
+            "is_async: true\n            method: DELETE",
+        );
+        assert!(Function::from_yaml_str(&delete).is_err());
+    }
 }
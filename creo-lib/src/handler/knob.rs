@@ -0,0 +1,129 @@
+use std::fmt::Display;
+
+use super::PassingType;
+
+/// The separator between the name of a handler function and the index of its knob setting in the
+/// names of the profiled services, e.g., `handler-matrix.knob-2`.
+pub const KNOB_SETTING_SEPARATOR: &str = ".knob-";
+
+/// The directory of a handler function containing the benchmark results of each knob setting.
+pub const KNOBS_DIR: &str = "knobs";
+
+/// The maximum number of knob settings of a handler function, as each setting is profiled
+/// separately.
+pub const MAX_KNOB_SETTINGS: usize = 32;
+
+fn default_steps() -> usize {
+    5
+}
+
+/// [`Knob`] declares a numeric parameter of a handler function, which tunes the work per request,
+/// e.g., the size of a matrix or the number of iterations.
+///
+/// The profiler sweeps `steps` evenly spaced values from `minimum` to `maximum`, such that the
+/// selection can choose the value hitting the requested resource level. The chosen value is passed
+/// to the handler function as a constant instead of being sent with each request.
+#[derive(serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct Knob {
+    /// The parameter tuned by the knob.
+    pub arg: PassingType,
+    pub minimum: f64,
+    pub maximum: f64,
+    /// The number of profiled values.
+    #[serde(default = "default_steps")]
+    pub steps: usize,
+}
+
+impl Knob {
+    /// Returns the profiled values of the knob. Values of integer parameters are rounded, and
+    /// duplicates are removed.
+    pub fn values(&self, is_integer: bool) -> Vec<f64> {
+        let mut values: Vec<f64> = if self.steps <= 1 || self.minimum == self.maximum {
+            vec![self.minimum]
+        } else {
+            let step = (self.maximum - self.minimum) / (self.steps - 1) as f64;
+            (0..self.steps)
+                .map(|i| self.minimum + step * i as f64)
+                .collect()
+        };
+        if is_integer {
+            values.iter_mut().for_each(|value| *value = value.round());
+            values.dedup();
+        }
+        values
+    }
+}
+
+/// [`KnobValue`] is the value of a single knob.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct KnobValue {
+    pub arg: PassingType,
+    pub value: f64,
+}
+
+/// [`KnobSetting`] assigns a value to each knob of a handler function. An empty setting passes all
+/// parameters with each request.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(transparent)]
+pub struct KnobSetting(pub Vec<KnobValue>);
+
+impl KnobSetting {
+    /// Returns the value of the knob tuning the given parameter, if any.
+    pub fn get(&self, arg: &PassingType) -> Option<f64> {
+        self.0
+            .iter()
+            .find(|knob| knob.arg == *arg)
+            .map(|knob| knob.value)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Compares the settings by their values in the order of the knobs.
+    pub fn total_cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0
+            .iter()
+            .zip(&other.0)
+            .map(|(a, b)| a.value.total_cmp(&b.value))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or_else(|| self.0.len().cmp(&other.0.len()))
+    }
+}
+
+impl Display for KnobSetting {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let values: Vec<_> = self
+            .0
+            .iter()
+            .map(|knob| match &knob.arg {
+                PassingType::Pos(pos) => format!("{}={}", pos, knob.value),
+                PassingType::Kw(name) => format!("{}={}", name, knob.value),
+            })
+            .collect();
+        write!(f, "[{}]", values.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_knob_values() {
+        let knob = Knob {
+            arg: PassingType::Pos(0),
+            minimum: 10.0,
+            maximum: 20.0,
+            steps: 3,
+        };
+        assert_eq!(knob.values(false), vec![10.0, 15.0, 20.0]);
+
+        let knob = Knob {
+            steps: 20,
+            maximum: 12.0,
+            ..knob
+        };
+        assert_eq!(knob.values(true), vec![10.0, 11.0, 12.0]);
+    }
+}
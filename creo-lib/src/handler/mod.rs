@@ -1,15 +1,25 @@
 mod arg_passing;
+mod check;
 mod definition;
+mod forward;
 mod function;
+mod knob;
 mod param;
 mod registry;
+mod scaffold;
 mod signature;
 
 pub use arg_passing::PassingType;
+pub use check::{check_handler_function, Finding, Severity};
 pub use definition::Definition;
+pub use forward::Forward;
 pub use function::Function;
-pub use param::Param;
+pub use knob::{
+    Knob, KnobSetting, KnobValue, KNOBS_DIR, KNOB_SETTING_SEPARATOR, MAX_KNOB_SETTINGS,
+};
+pub use param::{Param, ParamLocation};
 pub use registry::FunctionRegistry;
+pub use scaffold::{PrimitiveType, Scaffold, ScaffoldParameter};
 pub use signature::Signature;
 
 #[derive(thiserror::Error, Debug)]
@@ -18,4 +28,6 @@ pub enum Error {
     Io(#[from] std::io::Error),
     #[error("{0}")]
     Validation(#[from] serde_yaml::Error),
+    #[error("{0}")]
+    InvalidScaffold(String),
 }
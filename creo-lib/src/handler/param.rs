@@ -1,6 +1,32 @@
+/// [`ParamLocation`] specifies where a parameter is sent in a request, following the `in` field of
+/// OpenAPI parameters.
+#[derive(serde::Deserialize, serde::Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ParamLocation {
+    Path,
+    Query,
+    Header,
+    Body,
+}
+
+impl std::fmt::Display for ParamLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParamLocation::Path => write!(f, "path"),
+            ParamLocation::Query => write!(f, "query"),
+            ParamLocation::Header => write!(f, "header"),
+            ParamLocation::Body => write!(f, "body"),
+        }
+    }
+}
+
 #[derive(serde::Deserialize, PartialEq, Debug, Clone)]
 pub struct Param {
     pub arg: super::PassingType,
+    /// The location of the parameter in the request. Defaults to the query for primitive
+    /// parameters and to the body for object and array parameters.
+    #[serde(rename = "in", default)]
+    pub location: Option<ParamLocation>,
     #[serde(flatten)]
     pub schema: crate::schema::Schema,
 }
@@ -29,6 +55,20 @@ impl Param {
         }
     }
 
+    pub fn location(&self) -> ParamLocation {
+        self.location.unwrap_or(if self.is_primitive_type() {
+            ParamLocation::Query
+        } else {
+            ParamLocation::Body
+        })
+    }
+
+    /// Returns the name of the HTTP header, which carries the parameter, e.g., `user-id` for the
+    /// parameter `user_id`.
+    pub fn as_header_name(&self) -> String {
+        self.as_name().to_lowercase().replace('_', "-")
+    }
+
     pub fn as_name(&self) -> String {
         match &self.arg {
             super::PassingType::Kw(name) => name.clone(),
@@ -86,4 +126,18 @@ mod tests {
         let param = Param::from_yaml_str(INPUT).unwrap();
         assert!(param.schema.schema_data.nullable)
     }
+
+    #[test]
+    fn test_param_location() {
+        let param = Param::from_yaml_str("{ type: integer, arg: user_id }").unwrap();
+        assert_eq!(param.location(), ParamLocation::Query);
+
+        let param = Param::from_yaml_str("{ type: integer, arg: user_id, in: header }").unwrap();
+        assert_eq!(param.location(), ParamLocation::Header);
+        assert_eq!(param.as_header_name(), "user-id");
+
+        let param = Param::from_yaml_str("{ type: object, arg: 0 }").unwrap();
+        assert_eq!(param.location(), ParamLocation::Body);
+        assert!(Param::from_yaml_str("{ type: integer, arg: 0, in: cookie }").is_err());
+    }
 }
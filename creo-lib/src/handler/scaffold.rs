@@ -0,0 +1,532 @@
+use std::{fmt::Write, str::FromStr};
+
+use crate::{
+    generator,
+    programming_language::ProgrammingLanguage::{self, *},
+    service_types::UNPROFILED_KEY,
+    template,
+};
+
+use super::Error;
+
+/// [`PrimitiveType`] is a JSON type, which a scaffolded handler function can accept directly.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PrimitiveType {
+    String,
+    Integer,
+    Number,
+    Boolean,
+}
+
+impl FromStr for PrimitiveType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "string" => Ok(Self::String),
+            "integer" => Ok(Self::Integer),
+            "number" => Ok(Self::Number),
+            "boolean" => Ok(Self::Boolean),
+            _ => Err(format!(
+                "unsupported parameter type `{}`, expected one of string, integer, number, or boolean",
+                s
+            )),
+        }
+    }
+}
+
+/// [`ScaffoldParameter`] is a parameter of a scaffolded handler function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScaffoldParameter {
+    pub name: String,
+    pub data_type: PrimitiveType,
+}
+
+impl FromStr for ScaffoldParameter {
+    type Err = String;
+
+    /// Parses a parameter of the form `<name>:<type>`, e.g., `count:integer`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, data_type) = s.split_once(':').ok_or_else(|| {
+            format!(
+                "expected a parameter of the form <name>:<type>, got `{}`",
+                s
+            )
+        })?;
+        validate_identifier("parameter", name)?;
+        Ok(Self {
+            name: name.to_string(),
+            data_type: data_type.parse()?,
+        })
+    }
+}
+
+/// [`Scaffold`] describes the skeleton of a new handler function.
+#[derive(Clone, Debug)]
+pub struct Scaffold {
+    /// The name of the handler function directory, which is also the package name.
+    pub name: String,
+    /// The name of the handler function. Go handler functions are exported, i.e., their name is
+    /// converted to PascalCase, while the name of Java and Node.js handler functions is converted to
+    /// camelCase.
+    pub function: String,
+    pub description: Option<String>,
+    pub is_async: bool,
+    pub returns: bool,
+    pub parameters: Vec<ScaffoldParameter>,
+}
+
+impl Scaffold {
+    /// Writes the skeleton of the handler function into the given directory, which must not exist
+    /// yet.
+    ///
+    /// The skeleton comprises the source files for the programming language, the `definition`
+    /// file derived from the declared signature, and a placeholder `utilization` file, which marks
+    /// the handler function as unprofiled.
+    pub fn write(
+        &self,
+        handler_dir: impl AsRef<std::path::Path>,
+        lang: &ProgrammingLanguage,
+    ) -> Result<(), Error> {
+        validate_identifier("handler", &self.name).map_err(Error::InvalidScaffold)?;
+        validate_identifier("function", &self.function).map_err(Error::InvalidScaffold)?;
+        if self.is_async && matches!(lang, Go(_) | Java(_)) {
+            return Err(Error::InvalidScaffold(format!(
+                "{} handler functions cannot be async",
+                lang
+            )));
+        }
+
+        let handler_dir = handler_dir.as_ref();
+        std::fs::create_dir(handler_dir)?;
+        for (path, content) in self.source_files(lang) {
+            let path = handler_dir.join(path);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, content)?;
+        }
+        std::fs::write(
+            handler_dir.join("definition.yml"),
+            self.definition_file(lang),
+        )?;
+        std::fs::write(
+            handler_dir.join("utilization.yml"),
+            format!(
+                "# Placeholder, which is replaced by `creo profile aggregate`.\n\
+                 # The handler selection skips the handler function until it is profiled.\n\
+                 {}: true\n",
+                UNPROFILED_KEY
+            ),
+        )?;
+        Ok(())
+    }
+
+    fn definition_file(&self, lang: &ProgrammingLanguage) -> String {
+        let mut out = match lang {
+            Go(_) => format!(
+                "import_path: {}{}\n",
+                generator::go::HANDLER_MODULE_PREFIX,
+                self.name
+            ),
+            Java(_) => format!(
+                "import_path: {}{}.{}\n",
+                generator::java::HANDLER_PACKAGE_PREFIX,
+                self.name,
+                self.class_name()
+            ),
+            Node(_) => format!(
+                "import_path: \"{}{}\"\n",
+                generator::node::HANDLER_PACKAGE_SCOPE,
+                self.name
+            ),
+            _ => format!("import_path: {}\n", self.name),
+        };
+        if let Some(description) = &self.description {
+            writeln!(out, "description: {:?}", description).unwrap();
+        }
+        writeln!(out, "is_async: {}", self.is_async).unwrap();
+        writeln!(out, "returns: {}", self.returns).unwrap();
+        writeln!(out, "signature:").unwrap();
+        writeln!(out, "  function: {}", self.function_name(lang)).unwrap();
+        if self.parameters.is_empty() {
+            writeln!(out, "  parameters: []").unwrap();
+            return out;
+        }
+        writeln!(out, "  parameters:").unwrap();
+        for (arg, param) in self.parameters.iter().enumerate() {
+            writeln!(out, "    - arg: {}", arg).unwrap();
+            match param.data_type {
+                PrimitiveType::String => {
+                    writeln!(out, "      type: string").unwrap();
+                    writeln!(out, "      minLength: 1").unwrap();
+                    writeln!(
+                        out,
+                        "      maxLength: {}",
+                        template::DEFAULT_MAX_STRING_LENGTH
+                    )
+                    .unwrap();
+                }
+                PrimitiveType::Integer => {
+                    writeln!(out, "      type: integer").unwrap();
+                    if let Rust(_) | Go(_) | Java(_) = lang {
+                        writeln!(out, "      format: int64").unwrap();
+                    }
+                    writeln!(out, "      minimum: 0").unwrap();
+                    writeln!(out, "      maximum: {}", template::DEFAULT_MAX_INTEGER).unwrap();
+                }
+                PrimitiveType::Number => {
+                    writeln!(out, "      type: number").unwrap();
+                    if let Rust(_) | Go(_) | Java(_) = lang {
+                        writeln!(out, "      format: double").unwrap();
+                    }
+                    writeln!(out, "      minimum: 0").unwrap();
+                    writeln!(out, "      maximum: {}", template::DEFAULT_MAX_NUMBER).unwrap();
+                }
+                PrimitiveType::Boolean => writeln!(out, "      type: boolean").unwrap(),
+            }
+        }
+        out
+    }
+
+    fn source_files(&self, lang: &ProgrammingLanguage) -> Vec<(std::path::PathBuf, String)> {
+        let mapper = lang.as_data_type_mapper();
+        let params: Vec<_> = self
+            .parameters
+            .iter()
+            .map(|param| {
+                let data_type = match param.data_type {
+                    PrimitiveType::String => mapper.get_string_type(),
+                    PrimitiveType::Integer => mapper.get_signed_64_bit_integer_type(),
+                    PrimitiveType::Number => mapper.get_double_type(),
+                    PrimitiveType::Boolean => mapper.get_boolean_type(),
+                };
+                match lang {
+                    Go(_) => format!("{} {}", param.name, data_type),
+                    Java(_) => format!("{} {}", data_type, param.name),
+                    Node(_) => param.name.clone(),
+                    _ => format!("{}: {}", param.name, data_type),
+                }
+            })
+            .collect();
+        let params = params.join(", ");
+        let description = self
+            .description
+            .as_deref()
+            .unwrap_or("TODO: describe the handler function");
+
+        match lang {
+            Python(_) => {
+                let (return_type, body) = if self.returns {
+                    (" -> str", "    return \"\"\n")
+                } else {
+                    (" -> None", "    pass\n")
+                };
+                let module = format!(
+                    "{}def {}({}){}:\n    # TODO: implement the handler function\n{}",
+                    if self.is_async { "async " } else { "" },
+                    self.function,
+                    params,
+                    return_type,
+                    body
+                );
+                let package = std::path::Path::new("src").join(&self.name);
+                vec![
+                    (
+                        "pyproject.toml".into(),
+                        format!(
+                            "[build-system]\n\
+                             requires = [\"setuptools>=63.0\"]\n\
+                             build-backend = \"setuptools.build_meta\"\n\
+                             \n\
+                             [project]\n\
+                             name = \"{}\"\n\
+                             description = {:?}\n\
+                             version = \"1.0.0\"\n\
+                             requires-python = \">=3.9\"\n\
+                             dependencies = []\n",
+                            self.name, description
+                        ),
+                    ),
+                    (
+                        package.join("__init__.py"),
+                        format!(
+                            "from .handler import {} as {}\n",
+                            self.function, self.function
+                        ),
+                    ),
+                    (package.join("handler.py"), module),
+                ]
+            }
+            Rust(_) => {
+                let (return_type, body) = if self.returns {
+                    (" -> String", "    String::new()\n")
+                } else {
+                    ("", "")
+                };
+                let lib = format!(
+                    "pub {}fn {}({}){} {{\n    // TODO: implement the handler function\n{}}}\n",
+                    if self.is_async { "async " } else { "" },
+                    self.function,
+                    params,
+                    return_type,
+                    body
+                );
+                vec![
+                    (
+                        "Cargo.toml".into(),
+                        format!(
+                            "[package]\n\
+                             name = \"{}\"\n\
+                             version = \"0.1.0\"\n\
+                             edition = \"2021\"\n\
+                             \n\
+                             # See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html\n\
+                             \n\
+                             [dependencies]\n",
+                            self.name
+                        ),
+                    ),
+                    (std::path::Path::new("src").join("lib.rs"), lib),
+                ]
+            }
+            Go(_) => {
+                let (return_type, body) = if self.returns {
+                    (" string", "\treturn \"\"\n")
+                } else {
+                    ("", "")
+                };
+                let function = self.function_name(lang);
+                let source = format!(
+                    "package {}\n\n// {} {}\nfunc {}({}){} {{\n\t// TODO: implement the handler function\n{}}}\n",
+                    self.name, function, description, function, params, return_type, body
+                );
+                vec![
+                    (
+                        "go.mod".into(),
+                        format!(
+                            "module {}{}\n\ngo 1.22\n",
+                            generator::go::HANDLER_MODULE_PREFIX,
+                            self.name
+                        ),
+                    ),
+                    (format!("{}.go", self.name).into(), source),
+                ]
+            }
+            Java(_) => {
+                let (return_type, body) = if self.returns {
+                    ("String", "        return \"\";\n")
+                } else {
+                    ("void", "")
+                };
+                let class_name = self.class_name();
+                let source = format!(
+                    "package {}{};\n\n\
+                     public final class {} {{\n    \
+                     private {}() {{}}\n\n    \
+                     /** {} */\n    \
+                     public static {} {}({}) {{\n        \
+                     // TODO: implement the handler function\n{}    }}\n}}\n",
+                    generator::java::HANDLER_PACKAGE_PREFIX,
+                    self.name,
+                    class_name,
+                    class_name,
+                    description,
+                    return_type,
+                    self.function_name(lang),
+                    params,
+                    body
+                );
+                let package_dir =
+                    std::path::Path::new("src/main/java/creo/handlers").join(&self.name);
+                vec![
+                    (
+                        "pom.xml".into(),
+                        format!(
+                            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+                             <project xmlns=\"http://maven.apache.org/POM/4.0.0\" xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" xsi:schemaLocation=\"http://maven.apache.org/POM/4.0.0 https://maven.apache.org/xsd/maven-4.0.0.xsd\">\n  \
+                             <modelVersion>4.0.0</modelVersion>\n  \
+                             <groupId>{}</groupId>\n  \
+                             <artifactId>{}</artifactId>\n  \
+                             <version>{}</version>\n  \
+                             <description>{}</description>\n\n  \
+                             <properties>\n    \
+                             <maven.compiler.release>21</maven.compiler.release>\n    \
+                             <project.build.sourceEncoding>UTF-8</project.build.sourceEncoding>\n  \
+                             </properties>\n\n  \
+                             <dependencies>\n  \
+                             </dependencies>\n\
+                             </project>\n",
+                            generator::java::HANDLER_GROUP_ID,
+                            self.name,
+                            generator::java::HANDLER_VERSION,
+                            description
+                        ),
+                    ),
+                    (package_dir.join(format!("{}.java", class_name)), source),
+                ]
+            }
+            Node(_) => {
+                let body = if self.returns { "  return \"\";\n" } else { "" };
+                let source = format!(
+                    "/** {} */\nexport {}function {}({}) {{\n  // TODO: implement the handler function\n{}}}\n",
+                    description,
+                    if self.is_async { "async " } else { "" },
+                    self.function_name(lang),
+                    params,
+                    body
+                );
+                vec![
+                    (
+                        "package.json".into(),
+                        format!(
+                            "{{\n  \
+                             \"name\": \"{}{}\",\n  \
+                             \"version\": \"0.0.0\",\n  \
+                             \"description\": {:?},\n  \
+                             \"type\": \"module\",\n  \
+                             \"exports\": \"./index.js\",\n  \
+                             \"dependencies\": {{}}\n\
+                             }}\n",
+                            generator::node::HANDLER_PACKAGE_SCOPE,
+                            self.name,
+                            description
+                        ),
+                    ),
+                    ("index.js".into(), source),
+                ]
+            }
+        }
+    }
+
+    /// Returns the name of the handler function in the given programming language.
+    fn function_name(&self, lang: &ProgrammingLanguage) -> String {
+        match lang {
+            Go(_) => generator::go::to_pascal_case(&self.function),
+            Java(_) | Node(_) => generator::java::to_camel_case(&self.function),
+            _ => self.function.clone(),
+        }
+    }
+
+    /// Returns the name of the class declaring the Java handler function.
+    fn class_name(&self) -> String {
+        generator::go::to_pascal_case(&self.name)
+    }
+}
+
+/// Validates that the name is usable as package, module, and function name in all supported
+/// programming languages.
+fn validate_identifier(kind: &str, name: &str) -> Result<(), String> {
+    let mut chars = name.chars();
+    let is_valid = chars.next().is_some_and(|c| c.is_ascii_lowercase())
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if !is_valid {
+        return Err(format!(
+            "invalid {} name `{}`, expected lowercase letters, digits, and underscores starting with a letter",
+            kind, name
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{de::FromYamlStr, handler::Function};
+
+    fn scaffold() -> Scaffold {
+        Scaffold {
+            name: "greet".into(),
+            function: "greet_user".into(),
+            description: Some("Greets the \"user\".".into()),
+            is_async: true,
+            returns: true,
+            parameters: vec![
+                "name:string".parse().unwrap(),
+                "times:integer".parse().unwrap(),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_definition_file_parses() {
+        for lang in [Python(1), Rust(1)] {
+            let function = Function::from_yaml_str(&scaffold().definition_file(&lang)).unwrap();
+            assert_eq!(function.signature.function, "greet_user");
+            assert_eq!(function.signature.parameters.len(), 2);
+            assert_eq!(
+                function.description.as_deref(),
+                Some("Greets the \"user\".")
+            );
+            assert!(function.is_async);
+        }
+    }
+
+    #[test]
+    fn test_source_files_declare_function() {
+        for lang in [Python(1), Rust(1)] {
+            let files = scaffold().source_files(&lang);
+            let declaration = files
+                .iter()
+                .find_map(|(_, source)| lang.find_function_declaration(source, "greet_user"));
+            assert_eq!(declaration.map(|d| d.is_async), Some(true), "{:?}", files);
+        }
+    }
+
+    #[test]
+    fn test_sync_only_scaffold() {
+        for (lang, import_path, function_name) in [
+            (Go(1), "creo.local/handlers/greet", "GreetUser"),
+            (Java(1), "creo.handlers.greet.Greet", "greetUser"),
+        ] {
+            let sync_scaffold = Scaffold {
+                is_async: false,
+                ..scaffold()
+            };
+            let function = Function::from_yaml_str(&sync_scaffold.definition_file(&lang)).unwrap();
+            assert_eq!(function.import_path, import_path);
+            assert_eq!(function.signature.function, function_name);
+            let files = sync_scaffold.source_files(&lang);
+            let declaration = files
+                .iter()
+                .find_map(|(_, source)| lang.find_function_declaration(source, function_name));
+            assert_eq!(declaration.map(|d| d.is_async), Some(false), "{:?}", files);
+
+            let dir =
+                std::env::temp_dir().join(format!("creo-test-{}-scaffold", lang.as_dir_name()));
+            let err = scaffold().write(&dir, &lang).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!("{} handler functions cannot be async", lang)
+            );
+            assert!(!dir.exists());
+        }
+    }
+
+    #[test]
+    fn test_node_scaffold() {
+        let lang = Node(1);
+        let function = Function::from_yaml_str(&scaffold().definition_file(&lang)).unwrap();
+        assert_eq!(function.import_path, "@creo-handlers/greet");
+        assert_eq!(function.signature.function, "greetUser");
+        let files = scaffold().source_files(&lang);
+        let declaration = files
+            .iter()
+            .find_map(|(_, source)| lang.find_function_declaration(source, "greetUser"));
+        assert_eq!(declaration.map(|d| d.is_async), Some(true), "{:?}", files);
+    }
+
+    #[test]
+    fn test_parse_parameter() {
+        assert_eq!(
+            "count:integer".parse::<ScaffoldParameter>(),
+            Ok(ScaffoldParameter {
+                name: "count".into(),
+                data_type: PrimitiveType::Integer
+            })
+        );
+        assert!("count".parse::<ScaffoldParameter>().is_err());
+        assert!("Count:integer".parse::<ScaffoldParameter>().is_err());
+        assert!("count:object".parse::<ScaffoldParameter>().is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use super::param::Param;
+use super::param::{Param, ParamLocation};
 
 #[derive(serde::Deserialize, Clone, Debug)]
 #[serde(remote = "Self")]
@@ -6,6 +6,7 @@ pub struct Signature {
     pub function: String,
     #[serde(alias = "params", default)]
     pub parameters: Vec<Param>,
+    /// The schema of the result, which allows forwarding the result to downstream service calls.
     #[serde(default)]
     pub returns: Option<crate::schema::Schema>,
 }
@@ -47,12 +48,43 @@ impl<'de> serde::Deserialize<'de> for Signature {
                     "only exactly one parameter with type object or array is allowed",
                 ));
             }
+
+            validate_location(param).map_err(serde::de::Error::custom)?;
         }
 
         Ok(this)
     }
 }
 
+/// Validates that object and array parameters are sent in the body, and that parameters in the
+/// path or a header are always present.
+fn validate_location(param: &Param) -> Result<(), String> {
+    let location = param.location();
+    match location {
+        ParamLocation::Body if param.is_primitive_type() => Err(format!(
+            "expected only object or array parameters in the body, but parameter {} is neither",
+            param.as_name()
+        )),
+        ParamLocation::Path | ParamLocation::Query | ParamLocation::Header
+            if !param.is_primitive_type() =>
+        {
+            Err(format!(
+                "expected object or array parameter {} in the body, but was in the {}",
+                param.as_name(),
+                location
+            ))
+        }
+        ParamLocation::Path | ParamLocation::Header if param.schema.schema_data.nullable => {
+            Err(format!(
+                "expected {} parameter {} not to be nullable",
+                location,
+                param.as_name()
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::de::FromYamlStr;
@@ -127,4 +159,36 @@ mod tests {
             .is_err_and(|e| e.to_string()
                 == "only exactly one parameter with type object or array is allowed"));
     }
+
+    #[test]
+    fn test_parameter_locations() {
+        const INPUT: &str = "
+            function: function_name
+            parameters:
+                - type: integer
+                  arg: 0
+                  in: path
+                - type: string
+                  arg: 1
+                  in: header
+                - type: object
+                  arg: 2";
+        Signature::from_yaml_str(INPUT).unwrap();
+
+        let err = Signature::from_yaml_str(
+            &INPUT.replace("arg: 2", "arg: 2\n                  in: query"),
+        );
+        assert!(err.is_err_and(|e| e.to_string()
+            == "expected object or array parameter positional2 in the body, but was in the query"));
+
+        let err = Signature::from_yaml_str(&INPUT.replace("in: path", "in: body"));
+        assert!(err.is_err());
+
+        let err = Signature::from_yaml_str(
+            &INPUT.replace("in: header", "in: header\n                  nullable: true"),
+        );
+        assert!(err.is_err_and(
+            |e| e.to_string() == "expected header parameter positional1 not to be nullable"
+        ));
+    }
 }
@@ -1,7 +1,34 @@
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
 pub enum HTTPMethod {
     Get,
     Post,
+    Put,
+    Patch,
+    Delete,
+}
+
+impl HTTPMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HTTPMethod::Get => "GET",
+            HTTPMethod::Post => "POST",
+            HTTPMethod::Put => "PUT",
+            HTTPMethod::Patch => "PATCH",
+            HTTPMethod::Delete => "DELETE",
+        }
+    }
+
+    /// Returns whether requests with this method may have a body.
+    pub fn allows_body(&self) -> bool {
+        !matches!(self, HTTPMethod::Get | HTTPMethod::Delete)
+    }
+}
+
+impl std::fmt::Display for HTTPMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 impl serde::Serialize for HTTPMethod {
@@ -9,9 +36,6 @@ impl serde::Serialize for HTTPMethod {
     where
         S: serde::Serializer,
     {
-        match self {
-            HTTPMethod::Get => serializer.serialize_str("GET"),
-            HTTPMethod::Post => serializer.serialize_str("POST"),
-        }
+        serializer.serialize_str(self.as_str())
     }
 }
@@ -1,3 +1,10 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    fault_injection::FaultInjection, graph::ApplicationGraph, metrics::Tracing,
+    selection::BudgetReport,
+};
+
 pub fn create_application_directory(
     path: impl AsRef<std::path::Path>,
     meta_data: ApplicationMetaData<'_>,
@@ -25,6 +32,14 @@ pub struct ApplicationMetaData<'a> {
     pub application_name: &'a str,
     pub seed: &'a str,
     pub ports: Ports,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub language_mix: Vec<LanguageMix>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fault_injection: Option<&'a FaultInjection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracing: Option<&'a Tracing>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget: Option<&'a BudgetReport>,
 }
 
 #[derive(serde::Serialize)]
@@ -32,3 +47,88 @@ pub struct Ports {
     pub start: u32,
     pub end: u32,
 }
+
+/// [`LanguageMix`] describes the share of a programming language in a generated application.
+#[derive(serde::Serialize, Debug, PartialEq)]
+pub struct LanguageMix {
+    /// The name of the programming language.
+    pub language: &'static str,
+    /// The number of microservices implemented in the programming language.
+    pub services: usize,
+    /// The number of endpoints implemented in the programming language.
+    pub endpoints: usize,
+    /// The percentage of microservices implemented in the programming language.
+    pub percentage: f64,
+}
+
+impl std::fmt::Display for LanguageMix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} services ({:.1}%), {} endpoints",
+            self.language, self.services, self.percentage, self.endpoints
+        )
+    }
+}
+
+/// Returns the realized language mix of the given application, ordered by language name.
+pub fn language_mix(application: &ApplicationGraph) -> Vec<LanguageMix> {
+    let mut mix: BTreeMap<&'static str, (usize, usize)> = BTreeMap::new();
+    for service in application.iter_micro_services() {
+        let endpoints = application.iter_service_endpoints(service.id).count();
+        let entry = mix.entry(service.language.as_dir_name()).or_default();
+        entry.0 += 1;
+        entry.1 += endpoints;
+    }
+
+    let service_count = application.service_count();
+    mix.into_iter()
+        .map(|(language, (services, endpoints))| LanguageMix {
+            language,
+            services,
+            endpoints,
+            percentage: services as f64 * 100.0 / service_count as f64,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{graph, programming_language::ProgrammingLanguage};
+
+    #[test]
+    fn test_language_mix() {
+        let graph = graph::DiGraphBuilder::new().add_nodes(4).build();
+        let coloring = [0, 0, 1, 2]
+            .into_iter()
+            .map(graph::algorithms::coloring::ColorIndex)
+            .collect();
+        let graph = graph::ColoredGraphBuilder::new(graph, coloring, 3).build();
+        let languages = vec![
+            ProgrammingLanguage::Rust(30),
+            ProgrammingLanguage::Python(70),
+            ProgrammingLanguage::Python(70),
+        ];
+        let application =
+            ApplicationGraph::new(graph, languages, 8080, vec![Default::default(); 4]);
+
+        assert_eq!(
+            language_mix(&application),
+            [
+                LanguageMix {
+                    language: "python",
+                    services: 2,
+                    endpoints: 2,
+                    percentage: 200.0 / 3.0,
+                },
+                LanguageMix {
+                    language: "rust",
+                    services: 1,
+                    endpoints: 2,
+                    percentage: 100.0 / 3.0,
+                },
+            ]
+        );
+    }
+}
@@ -23,9 +23,14 @@ pub fn parse_language_handler_definition(
     let mut defs = Vec::with_capacity(dirs.len());
     for path in dirs {
         match crate::io::parse_utilization_file(&path) {
+            Ok(utilization) if utilization.unprofiled => {
+                log::info!(
+                    "Skipping handler function at path `{}`, as it was not profiled yet",
+                    path.display()
+                )
+            }
             Ok(utilization) => {
-                let def = handler::Definition::new(&path, utilization);
-                defs.push(def);
+                defs.extend(handler::Definition::with_knob_settings(&path, utilization));
             }
             Err(err) => {
                 log::warn!(
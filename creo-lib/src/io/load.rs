@@ -14,11 +14,15 @@ pub fn create_load_generator_file(
 
     for endpoint in application.iter_service_endpoints(service.id) {
         let handler = registry.get_function(endpoint.id);
+        let data = http_loadgenerator::RequestData::new(
+            handler.get_http_method(),
+            application.get_request_parameters(endpoint.id, handler),
+        );
         let user_request = http_loadgenerator::Request {
             endpoint_id: endpoint.id.0,
             service_id: service.id.0,
             path: application.get_endpoint_path(endpoint.id),
-            data: handler.into(),
+            data,
         };
         if application.is_user_frontend(endpoint.id) {
             frontend_requests.push(user_request.clone());
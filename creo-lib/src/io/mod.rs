@@ -6,10 +6,13 @@ mod handler_function;
 mod load;
 mod service;
 mod supported_file_types;
+mod topology;
 mod util;
 mod utilization;
 
-pub use application::{create_application_directory, ApplicationMetaData, Ports};
+pub use application::{
+    create_application_directory, language_mix, ApplicationMetaData, LanguageMix, Ports,
+};
 pub use docker::write_docker_compose_file;
 pub use error::Error;
 pub use handler_definitions::{
@@ -21,6 +24,7 @@ pub use load::{
 };
 pub use service::create_service_folder;
 pub use supported_file_types::FileType;
+pub use topology::write_application_topology;
 pub use util::{
     copy_dir_all, create_dir_all, detect_file_with_file_name, get_supported_file_type, is_dot_file,
     is_empty_dir, list_service_directories,
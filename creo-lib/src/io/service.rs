@@ -5,14 +5,13 @@ use crate::{
     graph, handler, template,
 };
 
-pub fn create_service_folder<R: rand::Rng>(
+pub fn create_service_folder(
     application_dir: impl AsRef<std::path::Path>,
     service_name: impl AsRef<str>,
     template_dir: impl AsRef<std::path::Path>,
     service: &application::MicroService,
     application: &graph::ApplicationGraph,
     registry: &handler::FunctionRegistry,
-    rng: &mut R,
 ) -> std::io::Result<()> {
     let service_dir = application_dir.as_ref().join(service_name.as_ref());
     crate::io::create_dir_all(&service_dir)?;
@@ -20,7 +19,7 @@ pub fn create_service_folder<R: rand::Rng>(
     let data_type_mapper = service.language.as_data_type_mapper();
     let symbol_generator = service.language.as_symbol_generator();
     let file_name_generator = service.language.as_file_name_generator();
-    let framework = service.language.choose_random_framework(rng);
+    let framework = service.framework.as_framework_generator();
     let faker = framework.to_faker();
 
     // Router File
@@ -78,6 +77,8 @@ pub fn create_service_folder<R: rand::Rng>(
             email: Some("yannik.lubas@uni-wuerzburg.de".into()),
             extensions: Default::default(),
         },
+        fault_injection: application.get_fault_injection().is_some(),
+        tracing: application.get_tracing().is_some(),
     };
     template::write_main_file(
         &service_dir,
@@ -122,6 +123,14 @@ pub fn create_service_folder<R: rand::Rng>(
         .into_iter()
         .map(|s| s.into())
         .collect();
+    if application.get_tracing().is_some() {
+        dependencies.extend(
+            framework
+                .get_tracing_requirements()
+                .into_iter()
+                .map(|s| s.into()),
+        );
+    }
     dependencies.extend(service.language.get_local_handler_dependencies(&lib_dir)?);
     let dependency_file_data = template::DependencyData {
         service_name: &get_host(service.id),
@@ -0,0 +1,25 @@
+use crate::{export, graph::ApplicationGraph};
+
+/// Writes the topology of the given application as `topology.json`, `topology.dot` and
+/// `topology.graphml` into the application directory. If the handler functions were selected by
+/// their distance to a target vector, the explanations are written as `selection_log.json`.
+pub fn write_application_topology(
+    app_dir: impl AsRef<std::path::Path>,
+    application: &ApplicationGraph,
+) -> std::io::Result<()> {
+    let app_dir = app_dir.as_ref();
+    let model = export::ApplicationModel::new(application);
+
+    let file = std::fs::File::create(app_dir.join("topology.json"))?;
+    serde_json::to_writer_pretty(file, &model)?;
+    std::fs::write(app_dir.join("topology.dot"), export::to_dot(&model))?;
+    std::fs::write(app_dir.join("topology.graphml"), export::to_graphml(&model))?;
+
+    let selection_log = export::selection_log(application);
+    if !selection_log.is_empty() {
+        let file = std::fs::File::create(app_dir.join("selection_log.json"))?;
+        serde_json::to_writer_pretty(file, &selection_log)?;
+    }
+
+    Ok(())
+}
@@ -3,6 +3,8 @@ pub mod compose;
 pub(crate) mod constants;
 pub mod de;
 mod dependencies;
+pub mod export;
+pub mod fault_injection;
 pub mod generator;
 pub mod graph;
 pub mod handler;
@@ -18,8 +20,9 @@ mod service_types;
 pub mod ssh;
 pub mod stats;
 pub mod template;
+pub mod trace;
 
-pub use service_types::ServiceTypeVec;
+pub use service_types::{ServiceType, ServiceTypeVec};
 
 pub const VERSION: &str = "1.0.0";
 pub use constants::*;
@@ -1,4 +1,4 @@
-use crate::{handler, schema};
+use crate::{handler, http_method::HTTPMethod, schema};
 
 #[derive(Debug, Clone)]
 pub enum HTTPLoadGeneratorType {
@@ -195,7 +195,11 @@ impl HTTPLoadGeneratorType {
 
 #[derive(Debug, Clone)]
 pub struct RequestData {
+    pub method: HTTPMethod,
+    /// The values appended to the path of the endpoint in the given order.
+    pub path: Vec<HTTPLoadGeneratorType>,
     pub query: Vec<QueryComponent>,
+    pub headers: Vec<QueryComponent>,
     pub body: Option<HTTPLoadGeneratorType>,
 }
 
@@ -212,25 +216,42 @@ impl QueryComponent {
     }
 }
 
-impl From<&handler::Function> for RequestData {
-    fn from(value: &handler::Function) -> Self {
-        let mut query = Vec::with_capacity(value.signature.parameters.len());
+impl RequestData {
+    /// Creates the data of a request to an endpoint, which sends the given parameters of the
+    /// endpoint's handler function with the given method.
+    pub fn new<'a>(
+        method: HTTPMethod,
+        parameters: impl IntoIterator<Item = &'a handler::Param>,
+    ) -> Self {
+        let parameters = parameters.into_iter();
+        let mut path = Vec::default();
+        let mut query = Vec::with_capacity(parameters.size_hint().0);
+        let mut headers = Vec::default();
         let mut body = Option::None;
 
-        for param in &value.signature.parameters {
-            if param.schema.get_object_schema().is_some()
-                || param.schema.get_array_schema_type().is_some()
-            {
-                body = Some(HTTPLoadGeneratorType::from(&param.schema.schema_kind))
-            } else {
-                query.push(QueryComponent {
+        for param in parameters {
+            let value = HTTPLoadGeneratorType::from(&param.schema.schema_kind);
+            match param.location() {
+                handler::ParamLocation::Path => path.push(value),
+                handler::ParamLocation::Query => query.push(QueryComponent {
                     name: param.as_name(),
-                    value: (&param.schema.schema_kind).into(),
-                });
+                    value,
+                }),
+                handler::ParamLocation::Header => headers.push(QueryComponent {
+                    name: param.as_header_name(),
+                    value,
+                }),
+                handler::ParamLocation::Body => body = Some(value),
             }
         }
 
-        Self { query, body }
+        Self {
+            method,
+            path,
+            query,
+            headers,
+            body,
+        }
     }
 }
 
@@ -248,8 +269,8 @@ impl Request {
         src.push_str("function ");
         src.push_str(self.as_lua_function().as_str());
         src.push_str("()\n  return");
-        if self.data.body.is_some() {
-            src.push_str(r#" "[POST]".."#);
+        if self.data.method != HTTPMethod::Get {
+            src.push_str(format!(r#" "[{}]".."#, self.data.method).as_str());
         }
         let query = self
             .data
@@ -259,9 +280,22 @@ impl Request {
             .collect::<Vec<_>>()
             .join(r#".."&".."#);
         src.push_str(format!(r#" services[{}].."{}""#, self.service_id, self.path).as_str());
+        for segment in &self.data.path {
+            src.push_str(format!(r#".."/"..tostring({})"#, segment.as_function_call()).as_str());
+        }
         if !query.is_empty() {
             src.push_str(format!(r#".."?"..{}"#, query).as_str());
         }
+        for header in &self.data.headers {
+            src.push_str(
+                format!(
+                    r#".."[HEADER]{}:"..tostring({})"#,
+                    header.name,
+                    header.value.as_function_call()
+                )
+                .as_str(),
+            );
+        }
         if let Some(body) = &self.data.body {
             src.push_str(
                 format!(r#".."[JSON]"..json.encode({})"#, body.as_function_call()).as_str(),
@@ -581,3 +615,52 @@ function random_object(...)
 	return out
 end
 "#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::de::FromYamlStr;
+
+    #[test]
+    fn test_request_lua_source() {
+        const INPUT: &str = "
+            import_path: update_user
+            is_async: true
+            method: PATCH
+            signature:
+              function: update_user_by_id
+              parameters:
+                - arg: 0
+                  title: id
+                  in: path
+                  type: integer
+                  minimum: 1
+                  maximum: 10
+                - arg: 1
+                  title: user_id
+                  in: header
+                  type: integer
+                  minimum: 1
+                  maximum: 10
+                - arg: 2
+                  type: object
+                  properties:
+                    name:
+                      type: string
+        ";
+        let function = handler::Function::from_yaml_str(INPUT).unwrap();
+        let request = Request {
+            endpoint_id: 3,
+            service_id: 1,
+            path: "/endpoint3".into(),
+            data: RequestData::new(function.get_http_method(), &function.signature.parameters),
+        };
+        let src = request.as_lua_source();
+        let line = src.lines().nth(1).unwrap();
+
+        assert!(line.starts_with(r#"  return "[PATCH]".. services[1].."/endpoint3".."/".."#));
+        let header = line.find(r#".."[HEADER]user-id:"..tostring("#).unwrap();
+        let body = line.find(r#".."[JSON]"..json.encode("#).unwrap();
+        assert!(header < body, "{}", line);
+    }
+}
@@ -0,0 +1,108 @@
+use docker_compose_types as dct;
+
+/// The host and port under which the generated microservices export their spans via OTLP/gRPC.
+const OTLP_ENDPOINT: &str = "http://jaeger:4317";
+
+/// [`Tracing`] describes the distributed tracing of the generated microservices.
+///
+/// If tracing is enabled, the microservices emit OpenTelemetry spans for every request and
+/// service call and export them to a Jaeger instance, which is added to the application.
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default, remote = "Self")]
+pub struct Tracing {
+    /// The fraction of traces, which are sampled by the entry microservices, in `[0, 1]`.
+    pub sampling_ratio: f64,
+    /// The host port of the Jaeger UI.
+    pub ui_port: u16,
+}
+
+impl Default for Tracing {
+    fn default() -> Self {
+        Self {
+            sampling_ratio: 1.0,
+            ui_port: 16686,
+        }
+    }
+}
+
+impl Tracing {
+    /// Returns the `OTEL_*` environment variables of the given microservice in the `KEY=VALUE`
+    /// format.
+    pub fn environment(&self, service_host: impl AsRef<str>) -> Vec<String> {
+        vec![
+            format!("OTEL_SERVICE_NAME={}", service_host.as_ref()),
+            format!("OTEL_EXPORTER_OTLP_ENDPOINT={}", OTLP_ENDPOINT),
+            "OTEL_TRACES_SAMPLER=parentbased_traceidratio".into(),
+            format!("OTEL_TRACES_SAMPLER_ARG={}", self.sampling_ratio),
+        ]
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Tracing {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let this = Tracing::deserialize(deserializer)?;
+        if !(0.0..=1.0).contains(&this.sampling_ratio) {
+            return Err(serde::de::Error::custom(format!(
+                "expected the sampling ratio to be in [0, 1], but got {}",
+                this.sampling_ratio
+            )));
+        }
+        Ok(this)
+    }
+}
+
+impl serde::Serialize for Tracing {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        Tracing::serialize(self, serializer)
+    }
+}
+
+pub fn create_jaeger_service(tracing: &Tracing) -> (String, dct::Service) {
+    (
+        "jaeger".into(),
+        dct::Service {
+            image: Some("jaegertracing/all-in-one:1.53".into()),
+            ports: dct::Ports::Short(vec![format!("{}:16686", tracing.ui_port)]),
+            expose: vec!["4317".into(), "4318".into()],
+            environment: dct::Environment::List(vec!["COLLECTOR_OTLP_ENABLED=true".into()]),
+            ..Default::default()
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_environment() {
+        let tracing = Tracing {
+            sampling_ratio: 0.25,
+            ..Default::default()
+        };
+        assert_eq!(
+            tracing.environment("service-0"),
+            [
+                "OTEL_SERVICE_NAME=service-0",
+                "OTEL_EXPORTER_OTLP_ENDPOINT=http://jaeger:4317",
+                "OTEL_TRACES_SAMPLER=parentbased_traceidratio",
+                "OTEL_TRACES_SAMPLER_ARG=0.25",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let tracing: Tracing = serde_yaml::from_str("ui_port: 20000").expect("should deserialize");
+        assert_eq!(tracing.sampling_ratio, 1.0);
+        assert_eq!(tracing.ui_port, 20000);
+
+        assert!(serde_yaml::from_str::<Tracing>("sampling_ratio: 1.5").is_err());
+    }
+}
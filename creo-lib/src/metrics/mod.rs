@@ -1,6 +1,9 @@
 mod cadvisor;
+mod jaeger;
 mod prometheus;
 
+pub use jaeger::Tracing;
+
 pub fn add_metrics_collection(
     dir: impl AsRef<std::path::Path>,
     depends_on: Vec<String>,
@@ -40,3 +43,9 @@ pub fn add_metrics_collection(
 
     Ok(())
 }
+
+/// Adds a Jaeger instance, which collects the spans of the microservices, to the application.
+pub fn add_trace_collection(tracing: &Tracing, compose: &mut crate::compose::Compose) {
+    let jaeger = jaeger::create_jaeger_service(tracing);
+    compose.0.services.0.insert(jaeger.0, Some(jaeger.1));
+}
@@ -6,6 +6,9 @@ impl ProgrammingLanguage {
         match self {
             Python(_) => &generator::python::DataTypeMapper,
             Rust(_) => &generator::rust::DataTypeMapper,
+            Go(_) => &generator::go::DataTypeMapper,
+            Java(_) => &generator::java::DataTypeMapper,
+            Node(_) => &generator::node::DataTypeMapper,
         }
     }
 }
@@ -6,6 +6,9 @@ impl ProgrammingLanguage {
         match self {
             Python(_) => generator::python::DOCKERFILE_TEMPLATE_PATH,
             Rust(_) => generator::rust::DOCKERFILE_TEMPLATE_PATH,
+            Go(_) => generator::go::DOCKERFILE_TEMPLATE_PATH,
+            Java(_) => generator::java::DOCKERFILE_TEMPLATE_PATH,
+            Node(_) => generator::node::DOCKERFILE_TEMPLATE_PATH,
         }
     }
 }
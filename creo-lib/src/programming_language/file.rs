@@ -6,6 +6,9 @@ impl ProgrammingLanguage {
         match self {
             Python(_) => &generator::python::FileNameGenerator,
             Rust(_) => &generator::rust::FileNameGenerator,
+            Go(_) => &generator::go::FileNameGenerator,
+            Java(_) => &generator::java::FileNameGenerator,
+            Node(_) => &generator::node::FileNameGenerator,
         }
     }
 }
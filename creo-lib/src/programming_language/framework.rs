@@ -0,0 +1,162 @@
+use std::str::FromStr;
+
+use super::ProgrammingLanguage;
+use crate::generator::{self, core::FrameworkGenerator};
+
+/// [`Framework`] is a web framework, in which the microservices of a programming language can be
+/// implemented.
+#[derive(strum_macros::EnumIter, PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum Framework {
+    FastAPI(usize),
+    Flask(usize),
+    Axum(usize),
+    ActixWeb(usize),
+    NetHttp(usize),
+    SpringBoot(usize),
+    Fastify(usize),
+}
+
+use Framework::*;
+
+impl Framework {
+    /// Returns the name of the framework in the configuration.
+    pub fn as_name(&self) -> &'static str {
+        match self {
+            FastAPI(_) => "fastapi",
+            Flask(_) => "flask",
+            Axum(_) => "axum",
+            ActixWeb(_) => "actix-web",
+            NetHttp(_) => "net-http",
+            SpringBoot(_) => "spring-boot",
+            Fastify(_) => "fastify",
+        }
+    }
+
+    /// Returns the fraction weight value of the framework.
+    pub fn as_fraction(&self) -> usize {
+        match self {
+            FastAPI(f) => *f,
+            Flask(f) => *f,
+            Axum(f) => *f,
+            ActixWeb(f) => *f,
+            NetHttp(f) => *f,
+            SpringBoot(f) => *f,
+            Fastify(f) => *f,
+        }
+    }
+
+    /// Returns whether the framework is a framework of the given programming language.
+    pub fn is_of(&self, language: &ProgrammingLanguage) -> bool {
+        use ProgrammingLanguage::*;
+
+        matches!(
+            (self, language),
+            (FastAPI(_) | Flask(_), Python(_))
+                | (Axum(_) | ActixWeb(_), Rust(_))
+                | (NetHttp(_), Go(_))
+                | (SpringBoot(_), Java(_))
+                | (Fastify(_), Node(_))
+        )
+    }
+
+    pub(crate) fn as_framework_generator(&self) -> &'static dyn FrameworkGenerator {
+        match self {
+            FastAPI(_) => &generator::python::Frameworks::FastAPI,
+            Flask(_) => &generator::python::Frameworks::Flask,
+            Axum(_) => &generator::rust::Frameworks::Axum,
+            ActixWeb(_) => &generator::rust::Frameworks::ActixWeb,
+            NetHttp(_) => &generator::go::Frameworks::NetHttp,
+            SpringBoot(_) => &generator::java::Frameworks::SpringBoot,
+            Fastify(_) => &generator::node::Frameworks::Fastify,
+        }
+    }
+}
+
+impl ProgrammingLanguage {
+    /// Returns the framework of the programming language, which is used if no framework is
+    /// specified and by the profiling applications.
+    pub fn default_framework(&self) -> Framework {
+        match self {
+            ProgrammingLanguage::Python(_) => FastAPI(1),
+            ProgrammingLanguage::Rust(_) => Axum(1),
+            ProgrammingLanguage::Go(_) => NetHttp(1),
+            ProgrammingLanguage::Java(_) => SpringBoot(1),
+            ProgrammingLanguage::Node(_) => Fastify(1),
+        }
+    }
+}
+
+impl std::fmt::Display for Framework {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FastAPI(_) => f.write_str("FastAPI"),
+            Flask(_) => f.write_str("Flask"),
+            Axum(_) => f.write_str("Axum"),
+            ActixWeb(_) => f.write_str("Actix Web"),
+            NetHttp(_) => f.write_str("net/http"),
+            SpringBoot(_) => f.write_str("Spring Boot"),
+            Fastify(_) => f.write_str("Fastify"),
+        }
+    }
+}
+
+impl FromStr for Framework {
+    type Err = String;
+
+    /// Parses the given string to a framework. Like for programming languages, the part after an
+    /// optional `:` is the fractional weight value of the framework.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, fraction) = s.split_once(':').unwrap_or((s, "1"));
+
+        let fraction = fraction.parse::<usize>().map_err(|e| e.to_string())?;
+
+        match name {
+            "fastapi" => Ok(FastAPI(fraction)),
+            "flask" => Ok(Flask(fraction)),
+            "axum" => Ok(Axum(fraction)),
+            "actix-web" => Ok(ActixWeb(fraction)),
+            "net-http" => Ok(NetHttp(fraction)),
+            "spring-boot" => Ok(SpringBoot(fraction)),
+            "fastify" => Ok(Fastify(fraction)),
+            _ => Err(format!("unknown framework {}", s)),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Framework {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Framework::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_framework() {
+        assert_eq!("actix-web".parse(), Ok(ActixWeb(1)));
+        assert_eq!("flask:30".parse(), Ok(Flask(30)));
+        assert!("flask:x".parse::<Framework>().is_err());
+        assert!("django".parse::<Framework>().is_err());
+    }
+
+    #[test]
+    fn test_every_framework_has_one_language() {
+        for framework in Framework::iter() {
+            let languages = ProgrammingLanguage::iter()
+                .filter(|language| framework.is_of(language))
+                .count();
+            assert_eq!(languages, 1, "expected {} to have one language", framework);
+        }
+        for language in ProgrammingLanguage::iter() {
+            assert!(language.default_framework().is_of(&language));
+        }
+    }
+}
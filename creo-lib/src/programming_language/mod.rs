@@ -2,15 +2,22 @@ mod data_type;
 mod dependency;
 mod docker;
 mod file;
-mod random;
+mod framework;
+mod source;
 mod symbol;
 
 use std::str::FromStr;
 
+pub use framework::Framework;
+pub use source::FunctionDeclaration;
+
 #[derive(strum_macros::EnumIter, PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub enum ProgrammingLanguage {
     Python(usize),
     Rust(usize),
+    Go(usize),
+    Java(usize),
+    Node(usize),
 }
 
 use ProgrammingLanguage::*;
@@ -22,6 +29,9 @@ impl ProgrammingLanguage {
         match self {
             Python(_) => "python",
             Rust(_) => "rust",
+            Go(_) => "go",
+            Java(_) => "java",
+            Node(_) => "node",
         }
     }
 
@@ -30,6 +40,9 @@ impl ProgrammingLanguage {
         match self {
             Python(f) => *f,
             Rust(f) => *f,
+            Go(f) => *f,
+            Java(f) => *f,
+            Node(f) => *f,
         }
     }
 }
@@ -39,6 +52,9 @@ impl std::fmt::Display for ProgrammingLanguage {
         match self {
             Python(_) => f.write_str("Python"),
             Rust(_) => f.write_str("Rust"),
+            Go(_) => f.write_str("Go"),
+            Java(_) => f.write_str("Java"),
+            Node(_) => f.write_str("Node"),
         }
     }
 }
@@ -58,6 +74,9 @@ impl FromStr for ProgrammingLanguage {
         match name {
             "python" => Ok(Python(fraction)),
             "rust" => Ok(Rust(fraction)),
+            "go" => Ok(Go(fraction)),
+            "java" => Ok(Java(fraction)),
+            "node" => Ok(Node(fraction)),
             _ => Err(format!("unknown programming language {}", s)),
         }
     }
@@ -0,0 +1,150 @@
+use super::ProgrammingLanguage::{self, *};
+
+/// [`FunctionDeclaration`] describes a function declared in a source file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FunctionDeclaration {
+    pub is_async: bool,
+}
+
+impl ProgrammingLanguage {
+    /// Returns the file extension of the source files of the programming language.
+    pub fn source_file_extension(&self) -> &'static str {
+        match self {
+            Python(_) => "py",
+            Rust(_) => "rs",
+            Go(_) => "go",
+            Java(_) => "java",
+            Node(_) => "js",
+        }
+    }
+
+    /// Returns the declaration of the function with the given name in the source, if any.
+    ///
+    /// The declaration is detected line by line, i.e., the function keyword, or the modifiers of a
+    /// Java method, and the function name must be on the same line.
+    pub fn find_function_declaration(
+        &self,
+        source: &str,
+        function_name: &str,
+    ) -> Option<FunctionDeclaration> {
+        source
+            .lines()
+            .find_map(|line| self.parse_function_declaration(line.trim_start(), function_name))
+    }
+
+    fn parse_function_declaration(
+        &self,
+        line: &str,
+        function_name: &str,
+    ) -> Option<FunctionDeclaration> {
+        let (line, keyword) = match self {
+            Python(_) => (line, "def "),
+            Rust(_) => {
+                let line = match line.strip_prefix("pub") {
+                    Some(rest) if rest.starts_with('(') => rest.split_once(')')?.1.trim_start(),
+                    Some(rest) => rest.trim_start(),
+                    None => line,
+                };
+                (line, "fn ")
+            }
+            Go(_) => (line, "func "),
+            Node(_) => (line.strip_prefix("export ").unwrap_or(line), "function "),
+            Java(_) => {
+                // Java has no function keyword, thus handler functions are detected as static
+                // methods, whose name precedes the parameter list.
+                let (head, _) = line.split_once('(')?;
+                let mut words = head.split_whitespace();
+                let is_static = words.clone().any(|word| word == "static");
+                return (is_static && words.next_back() == Some(function_name))
+                    .then_some(FunctionDeclaration { is_async: false });
+            }
+        };
+        let (line, is_async) = match line.strip_prefix("async ") {
+            Some(rest) => (rest.trim_start(), true),
+            None => (line, false),
+        };
+        let rest = line
+            .strip_prefix(keyword)?
+            .trim_start()
+            .strip_prefix(function_name)?;
+        rest.starts_with(['(', '<', '['])
+            .then_some(FunctionDeclaration { is_async })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_python_function_declaration() {
+        let source = "import argon2\n\nasync def hash_password_async(password):\n    pass\n\ndef hash_password(password: str) -> str:\n    pass\n";
+        let lang = Python(1);
+        assert_eq!(
+            lang.find_function_declaration(source, "hash_password"),
+            Some(FunctionDeclaration { is_async: false })
+        );
+        assert_eq!(
+            lang.find_function_declaration(source, "hash_password_async"),
+            Some(FunctionDeclaration { is_async: true })
+        );
+        assert_eq!(lang.find_function_declaration(source, "hash"), None);
+    }
+
+    #[test]
+    fn test_find_rust_function_declaration() {
+        let source = "use std::io;\n\npub async fn archive_files(compress: bool) {}\npub(crate) fn helper<T>(t: T) {}\n";
+        let lang = Rust(1);
+        assert_eq!(
+            lang.find_function_declaration(source, "archive_files"),
+            Some(FunctionDeclaration { is_async: true })
+        );
+        assert_eq!(
+            lang.find_function_declaration(source, "helper"),
+            Some(FunctionDeclaration { is_async: false })
+        );
+        assert_eq!(lang.find_function_declaration(source, "archive"), None);
+    }
+
+    #[test]
+    fn test_find_go_function_declaration() {
+        let source = "package primes\n\nfunc (s *sieve) Next() uint64 { return 0 }\n\nfunc GenerateFirstPrimes(n int32) []uint64 {\n\treturn nil\n}\n\nfunc Max[T int32 | int64](a, b T) T { return a }\n";
+        let lang = Go(1);
+        assert_eq!(
+            lang.find_function_declaration(source, "GenerateFirstPrimes"),
+            Some(FunctionDeclaration { is_async: false })
+        );
+        assert_eq!(
+            lang.find_function_declaration(source, "Max"),
+            Some(FunctionDeclaration { is_async: false })
+        );
+        assert_eq!(lang.find_function_declaration(source, "Next"), None);
+    }
+
+    #[test]
+    fn test_find_java_function_declaration() {
+        let source = "package creo.handlers.primes;\n\npublic final class Primes {\n    public static List<Long> generateFirstPrimes(int n) {\n        return next(n);\n    }\n\n    private long next(long q) {\n        return q;\n    }\n}\n";
+        let lang = Java(1);
+        assert_eq!(
+            lang.find_function_declaration(source, "generateFirstPrimes"),
+            Some(FunctionDeclaration { is_async: false })
+        );
+        assert_eq!(lang.find_function_declaration(source, "next"), None);
+        assert_eq!(lang.find_function_declaration(source, "generate"), None);
+    }
+
+    #[test]
+    fn test_find_node_function_declaration() {
+        let source = "import argon2 from \"argon2\";\n\nfunction salt() {}\n\nexport async function hashPassword(password) {\n  return argon2.hash(password);\n}\n";
+        let lang = Node(1);
+        assert_eq!(
+            lang.find_function_declaration(source, "hashPassword"),
+            Some(FunctionDeclaration { is_async: true })
+        );
+        assert_eq!(
+            lang.find_function_declaration(source, "salt"),
+            Some(FunctionDeclaration { is_async: false })
+        );
+        assert_eq!(lang.find_function_declaration(source, "hash"), None);
+    }
+}
@@ -6,6 +6,9 @@ impl ProgrammingLanguage {
         match self {
             Python(_) => &generator::python::SymbolGenerator,
             Rust(_) => &generator::rust::SymbolGenerator,
+            Go(_) => &generator::go::SymbolGenerator,
+            Java(_) => &generator::java::SymbolGenerator,
+            Node(_) => &generator::node::SymbolGenerator,
         }
     }
 }
@@ -0,0 +1,238 @@
+use std::collections::HashSet;
+
+use openapiv3::{IntegerFormat, NumberType, StringType, VariantOrUnknownOrEmpty};
+
+use super::{object_type::AdditionalProperties, ArrayType, ObjectType, Schema, SchemaKind, Type};
+
+impl Schema {
+    /// Returns whether every value of this schema is also a valid value of the target schema, i.e.,
+    /// whether a value of this schema can be passed on as a value of the target schema.
+    ///
+    /// Properties of objects, which this schema does not declare, are assumed to be absent.
+    pub fn is_compatible_with(&self, target: &Schema) -> bool {
+        if self.schema_data.nullable && !target.schema_data.nullable {
+            return false;
+        }
+        let SchemaKind::Type(source) = &self.schema_kind;
+        let SchemaKind::Type(target) = &target.schema_kind;
+        match (source, target) {
+            (Type::String(source), Type::String(target)) => is_compatible_string(source, target),
+            (Type::Integer(source), Type::Integer(target)) => {
+                let (source_min, source_max) = integer_bounds(source);
+                let (target_min, target_max) = integer_bounds(target);
+                target_min <= source_min
+                    && source_max <= target_max
+                    && is_compatible_enumeration(&source.enumeration, &target.enumeration)
+                    && is_multiple_of(source.multiple_of, target.multiple_of)
+            }
+            (Type::Integer(source), Type::Number(target)) => {
+                let (min, max) = integer_bounds(source);
+                let source = NumberType {
+                    minimum: Some(min as f64),
+                    maximum: Some(max as f64),
+                    ..Default::default()
+                };
+                target.enumeration.is_empty()
+                    && target.multiple_of.is_none()
+                    && is_compatible_number(&source, target)
+            }
+            (Type::Number(source), Type::Number(target)) => {
+                is_compatible_enumeration(&source.enumeration, &target.enumeration)
+                    && target.multiple_of.is_none()
+                    && is_compatible_number(source, target)
+            }
+            (Type::Boolean(_), Type::Boolean(_)) => true,
+            (Type::Array(source), Type::Array(target)) => is_compatible_array(source, target),
+            (Type::Object(source), Type::Object(target)) => is_compatible_object(source, target),
+            _ => false,
+        }
+    }
+}
+
+fn is_compatible_string(source: &StringType, target: &StringType) -> bool {
+    let is_compatible_format =
+        matches!(target.format, VariantOrUnknownOrEmpty::Empty) || source.format == target.format;
+    let is_compatible_pattern = target.pattern.is_none() || source.pattern == target.pattern;
+    is_compatible_format
+        && is_compatible_pattern
+        && is_compatible_enumeration(&source.enumeration, &target.enumeration)
+        && is_within(
+            (source.min_length, source.max_length),
+            (target.min_length, target.max_length),
+        )
+}
+
+/// Returns the inclusive bounds of the integer schema, which default to the bounds of its format.
+fn integer_bounds(integer: &openapiv3::IntegerType) -> (i64, i64) {
+    let (min, max) = match integer.format {
+        VariantOrUnknownOrEmpty::Item(IntegerFormat::Int32) => (i32::MIN as i64, i32::MAX as i64),
+        _ => (i64::MIN, i64::MAX),
+    };
+    let min = integer
+        .minimum
+        .map(|min| min.saturating_add(integer.exclusive_minimum as i64))
+        .unwrap_or(min);
+    let max = integer
+        .maximum
+        .map(|max| max.saturating_sub(integer.exclusive_maximum as i64))
+        .unwrap_or(max);
+    (min, max)
+}
+
+fn is_compatible_number(source: &NumberType, target: &NumberType) -> bool {
+    let is_above_minimum = match (source.minimum, target.minimum) {
+        (_, None) => true,
+        (None, Some(_)) => false,
+        (Some(source_min), Some(target_min)) => {
+            source_min > target_min
+                || (source_min == target_min
+                    && (source.exclusive_minimum || !target.exclusive_minimum))
+        }
+    };
+    let is_below_maximum = match (source.maximum, target.maximum) {
+        (_, None) => true,
+        (None, Some(_)) => false,
+        (Some(source_max), Some(target_max)) => {
+            source_max < target_max
+                || (source_max == target_max
+                    && (source.exclusive_maximum || !target.exclusive_maximum))
+        }
+    };
+    is_above_minimum && is_below_maximum
+}
+
+fn is_compatible_array(source: &ArrayType, target: &ArrayType) -> bool {
+    source.items.is_compatible_with(&target.items)
+        && (source.unique_items || !target.unique_items)
+        && is_within(
+            (source.min_items, source.max_items),
+            (target.min_items, target.max_items),
+        )
+}
+
+fn is_compatible_object(source: &ObjectType, target: &ObjectType) -> bool {
+    let source_required: HashSet<_> = source.required.iter().collect();
+    let are_required_present = target
+        .required
+        .iter()
+        .all(|name| source_required.contains(name));
+    let are_properties_compatible =
+        source
+            .properties
+            .iter()
+            .all(|(name, source_prop)| match target.properties.get(name) {
+                Some(target_prop) => source_prop.is_compatible_with(target_prop),
+                None => match &target.additional_properties {
+                    Some(AdditionalProperties::Any(allowed)) => *allowed,
+                    Some(AdditionalProperties::Schema(schema)) => {
+                        source_prop.is_compatible_with(schema)
+                    }
+                    None => true,
+                },
+            });
+    are_required_present
+        && are_properties_compatible
+        && target
+            .min_properties
+            .is_none_or(|min| source.required.len() >= min)
+        && target
+            .max_properties
+            .is_none_or(|max| source.properties.len() <= max)
+}
+
+fn is_compatible_enumeration<T: PartialEq>(source: &[Option<T>], target: &[Option<T>]) -> bool {
+    target.is_empty() || (!source.is_empty() && source.iter().all(|value| target.contains(value)))
+}
+
+fn is_multiple_of(source: Option<i64>, target: Option<i64>) -> bool {
+    match (source, target) {
+        (_, None) => true,
+        (Some(source), Some(target)) => target != 0 && source % target == 0,
+        (None, Some(_)) => false,
+    }
+}
+
+/// Returns whether the range of the source lies within the range of the target, where a missing
+/// minimum is zero and a missing maximum is unbounded.
+fn is_within(
+    (source_min, source_max): (Option<usize>, Option<usize>),
+    (target_min, target_max): (Option<usize>, Option<usize>),
+) -> bool {
+    let is_above_minimum = source_min.unwrap_or_default() >= target_min.unwrap_or_default();
+    let is_below_maximum = match (source_max, target_max) {
+        (_, None) => true,
+        (None, Some(_)) => false,
+        (Some(source_max), Some(target_max)) => source_max <= target_max,
+    };
+    is_above_minimum && is_below_maximum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schema(yaml: &str) -> Schema {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn test_primitive_compatibility() {
+        let id = schema("{ type: integer, minimum: 1, maximum: 100 }");
+        assert!(id.is_compatible_with(&schema("{ type: integer, minimum: 1, maximum: 300000 }")));
+        assert!(id.is_compatible_with(&schema("{ type: integer, format: int32 }")));
+        assert!(id.is_compatible_with(&schema("{ type: number, minimum: 0.5 }")));
+        assert!(!id.is_compatible_with(&schema("{ type: integer, minimum: 10 }")));
+        assert!(!id.is_compatible_with(&schema("{ type: string }")));
+        assert!(!schema("{ type: integer }")
+            .is_compatible_with(&schema("{ type: integer, format: int32 }")));
+        assert!(!schema("{ type: integer, nullable: true }")
+            .is_compatible_with(&schema("{ type: integer }")));
+
+        let hash = schema("{ type: string, minLength: 8, maxLength: 32 }");
+        assert!(hash.is_compatible_with(&schema("{ type: string, maxLength: 48 }")));
+        assert!(!hash.is_compatible_with(&schema("{ type: string, minLength: 10 }")));
+        assert!(!schema("{ type: string }")
+            .is_compatible_with(&schema("{ type: string, maxLength: 48 }")));
+    }
+
+    #[test]
+    fn test_structured_compatibility() {
+        let user = schema(
+            "
+            type: object
+            properties:
+              id: { type: integer, minimum: 1, maximum: 100 }
+              name: { type: string, minLength: 1, maxLength: 10 }
+            required: [id, name]
+            ",
+        );
+        let update = schema(
+            "
+            type: object
+            properties:
+              name: { type: string, maxLength: 64 }
+              email: { type: string }
+            additionalProperties: false
+            required: [name]
+            ",
+        );
+        assert!(!user.is_compatible_with(&update), "unexpected property id");
+
+        let lookup = schema(
+            "
+            type: object
+            properties:
+              id: { type: integer, minimum: 1 }
+            required: [id]
+            ",
+        );
+        assert!(user.is_compatible_with(&lookup));
+        assert!(!lookup.is_compatible_with(&user), "missing property name");
+
+        let users = schema("{ type: array, items: { type: integer, minimum: 1 }, maxItems: 5 }");
+        assert!(users.is_compatible_with(&schema("{ type: array, items: { type: number } }")));
+        assert!(!users.is_compatible_with(&schema(
+            "{ type: array, items: { type: integer }, minItems: 1 }"
+        )));
+    }
+}
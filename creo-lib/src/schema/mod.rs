@@ -1,4 +1,5 @@
 mod array_type;
+mod compatibility;
 mod object_type;
 mod schema_kind;
 
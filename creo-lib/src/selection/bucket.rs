@@ -1,16 +1,13 @@
-use crate::{
-    handler,
-    service_types::{Bucket, Property},
-};
+use crate::{handler, service_types::Bucket};
 
 const N_BUCKETS: usize = 3;
 
 pub fn select_bucket<'a>(
     handler_definitions: &'a [handler::Definition],
-    resource: &Property,
+    bucket: &Bucket,
 ) -> &'a [handler::Definition] {
     let bucket_boundaries = determine_bucket_boundaries(handler_definitions.len());
-    let bucket_index = select_bucket_index(resource);
+    let bucket_index = select_bucket_index(bucket);
     let &(start, stop) = &bucket_boundaries[bucket_index].to_owned();
     &handler_definitions[start..stop]
 }
@@ -29,8 +26,8 @@ fn determine_bucket_boundaries(length: usize) -> [(usize, usize); 3] {
     [(0, low), (low, medium), (medium, high)]
 }
 
-fn select_bucket_index(resource: &Property) -> usize {
-    match resource.bucket {
+fn select_bucket_index(bucket: &Bucket) -> usize {
+    match bucket {
         Bucket::Low => 0,
         Bucket::Medium => 1,
         Bucket::High => 2,
@@ -88,30 +85,32 @@ mod tests {
             definitions.push(handler::Definition {
                 directory: std::path::PathBuf::from(format!("test/path/{i}")),
                 utilization: HashMap::default(),
+                curves: HashMap::default(),
+                knobs: Default::default(),
             });
         }
 
-        let resource = service_types::Property {
-            label: service_types::Label::Cpu,
-            fraction: 100,
-            bucket: service_types::Bucket::Low,
-        };
-
-        let bucket = select_bucket(&definitions, &resource);
+        let bucket = select_bucket(&definitions, &service_types::Bucket::Low);
         assert_eq!(
             bucket,
             &[
                 handler::Definition {
                     directory: std::path::PathBuf::from("test/path/0".to_string()),
                     utilization: HashMap::default(),
+                    curves: HashMap::default(),
+                    knobs: Default::default(),
                 },
                 handler::Definition {
                     directory: std::path::PathBuf::from("test/path/1".to_string()),
                     utilization: HashMap::default(),
+                    curves: HashMap::default(),
+                    knobs: Default::default(),
                 },
                 handler::Definition {
                     directory: std::path::PathBuf::from("test/path/2".to_string()),
                     utilization: HashMap::default(),
+                    curves: HashMap::default(),
+                    knobs: Default::default(),
                 }
             ],
             "unexpected low bucket: {:?}",
@@ -125,30 +124,32 @@ mod tests {
             definitions.push(handler::Definition {
                 directory: std::path::PathBuf::from(format!("test/path/{i}")),
                 utilization: HashMap::default(),
+                curves: HashMap::default(),
+                knobs: Default::default(),
             });
         }
 
-        let resource = service_types::Property {
-            label: service_types::Label::Cpu,
-            fraction: 100,
-            bucket: service_types::Bucket::Medium,
-        };
-
-        let bucket = select_bucket(&definitions, &resource);
+        let bucket = select_bucket(&definitions, &service_types::Bucket::Medium);
         assert_eq!(
             bucket,
             &[
                 handler::Definition {
                     directory: std::path::PathBuf::from("test/path/3".to_string()),
                     utilization: HashMap::default(),
+                    curves: HashMap::default(),
+                    knobs: Default::default(),
                 },
                 handler::Definition {
                     directory: std::path::PathBuf::from("test/path/4".to_string()),
                     utilization: HashMap::default(),
+                    curves: HashMap::default(),
+                    knobs: Default::default(),
                 },
                 handler::Definition {
                     directory: std::path::PathBuf::from("test/path/5".to_string()),
                     utilization: HashMap::default(),
+                    curves: HashMap::default(),
+                    knobs: Default::default(),
                 }
             ],
             "unexpected medium bucket: {:?}",
@@ -162,30 +163,32 @@ mod tests {
             definitions.push(handler::Definition {
                 directory: std::path::PathBuf::from(format!("test/path/{i}")),
                 utilization: HashMap::default(),
+                curves: HashMap::default(),
+                knobs: Default::default(),
             });
         }
 
-        let resource = service_types::Property {
-            label: service_types::Label::Cpu,
-            fraction: 100,
-            bucket: service_types::Bucket::High,
-        };
-
-        let bucket = select_bucket(&definitions, &resource);
+        let bucket = select_bucket(&definitions, &service_types::Bucket::High);
         assert_eq!(
             bucket,
             &[
                 handler::Definition {
                     directory: std::path::PathBuf::from("test/path/6".to_string()),
                     utilization: HashMap::default(),
+                    curves: HashMap::default(),
+                    knobs: Default::default(),
                 },
                 handler::Definition {
                     directory: std::path::PathBuf::from("test/path/7".to_string()),
                     utilization: HashMap::default(),
+                    curves: HashMap::default(),
+                    knobs: Default::default(),
                 },
                 handler::Definition {
                     directory: std::path::PathBuf::from("test/path/8".to_string()),
                     utilization: HashMap::default(),
+                    curves: HashMap::default(),
+                    knobs: Default::default(),
                 }
             ],
             "unexpected high bucket: {:?}",
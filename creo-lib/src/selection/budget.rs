@@ -0,0 +1,314 @@
+use std::collections::HashMap;
+
+use super::select_bucket;
+use crate::{
+    handler,
+    service_types::{Intensity, Label, Property},
+};
+
+/// The maximum number of handler function replacements per endpoint.
+const MAX_ROUNDS_PER_ENDPOINT: usize = 10;
+
+/// [`Budget`] limits the total utilization of the application per label, e.g., the CPU and memory
+/// capacity of the worker node.
+///
+/// The total utilization is predicted as the sum of the profiled utilizations of the handler
+/// functions of all endpoints, in the units of the `utilization.yml` files.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+#[serde(remote = "Self")]
+pub struct Budget(pub HashMap<Label, f64>);
+
+impl<'de> serde::Deserialize<'de> for Budget {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let this = Self::deserialize(deserializer)?;
+        if this.0.is_empty() {
+            return Err(serde::de::Error::custom(
+                "expected the budget to limit at least one label",
+            ));
+        }
+        for (label, limit) in &this.0 {
+            if !limit.is_finite() || *limit <= 0.0 {
+                return Err(serde::de::Error::custom(format!(
+                    "expected the budget of {} to be a positive number, but was {}",
+                    label, limit
+                )));
+            }
+        }
+        Ok(this)
+    }
+}
+
+/// [`BudgetReport`] compares the predicted total utilization of an application with its budget.
+#[derive(serde::Serialize, Clone, Debug, PartialEq)]
+pub struct BudgetReport {
+    pub labels: Vec<BudgetUsage>,
+}
+
+/// [`BudgetUsage`] describes the predicted total utilization of a single label.
+#[derive(serde::Serialize, Clone, Debug, PartialEq)]
+pub struct BudgetUsage {
+    pub label: String,
+    pub budget: f64,
+    /// The predicted total utilization before the optimization.
+    pub initial: f64,
+    /// The predicted total utilization after the optimization.
+    pub predicted: f64,
+}
+
+impl BudgetReport {
+    /// Returns whether the predicted total utilization of all labels is within their budget.
+    pub fn is_satisfied(&self) -> bool {
+        self.labels
+            .iter()
+            .all(|usage| usage.predicted <= usage.budget)
+    }
+}
+
+impl std::fmt::Display for BudgetReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let usages: Vec<String> = self
+            .labels
+            .iter()
+            .map(|usage| {
+                format!(
+                    "{}: predicted {:.2} of {:.2} (initially {:.2})",
+                    usage.label, usage.predicted, usage.budget, usage.initial
+                )
+            })
+            .collect();
+        f.write_str(&usages.join(", "))
+    }
+}
+
+/// Returns the definitions, which satisfy all given properties. The selected definition is always
+/// included, such that an endpoint can keep its handler function.
+///
+/// A definition satisfies a bucket property, if it belongs to the bucket, and a target property,
+/// if it is within the tolerance of the target or, without a tolerance, nearest to the target.
+pub fn admissible_definitions(
+    definitions: &mut [handler::Definition],
+    properties: &[Property],
+    selection: &handler::Definition,
+) -> Vec<handler::Definition> {
+    let mut admissible: Vec<handler::Definition> = definitions.to_vec();
+    for property in properties {
+        definitions.sort_by(|a, b| a.compare_by_resource_type(b, &property.label));
+        let accepted: Vec<&handler::Definition> = match &property.intensity {
+            Intensity::Bucket(bucket) => select_bucket(definitions, bucket).iter().collect(),
+            Intensity::Target(target) => {
+                let deviation = |definition: &handler::Definition| {
+                    (definition.get_utilization(&property.label) - target.value).abs()
+                };
+                let min_deviation = definitions
+                    .iter()
+                    .map(deviation)
+                    .min_by(f64::total_cmp)
+                    .unwrap_or_default();
+                definitions
+                    .iter()
+                    .filter(|definition| match target.tolerance {
+                        Some(_) => target.accepts(definition.get_utilization(&property.label)),
+                        None => deviation(definition) == min_deviation,
+                    })
+                    .collect()
+            }
+        };
+        admissible.retain(|definition| accepted.contains(&definition));
+    }
+
+    let mut admissible: Vec<handler::Definition> = definitions
+        .iter()
+        .filter(|definition| *definition == selection || admissible.contains(definition))
+        .cloned()
+        .collect();
+    admissible.sort_by(|a, b| {
+        a.directory
+            .cmp(&b.directory)
+            .then_with(|| a.knobs.total_cmp(&b.knobs))
+    });
+    admissible
+}
+
+/// Replaces the handler functions of the endpoints by admissible alternatives, until the predicted
+/// total utilization meets the budget.
+///
+/// In each round, the single replacement, which reduces the relative excess of the budget the
+/// most, is applied. The optimization stops, once the budget is met or no replacement reduces the
+/// excess any further. Hence, the assignment is only changed where necessary.
+pub fn optimize_assignment(
+    assignment: &mut [handler::Definition],
+    admissible: &[Vec<handler::Definition>],
+    budget: &Budget,
+) -> BudgetReport {
+    let mut labels: Vec<(&Label, f64)> = budget.0.iter().map(|(l, b)| (l, *b)).collect();
+    labels.sort_by_key(|(label, _)| label.to_string());
+    let totals = |assignment: &[handler::Definition]| -> Vec<f64> {
+        labels
+            .iter()
+            .map(|(label, _)| {
+                assignment
+                    .iter()
+                    .map(|definition| definition.get_utilization(label))
+                    .sum()
+            })
+            .collect()
+    };
+    let excess = |totals: &[f64]| -> f64 {
+        labels
+            .iter()
+            .zip(totals)
+            .map(|((_, budget), total)| (total - budget).max(0.0) / budget)
+            .sum()
+    };
+
+    let initial = totals(assignment);
+    let mut current = initial.clone();
+    for _ in 0..assignment.len() * MAX_ROUNDS_PER_ENDPOINT {
+        let current_excess = excess(&current);
+        if current_excess <= 0.0 {
+            break;
+        }
+        let mut best: Option<(usize, &handler::Definition, Vec<f64>, f64)> = None;
+        for (endpoint, candidates) in admissible.iter().enumerate() {
+            let selected = &assignment[endpoint];
+            for candidate in candidates {
+                if candidate == selected {
+                    continue;
+                }
+                let replaced: Vec<f64> = labels
+                    .iter()
+                    .zip(&current)
+                    .map(|((label, _), total)| {
+                        total - selected.get_utilization(label) + candidate.get_utilization(label)
+                    })
+                    .collect();
+                let replaced_excess = excess(&replaced);
+                let best_excess = best.as_ref().map_or(current_excess, |best| best.3);
+                if replaced_excess < best_excess {
+                    best = Some((endpoint, candidate, replaced, replaced_excess));
+                }
+            }
+        }
+        let Some((endpoint, candidate, replaced, _)) = best else {
+            break;
+        };
+        assignment[endpoint] = candidate.clone();
+        current = replaced;
+    }
+
+    BudgetReport {
+        labels: labels
+            .iter()
+            .zip(initial.into_iter().zip(current))
+            .map(|((label, budget), (initial, predicted))| BudgetUsage {
+                label: label.to_string(),
+                budget: *budget,
+                initial,
+                predicted,
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service_types::{Bucket, Target};
+
+    fn definition(name: &str, cpu: f64, memory: f64) -> handler::Definition {
+        handler::Definition {
+            directory: std::path::PathBuf::from(format!("test/path/{name}")),
+            utilization: HashMap::from_iter([(Label::Cpu, cpu), (Label::Memory, memory)]),
+            curves: HashMap::default(),
+            knobs: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_admissible_definitions() {
+        let mut definitions = [
+            definition("a", 10.0, 100.0),
+            definition("b", 20.0, 10.0),
+            definition("c", 30.0, 50.0),
+            definition("d", 40.0, 20.0),
+            definition("e", 50.0, 200.0),
+            definition("f", 60.0, 30.0),
+        ];
+        let high_cpu = Property {
+            label: Label::Cpu,
+            fraction: 50,
+            intensity: Intensity::Bucket(Bucket::High),
+        };
+        let low_memory = Property {
+            label: Label::Memory,
+            fraction: 50,
+            intensity: Intensity::Target(Target {
+                value: 0.0,
+                tolerance: Some(30.0),
+            }),
+        };
+        let selection = definitions[4].clone();
+
+        let admissible = admissible_definitions(
+            &mut definitions,
+            std::slice::from_ref(&high_cpu),
+            &selection,
+        );
+        let names: Vec<_> = admissible.iter().map(|d| d.directory.clone()).collect();
+        assert_eq!(
+            names,
+            ["test/path/e", "test/path/f"].map(std::path::PathBuf::from)
+        );
+
+        let admissible =
+            admissible_definitions(&mut definitions, &[high_cpu, low_memory], &selection);
+        let names: Vec<_> = admissible.iter().map(|d| d.directory.clone()).collect();
+        assert_eq!(
+            names,
+            ["test/path/e", "test/path/f"].map(std::path::PathBuf::from)
+        );
+    }
+
+    #[test]
+    fn test_optimize_assignment() {
+        let heavy = definition("heavy", 100.0, 500.0);
+        let light = definition("light", 80.0, 50.0);
+        let other = definition("other", 90.0, 400.0);
+        let mut assignment = [heavy.clone(), heavy.clone(), other.clone()];
+        let admissible = vec![
+            vec![heavy.clone(), light.clone()],
+            vec![heavy.clone(), light.clone()],
+            vec![other.clone()],
+        ];
+        let budget = Budget(HashMap::from_iter([(Label::Memory, 1000.0)]));
+
+        let report = optimize_assignment(&mut assignment, &admissible, &budget);
+        assert!(report.is_satisfied(), "unexpected report: {}", report);
+        assert_eq!(report.labels[0].initial, 1400.0);
+        assert_eq!(report.labels[0].predicted, 950.0);
+        assert_eq!(
+            assignment
+                .iter()
+                .filter(|definition| **definition == light)
+                .count(),
+            1
+        );
+
+        let budget = Budget(HashMap::from_iter([(Label::Memory, 100.0)]));
+        let mut assignment = [heavy.clone(), heavy, other];
+        let report = optimize_assignment(&mut assignment, &admissible, &budget);
+        assert!(!report.is_satisfied());
+        assert_eq!(report.labels[0].predicted, 500.0);
+    }
+
+    #[test]
+    fn test_deserialize_budget() {
+        let budget: Budget = serde_yaml::from_str("{ CPU: 400, MEMORY: 2048 }").unwrap();
+        assert_eq!(budget.0[&Label::Cpu], 400.0);
+        assert!(serde_yaml::from_str::<Budget>("{}").is_err());
+        assert!(serde_yaml::from_str::<Budget>("{ CPU: 0 }").is_err());
+    }
+}
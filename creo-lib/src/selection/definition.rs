@@ -1,15 +1,76 @@
 use rand::seq::SliceRandom;
 
+use super::{Error, Result};
 use crate::{handler, service_types};
 
+/// Checks that the utilization of all given labels was profiled for every definition, such that
+/// handler functions can be selected by these labels.
+pub fn check_profiled_labels<'a>(
+    definitions: &[handler::Definition],
+    labels: impl IntoIterator<Item = &'a service_types::Label>,
+) -> Result<()> {
+    for label in labels {
+        if let Some(definition) = definitions.iter().find(|d| !d.has_utilization(label)) {
+            return Err(Error::MissingUtilization {
+                label: label.clone(),
+                definition: definition.directory.clone(),
+            });
+        }
+    }
+    Ok(())
+}
+
 pub fn select_definition(
     definitions: &mut [handler::Definition],
     resource: &service_types::Property,
     rng: &mut impl rand::Rng,
-) -> handler::Definition {
-    sort_language_definitions_by_resource_type(definitions, &resource.label);
-    let bucket = super::select_bucket(definitions, resource);
-    bucket.choose(rng).expect("non empty bucket").clone()
+) -> Result<handler::Definition> {
+    match &resource.intensity {
+        service_types::Intensity::Bucket(bucket) => {
+            sort_language_definitions_by_resource_type(definitions, &resource.label);
+            let bucket = super::select_bucket(definitions, bucket);
+            Ok(bucket.choose(rng).expect("non empty bucket").clone())
+        }
+        service_types::Intensity::Target(target) => {
+            select_nearest_definition(definitions, &resource.label, target, rng)
+        }
+    }
+}
+
+/// Selects the definition, whose utilization of the given resource is nearest to the target.
+/// Ties are broken randomly.
+fn select_nearest_definition(
+    definitions: &[handler::Definition],
+    resource_type: &service_types::Label,
+    target: &service_types::Target,
+    rng: &mut impl rand::Rng,
+) -> Result<handler::Definition> {
+    let distance = |definition: &handler::Definition| {
+        (definition.get_utilization(resource_type) - target.value).abs()
+    };
+    let min_distance = definitions
+        .iter()
+        .map(distance)
+        .min_by(f64::total_cmp)
+        .ok_or(Error::NoDefinitions)?;
+    let nearest: Vec<_> = definitions
+        .iter()
+        .filter(|definition| distance(definition) == min_distance)
+        .collect();
+    let selection = *nearest
+        .choose(rng)
+        .expect("at least one nearest definition");
+
+    let utilization = selection.get_utilization(resource_type);
+    if !target.accepts(utilization) {
+        return Err(Error::NoDefinitionWithinTolerance {
+            label: resource_type.clone(),
+            target: *target,
+            nearest: selection.directory.clone(),
+            utilization,
+        });
+    }
+    Ok(selection.clone())
 }
 
 fn sort_language_definitions_by_resource_type(
@@ -32,14 +93,20 @@ mod tests {
         let one = handler::Definition {
             directory: std::path::PathBuf::from("test/path/1"),
             utilization: HashMap::from_iter([(service_types::Label::Cpu, 0.5)]),
+            curves: HashMap::default(),
+            knobs: Default::default(),
         };
         let two = handler::Definition {
             directory: std::path::PathBuf::from("test/path/2"),
             utilization: HashMap::from_iter([(service_types::Label::Cpu, 1.5)]),
+            curves: HashMap::default(),
+            knobs: Default::default(),
         };
         let three = handler::Definition {
             directory: std::path::PathBuf::from("test/path/3"),
             utilization: HashMap::from_iter([(service_types::Label::Cpu, 0.8)]),
+            curves: HashMap::default(),
+            knobs: Default::default(),
         };
         let mut definitions = [one.clone(), two.clone(), three.clone()];
         sort_language_definitions_by_resource_type(&mut definitions, &service_types::Label::Cpu);
@@ -56,25 +123,32 @@ mod tests {
         let one = handler::Definition {
             directory: std::path::PathBuf::from("test/path/1"),
             utilization: HashMap::from_iter([(service_types::Label::Cpu, 0.5)]),
+            curves: HashMap::default(),
+            knobs: Default::default(),
         };
         let two = handler::Definition {
             directory: std::path::PathBuf::from("test/path/2"),
             utilization: HashMap::from_iter([(service_types::Label::Cpu, 1.5)]),
+            curves: HashMap::default(),
+            knobs: Default::default(),
         };
         let three = handler::Definition {
             directory: std::path::PathBuf::from("test/path/3"),
             utilization: HashMap::from_iter([(service_types::Label::Cpu, 0.8)]),
+            curves: HashMap::default(),
+            knobs: Default::default(),
         };
         let mut definitions = [one.clone(), two.clone(), three.clone()];
         let resource = service_types::Property {
             label: service_types::Label::Cpu,
             fraction: 100,
-            bucket: service_types::Bucket::High,
+            intensity: service_types::Intensity::Bucket(service_types::Bucket::High),
         };
         let mut rng = rand::thread_rng();
 
         for _ in 0..ITER {
-            let selection = select_definition(&mut definitions, &resource, &mut rng);
+            let selection = select_definition(&mut definitions, &resource, &mut rng)
+                .expect("should select a definition");
             assert_eq!(selection, two, "unexpected selection: {}", selection)
         }
     }
@@ -84,26 +158,38 @@ mod tests {
         let one = handler::Definition {
             directory: std::path::PathBuf::from("test/path/1"),
             utilization: HashMap::from_iter([(service_types::Label::Cpu, 0.5)]),
+            curves: HashMap::default(),
+            knobs: Default::default(),
         };
         let two = handler::Definition {
             directory: std::path::PathBuf::from("test/path/2"),
             utilization: HashMap::from_iter([(service_types::Label::Cpu, 1.5)]),
+            curves: HashMap::default(),
+            knobs: Default::default(),
         };
         let three = handler::Definition {
             directory: std::path::PathBuf::from("test/path/3"),
             utilization: HashMap::from_iter([(service_types::Label::Cpu, 0.8)]),
+            curves: HashMap::default(),
+            knobs: Default::default(),
         };
         let four = handler::Definition {
             directory: std::path::PathBuf::from("test/path/3"),
             utilization: HashMap::from_iter([(service_types::Label::Cpu, 0.2)]),
+            curves: HashMap::default(),
+            knobs: Default::default(),
         };
         let five = handler::Definition {
             directory: std::path::PathBuf::from("test/path/3"),
             utilization: HashMap::from_iter([(service_types::Label::Cpu, 3.8)]),
+            curves: HashMap::default(),
+            knobs: Default::default(),
         };
         let six = handler::Definition {
             directory: std::path::PathBuf::from("test/path/3"),
             utilization: HashMap::from_iter([(service_types::Label::Cpu, 0.7)]),
+            curves: HashMap::default(),
+            knobs: Default::default(),
         };
         let mut definitions = [
             one.clone(),
@@ -116,12 +202,13 @@ mod tests {
         let resource = service_types::Property {
             label: service_types::Label::Cpu,
             fraction: 100,
-            bucket: service_types::Bucket::High,
+            intensity: service_types::Intensity::Bucket(service_types::Bucket::High),
         };
         let mut rng = rand::thread_rng();
 
         for _ in 0..ITER {
-            let selection = select_definition(&mut definitions, &resource, &mut rng);
+            let selection = select_definition(&mut definitions, &resource, &mut rng)
+                .expect("should select a definition");
             assert!(
                 (selection == two) || (selection == five),
                 "unexpected selection: {}",
@@ -129,4 +216,112 @@ mod tests {
             )
         }
     }
+
+    #[test]
+    fn test_definition_selection_target() {
+        let one = handler::Definition {
+            directory: std::path::PathBuf::from("test/path/1"),
+            utilization: HashMap::from_iter([(service_types::Label::Cpu, 20.0)]),
+            curves: HashMap::default(),
+            knobs: Default::default(),
+        };
+        let two = handler::Definition {
+            directory: std::path::PathBuf::from("test/path/2"),
+            utilization: HashMap::from_iter([(service_types::Label::Cpu, 45.0)]),
+            curves: HashMap::default(),
+            knobs: Default::default(),
+        };
+        let three = handler::Definition {
+            directory: std::path::PathBuf::from("test/path/3"),
+            utilization: HashMap::from_iter([(service_types::Label::Cpu, 80.0)]),
+            curves: HashMap::default(),
+            knobs: Default::default(),
+        };
+        let mut definitions = [one.clone(), two.clone(), three.clone()];
+        let resource = service_types::Property {
+            label: service_types::Label::Cpu,
+            fraction: 100,
+            intensity: service_types::Intensity::Target(service_types::Target {
+                value: 40.0,
+                tolerance: Some(10.0),
+            }),
+        };
+        let mut rng = rand::thread_rng();
+
+        let selection = select_definition(&mut definitions, &resource, &mut rng)
+            .expect("should select a definition");
+        assert_eq!(selection, two, "unexpected selection: {}", selection);
+    }
+
+    #[test]
+    fn test_definition_selection_target_out_of_tolerance() {
+        let one = handler::Definition {
+            directory: std::path::PathBuf::from("test/path/1"),
+            utilization: HashMap::from_iter([(service_types::Label::Memory, 50.0)]),
+            curves: HashMap::default(),
+            knobs: Default::default(),
+        };
+        let two = handler::Definition {
+            directory: std::path::PathBuf::from("test/path/2"),
+            utilization: HashMap::from_iter([(service_types::Label::Memory, 120.0)]),
+            curves: HashMap::default(),
+            knobs: Default::default(),
+        };
+        let mut definitions = [one, two.clone()];
+        let mut resource = service_types::Property {
+            label: service_types::Label::Memory,
+            fraction: 100,
+            intensity: service_types::Intensity::Target(service_types::Target {
+                value: 200.0,
+                tolerance: Some(50.0),
+            }),
+        };
+        let mut rng = rand::thread_rng();
+
+        let err = select_definition(&mut definitions, &resource, &mut rng)
+            .expect_err("should not select a definition");
+        assert!(
+            matches!(&err, Error::NoDefinitionWithinTolerance { nearest, .. } if *nearest == two.directory),
+            "unexpected error: {}",
+            err
+        );
+
+        resource.intensity = service_types::Intensity::Target(service_types::Target {
+            value: 200.0,
+            tolerance: None,
+        });
+        let selection = select_definition(&mut definitions, &resource, &mut rng)
+            .expect("should select the nearest definition");
+        assert_eq!(selection, two, "unexpected selection: {}", selection);
+    }
+
+    #[test]
+    fn test_check_profiled_labels() {
+        let one = handler::Definition {
+            directory: std::path::PathBuf::from("test/path/1"),
+            utilization: HashMap::from_iter([
+                (service_types::Label::Cpu, 20.0),
+                (service_types::Label::Latency, 3.5),
+            ]),
+            curves: HashMap::default(),
+            knobs: Default::default(),
+        };
+        let two = handler::Definition {
+            directory: std::path::PathBuf::from("test/path/2"),
+            utilization: HashMap::from_iter([(service_types::Label::Cpu, 45.0)]),
+            curves: HashMap::default(),
+            knobs: Default::default(),
+        };
+        let definitions = [one, two.clone()];
+
+        check_profiled_labels(&definitions, &[service_types::Label::Cpu])
+            .expect("all definitions profile the CPU");
+        let err = check_profiled_labels(&definitions, &[service_types::Label::Latency])
+            .expect_err("the second definition lacks a latency");
+        assert!(
+            matches!(&err, Error::MissingUtilization { definition, .. } if *definition == two.directory),
+            "unexpected error: {}",
+            err
+        );
+    }
 }
@@ -0,0 +1,23 @@
+use crate::service_types::{Label, Target};
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("no handler functions available")]
+    NoDefinitions,
+    #[error(
+        "no handler function has a {label} utilization within {target}, the nearest is {nearest} with {utilization}"
+    )]
+    NoDefinitionWithinTolerance {
+        label: Label,
+        target: Target,
+        nearest: std::path::PathBuf,
+        utilization: f64,
+    },
+    #[error("the handler function {definition} has no profiled {label} utilization, please profile it with `creo profile` or re-run `creo profile aggregate` on its benchmark results")]
+    MissingUtilization {
+        label: Label,
+        definition: std::path::PathBuf,
+    },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
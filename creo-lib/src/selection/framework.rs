@@ -0,0 +1,61 @@
+use rand::{seq::SliceRandom, Rng};
+
+use crate::programming_language::{Framework, ProgrammingLanguage};
+
+/// Selects a framework of the given programming language. If any of the available frameworks is a
+/// framework of the language, the frameworks of the language are chosen by their fraction weight
+/// values. Otherwise, the default framework of the language is selected, i.e., mixing frameworks
+/// of a language is opt-in.
+pub fn select_framework<R: Rng>(
+    language: &ProgrammingLanguage,
+    available_frameworks: &[Framework],
+    rng: &mut R,
+) -> Framework {
+    let frameworks: Vec<_> = available_frameworks
+        .iter()
+        .copied()
+        .filter(|framework| framework.is_of(language))
+        .collect();
+    if frameworks.is_empty() {
+        return language.default_framework();
+    }
+
+    *frameworks
+        .choose_weighted(rng, |framework| framework.as_fraction())
+        .expect("should be able to choose a random framework")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COUNT: usize = 10_000;
+
+    #[test]
+    fn test_framework_of_language() {
+        let frameworks = [Framework::Flask(100), Framework::ActixWeb(100)];
+        let mut rng = rand::thread_rng();
+        for _ in 0..COUNT {
+            assert_eq!(
+                select_framework(&ProgrammingLanguage::Python(1), &frameworks, &mut rng),
+                Framework::Flask(100)
+            );
+            assert_eq!(
+                select_framework(&ProgrammingLanguage::Go(1), &frameworks, &mut rng),
+                Framework::NetHttp(1)
+            );
+        }
+    }
+
+    #[test]
+    fn test_framework_weights() {
+        let frameworks = [Framework::Axum(0), Framework::ActixWeb(100)];
+        let mut rng = rand::thread_rng();
+        for _ in 0..COUNT {
+            assert_eq!(
+                select_framework(&ProgrammingLanguage::Rust(1), &frameworks, &mut rng),
+                Framework::ActixWeb(100)
+            );
+        }
+    }
+}
@@ -1,11 +1,24 @@
 mod bucket;
+mod budget;
 mod definition;
+mod error;
+mod framework;
 mod programming_language;
+mod request_rate;
 mod resource;
 mod service_type;
+mod vector;
 
 pub use bucket::select_bucket;
-pub use definition::select_definition;
+pub use budget::{admissible_definitions, optimize_assignment, Budget, BudgetReport, BudgetUsage};
+pub use definition::{check_profiled_labels, select_definition};
+pub use error::{Error, Result};
+pub use framework::select_framework;
 pub use programming_language::select_programming_language;
+pub use request_rate::expected_request_rates;
 pub use resource::select_resource;
 pub use service_type::select_service_type;
+pub use vector::{
+    select_definition_by_distance, AlternativeExplanation, DimensionExplanation,
+    SelectionExplanation, SelectionMode,
+};
@@ -0,0 +1,78 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::{
+    application::CallSemantics,
+    graph::{DiGraph, EndpointIndex, NodeIndex},
+};
+
+/// Returns the number of requests per second each endpoint is expected to receive, indexed by the
+/// endpoint's ID, if the users send `request_rate` requests per second to the application.
+///
+/// The load generator requests all user-facing endpoints equally often, such that each of them
+/// receives an equal share of the request rate. Every inter-service call forwards the request rate
+/// of the calling endpoint, scaled by the call's probability and repetitions. Calls without
+/// semantics are issued exactly once.
+pub fn expected_request_rates(
+    graph: &DiGraph,
+    call_semantics: &HashMap<(EndpointIndex, EndpointIndex), CallSemantics>,
+    request_rate: f64,
+) -> Vec<f64> {
+    let mut in_degrees: Vec<_> = graph
+        .iter_nodes()
+        .map(|v| graph.predecssors(v).count())
+        .collect();
+    let mut queue: VecDeque<NodeIndex> = graph
+        .iter_nodes()
+        .filter(|v| in_degrees[v.0] == 0)
+        .collect();
+    let mut rates = vec![0.0; graph.node_count()];
+    let frontend_rate = request_rate / queue.len().max(1) as f64;
+    for v in queue.iter() {
+        rates[v.0] = frontend_rate;
+    }
+
+    while let Some(source) = queue.pop_front() {
+        for target in graph.successors(source) {
+            let semantics = call_semantics
+                .get(&(source.into(), target.into()))
+                .copied()
+                .unwrap_or_default();
+            rates[target.0] +=
+                rates[source.0] * semantics.probability * semantics.repetitions as f64;
+            in_degrees[target.0] -= 1;
+            if in_degrees[target.0] == 0 {
+                queue.push_back(target);
+            }
+        }
+    }
+
+    rates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::DiGraphBuilder;
+
+    #[test]
+    fn test_expected_request_rates() {
+        // N0 ---> N2 ---> N3
+        //          ^
+        // N1 ------+
+        let graph = DiGraphBuilder::with_node_and_edge_count(4, 3)
+            .add_nodes(4)
+            .add_edges(&[(0, 2).into(), (1, 2).into(), (2, 3).into()])
+            .build();
+        let call_semantics = HashMap::from_iter([(
+            (EndpointIndex(2), EndpointIndex(3)),
+            CallSemantics {
+                probability: 0.5,
+                repetitions: 3,
+                ..Default::default()
+            },
+        )]);
+
+        let rates = expected_request_rates(&graph, &call_semantics, 100.0);
+        assert_eq!(rates, vec![50.0, 50.0, 100.0, 150.0]);
+    }
+}
@@ -22,12 +22,12 @@ mod tests {
         let cpu_label = service_types::Property {
             label: service_types::Label::Cpu,
             fraction: 100,
-            bucket: service_types::Bucket::High,
+            intensity: service_types::Intensity::Bucket(service_types::Bucket::High),
         };
         let memory_label = service_types::Property {
             label: service_types::Label::Memory,
             fraction: 0,
-            bucket: service_types::Bucket::High,
+            intensity: service_types::Intensity::Bucket(service_types::Bucket::High),
         };
         let service_type = service_types::ServiceType {
             fraction: 100,
@@ -49,12 +49,12 @@ mod tests {
         let cpu_label = service_types::Property {
             label: service_types::Label::Cpu,
             fraction: 50,
-            bucket: service_types::Bucket::High,
+            intensity: service_types::Intensity::Bucket(service_types::Bucket::High),
         };
         let memory_label = service_types::Property {
             label: service_types::Label::Memory,
             fraction: 50,
-            bucket: service_types::Bucket::High,
+            intensity: service_types::Intensity::Bucket(service_types::Bucket::High),
         };
         let service_type = service_types::ServiceType {
             fraction: 100,
@@ -27,12 +27,12 @@ mod tests {
         let cpu_label = service_types::Property {
             label: service_types::Label::Cpu,
             fraction: 100,
-            bucket: service_types::Bucket::High,
+            intensity: service_types::Intensity::Bucket(service_types::Bucket::High),
         };
         let memory_label = service_types::Property {
             label: service_types::Label::Memory,
             fraction: 100,
-            bucket: service_types::Bucket::High,
+            intensity: service_types::Intensity::Bucket(service_types::Bucket::High),
         };
         let cpu_service_type = service_types::ServiceType {
             fraction: 100,
@@ -61,12 +61,12 @@ mod tests {
         let cpu_label = service_types::Property {
             label: service_types::Label::Cpu,
             fraction: 100,
-            bucket: service_types::Bucket::High,
+            intensity: service_types::Intensity::Bucket(service_types::Bucket::High),
         };
         let memory_label = service_types::Property {
             label: service_types::Label::Memory,
             fraction: 100,
-            bucket: service_types::Bucket::High,
+            intensity: service_types::Intensity::Bucket(service_types::Bucket::High),
         };
         let cpu_service_type = service_types::ServiceType {
             fraction: 80,
@@ -0,0 +1,377 @@
+use rand::seq::SliceRandom;
+use strum::IntoEnumIterator;
+
+use super::{Error, Result};
+use crate::{
+    handler,
+    service_types::{Bucket, Intensity, Label, ServiceType},
+};
+
+/// The weight of the resources, which a service type does not specify. These resources should be
+/// used as little as possible, but matter less than the specified resources.
+const UNSPECIFIED_WEIGHT: f64 = 0.1;
+/// The number of runner-up handler functions recorded in a [`SelectionExplanation`].
+const N_ALTERNATIVES: usize = 3;
+
+/// [`SelectionMode`] specifies, how the handler functions of the endpoints are selected.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SelectionMode {
+    /// Each endpoint chooses one property of its service type at random and selects a handler
+    /// function by the utilization of this property's label only.
+    #[default]
+    Label,
+    /// Each endpoint selects the handler function, whose utilization of all labels is nearest to
+    /// the target vector of its service type.
+    Vector,
+}
+
+/// [`SelectionExplanation`] explains, why a handler function was selected for an endpoint.
+#[derive(serde::Serialize, Clone, Debug, PartialEq)]
+pub struct SelectionExplanation {
+    /// The name of the selected handler function.
+    pub handler_function: String,
+    /// The weighted distance of the selected handler function to the target vector in `[0, 1]`.
+    pub distance: f64,
+    /// The target and the selected handler function's value of every label.
+    pub dimensions: Vec<DimensionExplanation>,
+    /// The handler functions nearest to the target vector after the selected one.
+    pub alternatives: Vec<AlternativeExplanation>,
+    /// The number of requests per second the endpoint is expected to receive, if the handler
+    /// function was selected by its utilization at this request rate.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_rate: Option<f64>,
+    /// The handler function, which replaced the selected one to meet the budget of the
+    /// application, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replaced_by: Option<String>,
+}
+
+/// [`DimensionExplanation`] describes a single label of the target vector.
+#[derive(serde::Serialize, Clone, Debug, PartialEq)]
+pub struct DimensionExplanation {
+    pub label: String,
+    /// The weight of the label in the distance.
+    pub weight: f64,
+    /// The targeted percentile of the label's utilization in `[0, 1]`.
+    pub target: f64,
+    /// The percentile of the selected handler function's utilization in `[0, 1]`.
+    pub percentile: f64,
+    /// The profiled (or predicted) utilization of the selected handler function.
+    pub utilization: f64,
+}
+
+/// [`AlternativeExplanation`] describes a handler function, which was not selected.
+#[derive(serde::Serialize, Clone, Debug, PartialEq)]
+pub struct AlternativeExplanation {
+    pub handler_function: String,
+    pub distance: f64,
+}
+
+/// A single label of the target vector of a service type.
+struct Dimension {
+    label: Label,
+    weight: f64,
+    target: f64,
+    /// The ascending utilizations of all handler functions.
+    utilizations: Vec<f64>,
+}
+
+/// Selects the handler function, whose utilization vector is nearest to the target vector of the
+/// service type. Ties are broken randomly.
+///
+/// The utilizations of each label are normalized to their percentile among all handler functions,
+/// such that a single outlier does not dominate the distance. The properties of the service type
+/// target the center of their bucket, or the percentile of their numeric target, weighted by their
+/// fraction. All other labels target the center of the `LOW` bucket with a small weight, unless
+/// they were not profiled for all handler functions.
+pub fn select_definition_by_distance(
+    definitions: &[handler::Definition],
+    service_type: &ServiceType,
+    rng: &mut impl rand::Rng,
+) -> Result<(handler::Definition, SelectionExplanation)> {
+    let candidates = filter_by_tolerance(definitions, service_type)?;
+    let dimensions = determine_dimensions(definitions, service_type);
+    let distance = |definition: &handler::Definition| {
+        let (squares, weights) = dimensions.iter().fold((0.0, 0.0), |(squares, weights), d| {
+            let deviation =
+                percentile(&d.utilizations, definition.get_utilization(&d.label)) - d.target;
+            (
+                squares + d.weight * deviation * deviation,
+                weights + d.weight,
+            )
+        });
+        (squares / weights).sqrt()
+    };
+
+    let mut ranking: Vec<_> = candidates
+        .into_iter()
+        .map(|definition| (definition, distance(definition)))
+        .collect();
+    ranking.shuffle(rng);
+    ranking.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+    let (selection, min_distance) = *ranking.first().ok_or(Error::NoDefinitions)?;
+
+    let explanation = SelectionExplanation {
+        handler_function: selection.name(),
+        distance: min_distance,
+        dimensions: dimensions
+            .iter()
+            .map(|d| {
+                let utilization = selection.get_utilization(&d.label);
+                DimensionExplanation {
+                    label: d.label.to_string(),
+                    weight: d.weight,
+                    target: d.target,
+                    percentile: percentile(&d.utilizations, utilization),
+                    utilization,
+                }
+            })
+            .collect(),
+        alternatives: ranking
+            .iter()
+            .skip(1)
+            .take(N_ALTERNATIVES)
+            .map(|(definition, distance)| AlternativeExplanation {
+                handler_function: definition.name(),
+                distance: *distance,
+            })
+            .collect(),
+        request_rate: None,
+        replaced_by: None,
+    };
+    Ok((selection.clone(), explanation))
+}
+
+/// Returns the definitions, whose utilizations are within the tolerances of all numeric targets
+/// of the service type.
+fn filter_by_tolerance<'a>(
+    definitions: &'a [handler::Definition],
+    service_type: &ServiceType,
+) -> Result<Vec<&'a handler::Definition>> {
+    let mut candidates: Vec<_> = definitions.iter().collect();
+    for property in &service_type.properties {
+        let Intensity::Target(target) = &property.intensity else {
+            continue;
+        };
+        let accepted: Vec<_> = candidates
+            .iter()
+            .copied()
+            .filter(|definition| target.accepts(definition.get_utilization(&property.label)))
+            .collect();
+        if accepted.is_empty() {
+            let deviation = |definition: &&handler::Definition| {
+                (definition.get_utilization(&property.label) - target.value).abs()
+            };
+            let nearest = candidates
+                .iter()
+                .min_by(|a, b| deviation(a).total_cmp(&deviation(b)))
+                .ok_or(Error::NoDefinitions)?;
+            return Err(Error::NoDefinitionWithinTolerance {
+                label: property.label.clone(),
+                target: *target,
+                nearest: nearest.directory.clone(),
+                utilization: nearest.get_utilization(&property.label),
+            });
+        }
+        candidates = accepted;
+    }
+    Ok(candidates)
+}
+
+fn determine_dimensions(
+    definitions: &[handler::Definition],
+    service_type: &ServiceType,
+) -> Vec<Dimension> {
+    Label::iter()
+        .filter(|label| {
+            service_type.properties.iter().any(|p| p.label == *label)
+                || definitions.iter().all(|d| d.has_utilization(label))
+        })
+        .map(|label| {
+            let mut utilizations: Vec<_> = definitions
+                .iter()
+                .map(|definition| definition.get_utilization(&label))
+                .collect();
+            utilizations.sort_by(f64::total_cmp);
+            let (weight, target) = match service_type
+                .properties
+                .iter()
+                .find(|property| property.label == label)
+            {
+                Some(property) => {
+                    let target = match &property.intensity {
+                        Intensity::Bucket(bucket) => bucket_center(bucket),
+                        Intensity::Target(target) => percentile(&utilizations, target.value),
+                    };
+                    (property.fraction as f64 / 100.0, target)
+                }
+                None => (UNSPECIFIED_WEIGHT, bucket_center(&Bucket::Low)),
+            };
+            Dimension {
+                label,
+                weight,
+                target,
+                utilizations,
+            }
+        })
+        .collect()
+}
+
+fn bucket_center(bucket: &Bucket) -> f64 {
+    match bucket {
+        Bucket::Low => 1.0 / 6.0,
+        Bucket::Medium => 0.5,
+        Bucket::High => 5.0 / 6.0,
+    }
+}
+
+/// Returns the percentile of the value among the ascending utilizations in `[0, 1]`. Equal
+/// utilizations share their average rank, and values between two utilizations are interpolated
+/// linearly.
+fn percentile(utilizations: &[f64], value: f64) -> f64 {
+    let n = utilizations.len();
+    if n < 2 {
+        return 0.5;
+    }
+    let lower = utilizations.partition_point(|utilization| *utilization < value);
+    let upper = utilizations.partition_point(|utilization| *utilization <= value);
+    let rank = if lower < upper {
+        (lower + upper - 1) as f64 / 2.0
+    } else if lower == 0 {
+        0.0
+    } else if lower == n {
+        (n - 1) as f64
+    } else {
+        let (below, above) = (utilizations[lower - 1], utilizations[lower]);
+        (lower - 1) as f64 + (value - below) / (above - below)
+    };
+    rank / (n - 1) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::service_types::{Property, Target};
+
+    fn definition(name: &str, cpu: f64, memory: f64) -> handler::Definition {
+        let mut utilization: HashMap<_, _> = Label::iter().map(|label| (label, 0.0)).collect();
+        utilization.insert(Label::Cpu, cpu);
+        utilization.insert(Label::Memory, memory);
+        handler::Definition {
+            directory: std::path::PathBuf::from(format!("test/path/{name}")),
+            utilization,
+            curves: HashMap::default(),
+            knobs: Default::default(),
+        }
+    }
+
+    fn service_type(properties: Vec<Property>) -> ServiceType {
+        ServiceType {
+            fraction: 100,
+            properties,
+        }
+    }
+
+    #[test]
+    fn test_percentile() {
+        let utilizations = [1.0, 2.0, 2.0, 4.0, 5.0];
+        assert_eq!(percentile(&utilizations, 1.0), 0.0);
+        assert_eq!(percentile(&utilizations, 2.0), 0.375);
+        assert_eq!(percentile(&utilizations, 3.0), 0.625);
+        assert_eq!(percentile(&utilizations, 5.0), 1.0);
+        assert_eq!(percentile(&utilizations, 0.5), 0.0);
+        assert_eq!(percentile(&utilizations, 9.0), 1.0);
+        assert_eq!(percentile(&[3.0], 1.0), 0.5);
+    }
+
+    #[test]
+    fn test_select_by_distance() {
+        let definitions = [
+            definition("cpu_and_memory", 900.0, 800.0),
+            definition("cpu_only", 800.0, 10.0),
+            definition("memory_only", 20.0, 900.0),
+            definition("idle", 10.0, 20.0),
+        ];
+        let service_type = service_type(vec![
+            Property {
+                label: Label::Cpu,
+                fraction: 50,
+                intensity: Intensity::Bucket(Bucket::High),
+            },
+            Property {
+                label: Label::Memory,
+                fraction: 50,
+                intensity: Intensity::Bucket(Bucket::Low),
+            },
+        ]);
+        let mut rng = rand::thread_rng();
+
+        let (selection, explanation) =
+            select_definition_by_distance(&definitions, &service_type, &mut rng)
+                .expect("should select a definition");
+        assert_eq!(selection, definitions[1]);
+        assert_eq!(explanation.handler_function, "cpu_only");
+        assert_eq!(explanation.dimensions.len(), Label::iter().count());
+        assert_eq!(explanation.alternatives.len(), N_ALTERNATIVES);
+        assert!(explanation
+            .alternatives
+            .iter()
+            .all(|alternative| alternative.distance >= explanation.distance));
+    }
+
+    #[test]
+    fn test_select_by_distance_skips_unprofiled_labels() {
+        let mut definitions = [
+            definition("slow", 10.0, 10.0),
+            definition("fast", 10.0, 10.0),
+        ];
+        definitions[0].utilization.insert(Label::Latency, 250.0);
+        definitions[1].utilization.remove(&Label::Latency);
+        let mut rng = rand::thread_rng();
+
+        let (_, explanation) =
+            select_definition_by_distance(&definitions, &service_type(vec![]), &mut rng)
+                .expect("should select a definition");
+        assert!(explanation
+            .dimensions
+            .iter()
+            .all(|dimension| dimension.label != Label::Latency.to_string()));
+    }
+
+    #[test]
+    fn test_select_by_distance_tolerance() {
+        let definitions = [
+            definition("cpu_and_memory", 900.0, 800.0),
+            definition("cpu_only", 800.0, 10.0),
+        ];
+        let mut service_type = service_type(vec![
+            Property {
+                label: Label::Cpu,
+                fraction: 50,
+                intensity: Intensity::Bucket(Bucket::High),
+            },
+            Property {
+                label: Label::Memory,
+                fraction: 50,
+                intensity: Intensity::Target(Target {
+                    value: 750.0,
+                    tolerance: Some(100.0),
+                }),
+            },
+        ]);
+        let mut rng = rand::thread_rng();
+
+        let (selection, _) = select_definition_by_distance(&definitions, &service_type, &mut rng)
+            .expect("should select a definition");
+        assert_eq!(selection, definitions[0]);
+
+        service_type.properties[1].intensity = Intensity::Target(Target {
+            value: 400.0,
+            tolerance: Some(100.0),
+        });
+        assert!(select_definition_by_distance(&definitions, &service_type, &mut rng).is_err());
+    }
+}
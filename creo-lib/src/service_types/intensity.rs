@@ -20,3 +20,51 @@ impl Display for Bucket {
         f.write_str(s)
     }
 }
+
+/// [`Target`] describes a numeric resource utilization, which a handler function should have.
+///
+/// The value is given in the unit of the profiled `utilization.yml` files, e.g., percent of a CPU
+/// core for `CPU` or MB for `MEMORY`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Target {
+    /// The targeted utilization.
+    pub value: f64,
+    /// The (optional) maximum absolute deviation of a handler function's utilization from `value`.
+    pub tolerance: Option<f64>,
+}
+
+impl Target {
+    /// Returns whether the given utilization deviates at most `tolerance` from the target.
+    pub fn accepts(&self, utilization: f64) -> bool {
+        self.tolerance
+            .is_none_or(|tolerance| (utilization - self.value).abs() <= tolerance)
+    }
+}
+
+impl Display for Target {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.tolerance {
+            Some(tolerance) => write!(f, "{}±{}", self.value, tolerance),
+            None => write!(f, "{}", self.value),
+        }
+    }
+}
+
+/// [`Intensity`] describes how intensively a handler function uses a resource.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Intensity {
+    /// The handler function is one of the lowest, middle or highest third of all handler
+    /// functions of a programming language, ordered by their utilization.
+    Bucket(Bucket),
+    /// The handler function's utilization is nearest to a numeric target.
+    Target(Target),
+}
+
+impl Display for Intensity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Intensity::Bucket(bucket) => bucket.fmt(f),
+            Intensity::Target(target) => target.fmt(f),
+        }
+    }
+}
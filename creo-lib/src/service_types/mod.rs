@@ -1,11 +1,13 @@
 mod intensity;
+mod preset;
 mod resource;
 mod resource_type;
 mod service_type;
 mod utilization;
 
-pub use intensity::Bucket;
+pub use intensity::{Bucket, Intensity, Target};
+pub use preset::Preset;
 pub use resource::Property;
 pub use resource_type::Label;
 pub use service_type::{ServiceType, ServiceTypeVec};
-pub use utilization::Utilization;
+pub use utilization::{LoadLevel, Utilization, UtilizationCurve, KNOBS_KEY, UNPROFILED_KEY};
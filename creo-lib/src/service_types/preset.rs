@@ -0,0 +1,78 @@
+use super::{Bucket, Intensity, Label, Property};
+
+/// [`Preset`] is a named, reusable set of properties of a service type.
+#[derive(
+    serde::Deserialize,
+    strum_macros::Display,
+    strum_macros::EnumIter,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum Preset {
+    /// Endpoints mostly consume a lot of CPU, but little memory.
+    CpuBound,
+    /// Endpoints mostly consume a lot of memory, but little CPU.
+    MemoryBound,
+    /// Endpoints mostly read and write a lot from disk, but consume little CPU.
+    IoBound,
+    /// Endpoints mostly receive and transmit a lot over the network, but consume little CPU.
+    NetworkBound,
+    /// Endpoints consume a medium amount of CPU and memory.
+    Balanced,
+}
+
+impl Preset {
+    /// Returns the properties of the preset.
+    pub fn properties(&self) -> Vec<Property> {
+        let property = |label, fraction, bucket| Property {
+            label,
+            fraction,
+            intensity: Intensity::Bucket(bucket),
+        };
+        match self {
+            Preset::CpuBound => vec![
+                property(Label::Cpu, 70, Bucket::High),
+                property(Label::Memory, 30, Bucket::Low),
+            ],
+            Preset::MemoryBound => vec![
+                property(Label::Memory, 70, Bucket::High),
+                property(Label::Cpu, 30, Bucket::Low),
+            ],
+            Preset::IoBound => vec![
+                property(Label::DiskRead, 35, Bucket::High),
+                property(Label::DiskWrite, 35, Bucket::High),
+                property(Label::Cpu, 30, Bucket::Low),
+            ],
+            Preset::NetworkBound => vec![
+                property(Label::NetworkReceive, 35, Bucket::High),
+                property(Label::NetworkTransmit, 35, Bucket::High),
+                property(Label::Cpu, 30, Bucket::Low),
+            ],
+            Preset::Balanced => vec![
+                property(Label::Cpu, 50, Bucket::Medium),
+                property(Label::Memory, 50, Bucket::Medium),
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn test_preset_fractions() {
+        for preset in Preset::iter() {
+            let sum: u8 = preset.properties().iter().map(|p| p.fraction).sum();
+            assert_eq!(sum, 100, "unexpected fractions of preset {}", preset);
+        }
+    }
+}
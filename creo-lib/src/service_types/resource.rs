@@ -1,13 +1,23 @@
 use std::{fmt::Display, hash::Hash};
 
-use super::{Bucket, Label};
+use super::{Bucket, Intensity, Label, Target};
 
-#[derive(serde::Deserialize, Clone, Debug)]
-#[serde(remote = "Self")]
+#[derive(Clone, Debug)]
 pub struct Property {
     pub label: Label,
     pub fraction: u8,
-    pub bucket: Bucket,
+    pub intensity: Intensity,
+}
+
+/// The raw representation of a [`Property`], which specifies either a `bucket` or a numeric
+/// `target` with an optional `tolerance`.
+#[derive(serde::Deserialize)]
+struct PropertyRepr {
+    label: Label,
+    fraction: u8,
+    bucket: Option<Bucket>,
+    target: Option<f64>,
+    tolerance: Option<f64>,
 }
 
 impl<'de> serde::Deserialize<'de> for Property {
@@ -15,16 +25,57 @@ impl<'de> serde::Deserialize<'de> for Property {
     where
         D: serde::Deserializer<'de>,
     {
-        let this = Self::deserialize(deserializer)?;
+        let repr = PropertyRepr::deserialize(deserializer)?;
 
-        if this.fraction < 1 || this.fraction > 100 {
+        if repr.fraction < 1 || repr.fraction > 100 {
             return Err(serde::de::Error::custom(format!(
                 "expected fraction to be in the range of 1..=100, but was {}",
-                this.fraction
+                repr.fraction
             )));
         }
 
-        Ok(this)
+        let intensity = match (repr.bucket, repr.target, repr.tolerance) {
+            (Some(bucket), None, None) => Intensity::Bucket(bucket),
+            (Some(_), _, _) => {
+                return Err(serde::de::Error::custom(format!(
+                    "expected either a bucket or a target for resource {}, but got both",
+                    repr.label
+                )))
+            }
+            (None, Some(value), tolerance) => {
+                if !value.is_finite() || value < 0.0 {
+                    return Err(serde::de::Error::custom(format!(
+                        "expected the target of resource {} to be a non-negative number, but was {}",
+                        repr.label, value
+                    )));
+                }
+                if let Some(tolerance) = tolerance.filter(|t| !t.is_finite() || *t < 0.0) {
+                    return Err(serde::de::Error::custom(format!(
+                        "expected the tolerance of resource {} to be a non-negative number, but was {}",
+                        repr.label, tolerance
+                    )));
+                }
+                Intensity::Target(Target { value, tolerance })
+            }
+            (None, None, Some(_)) => {
+                return Err(serde::de::Error::custom(format!(
+                    "expected a target for the tolerance of resource {}",
+                    repr.label
+                )))
+            }
+            (None, None, None) => {
+                return Err(serde::de::Error::custom(format!(
+                    "expected either a bucket or a target for resource {}",
+                    repr.label
+                )))
+            }
+        };
+
+        Ok(Self {
+            label: repr.label,
+            fraction: repr.fraction,
+            intensity,
+        })
     }
 }
 
@@ -44,6 +95,45 @@ impl Hash for Property {
 
 impl Display for Property {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} ({}, {}%)", self.label, self.bucket, self.fraction)
+        write!(f, "{} ({}, {}%)", self.label, self.intensity, self.fraction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_bucket() {
+        let property: Property =
+            serde_yaml::from_str("{ label: CPU, fraction: 100, bucket: HIGH }").unwrap();
+        assert_eq!(property.intensity, Intensity::Bucket(Bucket::High));
+    }
+
+    #[test]
+    fn test_deserialize_target() {
+        let property: Property =
+            serde_yaml::from_str("{ label: MEMORY, fraction: 50, target: 200, tolerance: 25 }")
+                .unwrap();
+        assert_eq!(
+            property.intensity,
+            Intensity::Target(Target {
+                value: 200.0,
+                tolerance: Some(25.0)
+            })
+        );
+
+        assert!(serde_yaml::from_str::<Property>("{ label: CPU, fraction: 100 }").is_err());
+        assert!(serde_yaml::from_str::<Property>(
+            "{ label: CPU, fraction: 100, bucket: LOW, target: 40 }"
+        )
+        .is_err());
+        assert!(
+            serde_yaml::from_str::<Property>("{ label: CPU, fraction: 100, tolerance: 5 }")
+                .is_err()
+        );
+        assert!(
+            serde_yaml::from_str::<Property>("{ label: CPU, fraction: 100, target: -1 }").is_err()
+        );
     }
 }
@@ -1,6 +1,6 @@
 #[derive(serde::Deserialize, Clone, Debug)]
 #[serde(remote = "Self")]
-pub struct ResourceDistribution{
+pub struct ResourceDistribution {
     pub low: u8,
     pub mid: u8,
     pub high: u8,
@@ -1,6 +1,6 @@
 use std::fmt;
 
-#[derive(serde::Deserialize, Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(serde::Deserialize, strum_macros::EnumIter, Clone, PartialEq, Eq, Hash, Debug)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Label {
     Cpu,
@@ -9,6 +9,8 @@ pub enum Label {
     NetworkTransmit,
     DiskRead,
     DiskWrite,
+    /// The average response time of the handler function in milliseconds.
+    Latency,
 }
 
 use Label::*;
@@ -22,6 +24,7 @@ impl fmt::Display for Label {
             NetworkTransmit => "NETWORK_TRANSMIT",
             DiskRead => "DISK_READ",
             DiskWrite => "DISK_WRITE",
+            Latency => "LATENCY",
         };
         f.write_str(s)
     }
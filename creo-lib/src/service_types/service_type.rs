@@ -1,10 +1,19 @@
 use std::{collections::HashSet, fmt::Display};
 
-use super::Property;
+use serde::Deserialize;
 
-#[derive(serde::Deserialize, Clone, Debug, PartialEq, Eq)]
-#[serde(remote = "Self")]
+use super::{Preset, Property};
+
+/// The selection weight of a service type, which does not specify a fraction.
+const DEFAULT_FRACTION: u8 = 100;
+
+/// [`ServiceType`] specifies the resource usage profile of a microservice.
+///
+/// A service type is either specified by its properties or by the name of a [`Preset`], e.g.,
+/// `cpu-bound` or `{ fraction: 50, preset: cpu-bound }`.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ServiceType {
+    /// The selection weight of the service type (Default: `100`).
     pub fraction: u8,
     pub properties: Vec<Property>,
 }
@@ -19,12 +28,74 @@ impl Display for ServiceType {
     }
 }
 
+/// The raw representation of a [`ServiceType`], which specifies either a `preset` or its
+/// `properties`.
+#[derive(serde::Deserialize)]
+struct ServiceTypeRepr {
+    #[serde(default = "default_fraction")]
+    fraction: u8,
+    preset: Option<Preset>,
+    properties: Option<Vec<Property>>,
+}
+
+fn default_fraction() -> u8 {
+    DEFAULT_FRACTION
+}
+
+struct ServiceTypeVisitor;
+
+impl<'de> serde::de::Visitor<'de> for ServiceTypeVisitor {
+    type Value = ServiceTypeRepr;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a preset name or a service type definition")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        let preset = Preset::deserialize(serde::de::value::StrDeserializer::<E>::new(v))?;
+        Ok(ServiceTypeRepr {
+            fraction: DEFAULT_FRACTION,
+            preset: Some(preset),
+            properties: None,
+        })
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        ServiceTypeRepr::deserialize(serde::de::value::MapAccessDeserializer::new(map))
+    }
+}
+
 impl<'de> serde::Deserialize<'de> for ServiceType {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        let this = Self::deserialize(deserializer)?;
+        let repr = deserializer.deserialize_any(ServiceTypeVisitor)?;
+        let properties = match (repr.preset, repr.properties) {
+            (Some(preset), None) => preset.properties(),
+            (None, Some(properties)) => properties,
+            (Some(preset), Some(_)) => {
+                return Err(serde::de::Error::custom(format!(
+                "expected either a preset or properties, but got both the preset {} and properties",
+                preset
+            )))
+            }
+            (None, None) => {
+                return Err(serde::de::Error::custom(
+                    "expected either a preset or properties",
+                ))
+            }
+        };
+        let this = Self {
+            fraction: repr.fraction,
+            properties,
+        };
 
         if this.properties.is_empty() {
             return Err(serde::de::Error::custom("resources list must be non empty"));
@@ -57,3 +128,32 @@ impl<'de> serde::Deserialize<'de> for ServiceType {
 
 #[derive(serde::Deserialize, Debug)]
 pub struct ServiceTypeVec(pub crate::de::NonEmptyVec<ServiceType>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_preset() {
+        let by_name: ServiceType = serde_yaml::from_str("cpu-bound").unwrap();
+        assert_eq!(by_name.fraction, DEFAULT_FRACTION);
+        assert_eq!(by_name.properties, Preset::CpuBound.properties());
+
+        let with_fraction: ServiceType =
+            serde_yaml::from_str("{ fraction: 20, preset: balanced }").unwrap();
+        assert_eq!(with_fraction.fraction, 20);
+        assert_eq!(with_fraction.properties, Preset::Balanced.properties());
+
+        assert!(serde_yaml::from_str::<ServiceType>("gpu-bound").is_err());
+    }
+
+    #[test]
+    fn test_deserialize_preset_or_properties() {
+        let both = serde_yaml::from_str::<ServiceType>(
+            "{ preset: io-bound, properties: [{ label: CPU, fraction: 100, bucket: HIGH }] }",
+        );
+        assert!(both.is_err());
+        let neither = serde_yaml::from_str::<ServiceType>("{ fraction: 50 }");
+        assert!(neither.is_err());
+    }
+}
@@ -1,6 +1,245 @@
 use std::collections::HashMap;
 
+use crate::{handler::KnobSetting, stats::regression::Polynomial};
+
 use super::Label;
 
-#[derive(serde::Deserialize, Debug)]
-pub struct Utilization(pub HashMap<Label, f64>);
+/// The maximum degree of the polynomial model fitted to a [`UtilizationCurve`].
+const POLYNOMIAL_DEGREE: usize = 2;
+
+/// The key marking a placeholder utilization file of a handler function, which was not profiled
+/// yet.
+pub const UNPROFILED_KEY: &str = "unprofiled";
+
+/// The key of the utilization profiled for each knob setting of a handler function.
+pub const KNOBS_KEY: &str = "knobs";
+
+/// [`Utilization`] is the profiled resource utilization of a handler function.
+///
+/// Each label is either profiled as a single average over all load levels, or as a
+/// [`UtilizationCurve`], which additionally keeps the utilization per load level.
+#[derive(Debug, Default)]
+pub struct Utilization {
+    /// The average utilization over all load levels per label.
+    pub averages: HashMap<Label, f64>,
+    /// The utilization per load level, if it was profiled.
+    pub curves: HashMap<Label, UtilizationCurve>,
+    /// Whether the utilization is a placeholder, as the handler function was not profiled yet.
+    pub unprofiled: bool,
+    /// The utilization of each profiled knob setting, if the handler function has knobs.
+    pub knobs: Vec<KnobUtilization>,
+}
+
+/// [`KnobUtilization`] is the profiled resource utilization of a handler function, whose knobs
+/// are set to the given values.
+#[derive(Debug, serde::Deserialize)]
+pub struct KnobUtilization {
+    pub setting: KnobSetting,
+    pub utilization: Utilization,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum UtilizationRepr {
+    Average(f64),
+    Curve(UtilizationCurve),
+}
+
+enum UtilizationKey {
+    Label(Label),
+    Unprofiled,
+    Knobs,
+}
+
+impl<'de> serde::Deserialize<'de> for UtilizationKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::IntoDeserializer;
+
+        let key = String::deserialize(deserializer)?;
+        if key == UNPROFILED_KEY {
+            return Ok(Self::Unprofiled);
+        }
+        if key == KNOBS_KEY {
+            return Ok(Self::Knobs);
+        }
+        Label::deserialize(key.as_str().into_deserializer()).map(Self::Label)
+    }
+}
+
+struct UtilizationVisitor;
+
+impl<'de> serde::de::Visitor<'de> for UtilizationVisitor {
+    type Value = Utilization;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a map from labels to utilizations")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        let mut this = Utilization::default();
+        while let Some(key) = map.next_key()? {
+            match key {
+                UtilizationKey::Label(label) => match map.next_value()? {
+                    UtilizationRepr::Average(average) => {
+                        this.averages.insert(label, average);
+                    }
+                    UtilizationRepr::Curve(curve) => {
+                        this.averages.insert(label.clone(), curve.average);
+                        this.curves.insert(label, curve);
+                    }
+                },
+                UtilizationKey::Unprofiled => this.unprofiled = map.next_value()?,
+                UtilizationKey::Knobs => this.knobs = map.next_value()?,
+            }
+        }
+        Ok(this)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Utilization {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(UtilizationVisitor)
+    }
+}
+
+/// [`LoadLevel`] is the average utilization measured at a constant request rate.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct LoadLevel {
+    /// The number of requests per second.
+    pub rps: f64,
+    /// The average utilization at this request rate.
+    pub utilization: f64,
+}
+
+/// [`UtilizationCurve`] describes how the utilization of a handler function scales with the request
+/// rate.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct UtilizationCurve {
+    /// The average utilization over all load levels.
+    pub average: f64,
+    /// The profiled load levels, sorted by their request rate.
+    pub points: Vec<LoadLevel>,
+    /// The linear model fitted to the load levels.
+    pub linear: Polynomial,
+    /// The polynomial model fitted to the load levels.
+    pub polynomial: Polynomial,
+}
+
+impl UtilizationCurve {
+    /// Creates the curve through the given load levels and fits its models.
+    pub fn new(mut points: Vec<LoadLevel>) -> Self {
+        points.sort_by(|a, b| a.rps.total_cmp(&b.rps));
+        let average = if points.is_empty() {
+            0.0
+        } else {
+            points.iter().map(|p| p.utilization).sum::<f64>() / points.len() as f64
+        };
+        let xy: Vec<_> = points.iter().map(|p| (p.rps, p.utilization)).collect();
+        Self {
+            average,
+            linear: Polynomial::fit(&xy, 1),
+            polynomial: Polynomial::fit(&xy, POLYNOMIAL_DEGREE),
+            points,
+        }
+    }
+
+    /// Predicts the utilization at the given request rate.
+    ///
+    /// Within the profiled range of request rates, the polynomial model is used. Outside of it,
+    /// the linear model extrapolates the utilization, as the polynomial model tends to diverge.
+    /// The prediction is never negative.
+    pub fn predict(&self, rps: f64) -> f64 {
+        let (Some(first), Some(last)) = (self.points.first(), self.points.last()) else {
+            return self.average;
+        };
+        let prediction = if (first.rps..=last.rps).contains(&rps) {
+            self.polynomial.evaluate(rps)
+        } else {
+            self.linear.evaluate(rps)
+        };
+        prediction.max(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_averages_and_curves() {
+        let utilization: Utilization = serde_yaml::from_str(
+            r#"
+CPU:
+  average: 20.0
+  points:
+    - rps: 10
+      utilization: 10.0
+    - rps: 30
+      utilization: 30.0
+  linear: [0.0, 1.0]
+  polynomial: [0.0, 1.0]
+MEMORY: 42.0
+"#,
+        )
+        .unwrap();
+        assert_eq!(utilization.averages[&Label::Cpu], 20.0);
+        assert_eq!(utilization.averages[&Label::Memory], 42.0);
+        assert_eq!(utilization.curves.len(), 1);
+        assert_eq!(utilization.curves[&Label::Cpu].predict(20.0), 20.0);
+    }
+
+    #[test]
+    fn test_deserialize_unprofiled() {
+        let utilization: Utilization = serde_yaml::from_str("unprofiled: true").unwrap();
+        assert!(utilization.unprofiled);
+        assert!(utilization.averages.is_empty());
+        assert!(serde_yaml::from_str::<Utilization>("CPUS: 1.0").is_err());
+    }
+
+    #[test]
+    fn test_deserialize_knobs() {
+        let utilization: Utilization = serde_yaml::from_str(
+            r#"
+CPU: 15.0
+knobs:
+  - setting: [{ arg: 0, value: 10 }]
+    utilization: { CPU: 10.0 }
+  - setting: [{ arg: 0, value: 20 }]
+    utilization: { CPU: 20.0 }
+"#,
+        )
+        .unwrap();
+        assert_eq!(utilization.knobs.len(), 2);
+        assert_eq!(
+            utilization.knobs[1]
+                .setting
+                .get(&crate::handler::PassingType::Pos(0)),
+            Some(20.0)
+        );
+        assert_eq!(utilization.knobs[1].utilization.averages[&Label::Cpu], 20.0);
+    }
+
+    #[test]
+    fn test_predict_extrapolates_linearly() {
+        let curve = UtilizationCurve::new(
+            [(100.0, 4.0), (50.0, 2.0), (150.0, 7.0)]
+                .into_iter()
+                .map(|(rps, utilization)| LoadLevel { rps, utilization })
+                .collect(),
+        );
+        assert_eq!(curve.points[0].rps, 50.0);
+        assert!((curve.average - 13.0 / 3.0).abs() < 1e-9);
+        assert!((curve.predict(100.0) - 4.0).abs() < 1e-9);
+        assert!((curve.predict(200.0) - curve.linear.evaluate(200.0)).abs() < 1e-9);
+        assert_eq!(curve.predict(-1000.0), 0.0);
+    }
+}
@@ -4,6 +4,8 @@ use ndarray::Array1;
 use std::collections::{BTreeMap, HashMap};
 use tokio::io::AsyncBufReadExt;
 
+use crate::handler::{KnobSetting, KNOBS_DIR};
+use crate::service_types::{LoadLevel, UtilizationCurve, KNOBS_KEY};
 use crate::stats::levene::levene_test;
 
 use super::{path_to_str, BenchmarkConfig, Result};
@@ -20,6 +22,11 @@ pub async fn aggregate(
         if !path.is_dir() {
             continue;
         }
+        let knobs = path.join(KNOBS_DIR);
+        if knobs.is_dir() {
+            aggregate_knobs(benchmark_config, &path, &knobs).await?;
+            continue;
+        }
         let benchmarks = path.join("benchmarks");
         if !benchmarks.is_dir() {
             log::warn!(
@@ -28,22 +35,7 @@ pub async fn aggregate(
             );
             continue;
         }
-        let mut load_level_it = tokio::fs::read_dir(benchmarks).await?;
-        let mut load_level_results: HashMap<_, Vec<f64>> = HashMap::new();
-        while let Some(load_level) = load_level_it.next_entry().await? {
-            let result =
-                test_load_level(&load_level.path(), benchmark_config.benchmark_duration).await?;
-            for (key, value) in result {
-                let entry = load_level_results.entry(key).or_default();
-                entry.push(value);
-            }
-        }
-        let utilization: BTreeMap<_, f64> =
-            BTreeMap::from_iter(load_level_results.iter().map(|(key, values)| {
-                let n = values.len();
-                let avg = values.iter().sum::<f64>() / (n as f64);
-                (*key, avg)
-            }));
+        let utilization = aggregate_benchmarks(benchmark_config, &benchmarks).await?;
         let utilization_file = std::fs::File::create(path.join("utilization.yml"))?;
         serde_yaml::to_writer(&utilization_file, &utilization)?;
     }
@@ -51,6 +43,119 @@ pub async fn aggregate(
     Ok(())
 }
 
+/// [`KnobAggregate`] is the aggregated utilization of a single knob setting.
+#[derive(serde::Serialize)]
+struct KnobAggregate {
+    setting: KnobSetting,
+    utilization: BTreeMap<&'static str, UtilizationCurve>,
+}
+
+/// Aggregates the benchmarks of each knob setting of the handler function, i.e.,
+/// `knobs/<index>/benchmarks`, where the index refers to the knob settings of its definition.
+///
+/// The labels of the utilization file are averaged over all knob settings, such that the handler
+/// function can also be selected, if the knob settings are not considered.
+async fn aggregate_knobs(
+    benchmark_config: &BenchmarkConfig,
+    handler_dir: &std::path::Path,
+    knobs_dir: &std::path::Path,
+) -> Result<()> {
+    let settings = crate::io::parse_handler_function(handler_dir)?.knob_settings();
+
+    let mut knobs = Vec::new();
+    let mut knob_it = tokio::fs::read_dir(knobs_dir).await?;
+    while let Some(knob_dir) = knob_it.next_entry().await? {
+        let knob_path = knob_dir.path();
+        let setting = knob_path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.parse::<usize>().ok())
+            .and_then(|idx| settings.get(idx).map(|setting| (idx, setting)));
+        let benchmarks = knob_path.join("benchmarks");
+        let Some((idx, setting)) = setting.filter(|_| benchmarks.is_dir()) else {
+            log::warn!(
+                "Unexpected knob setting directory `{}`! Skipping...",
+                knob_path.display()
+            );
+            continue;
+        };
+        let utilization = aggregate_benchmarks(benchmark_config, &benchmarks).await?;
+        knobs.push((
+            idx,
+            KnobAggregate {
+                setting: setting.clone(),
+                utilization,
+            },
+        ));
+    }
+    if knobs.is_empty() {
+        log::warn!(
+            "Missing knob setting benchmarks for path `{}`! Skipping...",
+            handler_dir.display()
+        );
+        return Ok(());
+    }
+    knobs.sort_by_key(|(idx, _)| *idx);
+
+    let mut averages: BTreeMap<&'static str, Vec<f64>> = BTreeMap::new();
+    for (_, knob) in &knobs {
+        for (key, curve) in &knob.utilization {
+            averages.entry(key).or_default().push(curve.average);
+        }
+    }
+    let mut utilization = serde_yaml::Mapping::new();
+    for (key, values) in averages {
+        let average = values.iter().sum::<f64>() / values.len() as f64;
+        utilization.insert(key.into(), average.into());
+    }
+    let knobs: Vec<_> = knobs.into_iter().map(|(_, knob)| knob).collect();
+    utilization.insert(KNOBS_KEY.into(), serde_yaml::to_value(knobs)?);
+
+    let utilization_file = std::fs::File::create(handler_dir.join("utilization.yml"))?;
+    serde_yaml::to_writer(&utilization_file, &utilization)?;
+    Ok(())
+}
+
+/// Aggregates the load levels of a benchmarks directory, i.e., `benchmarks/rps-<request rate>`,
+/// into a utilization curve per metric.
+async fn aggregate_benchmarks(
+    benchmark_config: &BenchmarkConfig,
+    benchmarks: &std::path::Path,
+) -> Result<BTreeMap<&'static str, UtilizationCurve>> {
+    let mut load_level_it = tokio::fs::read_dir(benchmarks).await?;
+    let mut load_level_results: HashMap<_, Vec<LoadLevel>> = HashMap::new();
+    while let Some(load_level) = load_level_it.next_entry().await? {
+        let load_level_path = load_level.path();
+        let Some(rps) = parse_request_rate(&load_level_path) else {
+            log::warn!(
+                "Unexpected load level directory `{}`! Skipping...",
+                load_level_path.display()
+            );
+            continue;
+        };
+        let result = test_load_level(&load_level_path, benchmark_config.benchmark_duration).await?;
+        for (key, utilization) in result {
+            let entry = load_level_results.entry(key).or_default();
+            entry.push(LoadLevel { rps, utilization });
+        }
+    }
+    Ok(BTreeMap::from_iter(
+        load_level_results
+            .into_iter()
+            .map(|(key, points)| (key, UtilizationCurve::new(points))),
+    ))
+}
+
+/// Parses the request rate of a load level from its directory name, i.e., `rps-<request rate>`.
+fn parse_request_rate(load_level: &std::path::Path) -> Option<f64> {
+    load_level
+        .file_name()?
+        .to_str()?
+        .strip_prefix("rps-")?
+        .parse()
+        .ok()
+}
+
 #[derive(serde::Deserialize, Debug)]
 struct PrometheusAPIResponse {
     pub status: String,
@@ -163,6 +268,39 @@ lazy_static::lazy_static! {
     ];
 }
 
+/// The key of the response time measured by the load generator.
+const LATENCY_KEY: &str = "LATENCY";
+
+/// Reads the average response time in milliseconds of every second, in which at least one request
+/// succeeded, from the summary of the load generator.
+fn read_response_times(summary: &std::path::Path) -> Result<Array1<f64>> {
+    const SUCCESSFUL_TRANSACTIONS_COLUMN: usize = 2;
+    const AVG_RESPONSE_TIME_COLUMN: usize = 5;
+
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .from_path(summary)?;
+    let mut response_times = Vec::new();
+    for record in reader.records() {
+        let record = record?;
+        let parse = |column| {
+            record
+                .get(column)
+                .and_then(|value: &str| value.trim().parse::<f64>().ok())
+        };
+        if let (Some(successful), Some(response_time)) = (
+            parse(SUCCESSFUL_TRANSACTIONS_COLUMN),
+            parse(AVG_RESPONSE_TIME_COLUMN),
+        ) {
+            if successful > 0.0 {
+                // The load generator reports seconds
+                response_times.push(response_time * 1e3);
+            }
+        }
+    }
+    Ok(Array1::from(response_times))
+}
+
 pub async fn test_load_level(
     dir: impl AsRef<std::path::Path>,
     duration: usize,
@@ -283,6 +421,10 @@ pub async fn test_load_level(
             let vec = results.entry(metric.as_key()).or_default();
             vec.push(Array1::from_iter(values.values().copied()));
         }
+        let response_times = read_response_times(&path.join("summary_out.csv"))?;
+        if !response_times.is_empty() {
+            results.entry(LATENCY_KEY).or_default().push(response_times);
+        }
         let mut docker = std::process::Command::new("docker");
         let encoder = local_encoding::posix::EncoderUtf8 {};
         let cid = encoder.to_string(cid.stdout.as_slice()).unwrap();
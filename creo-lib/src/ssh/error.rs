@@ -16,6 +16,8 @@ pub enum Error {
     InvalidArgument(String),
     #[error("failed to render script template: {0}")]
     RenderScript(#[from] handlebars::RenderError),
+    #[error("failed to read load generator results: {0}")]
+    LoadGeneratorResults(#[from] csv::Error),
     #[error("unstable benchmarks: {0}")]
     UnstableBenchmark(String),
 }
@@ -4,6 +4,8 @@ use futures::{
 };
 use tokio::io::AsyncWriteExt;
 
+use crate::handler::{KNOBS_DIR, KNOB_SETTING_SEPARATOR};
+
 use super::{establish_connections, path_to_str, Client, Config, Error, Result};
 
 pub async fn pull(
@@ -41,12 +43,16 @@ pub async fn pull(
 
                         let benchmark_path = service_path
                             .join("benchmarks");
-                        let handler_dir = lang_dir
-                            .join(
-                                dir.file_name()
-                                    .strip_prefix("handler-")
-                                    .expect("handler- prefix"),
-                            );
+                        let file_name = dir.file_name();
+                        let service_name = file_name
+                            .strip_prefix("handler-")
+                            .expect("handler- prefix");
+                        // The results of each knob setting are kept in a separate directory
+                        let handler_dir = match service_name.split_once(KNOB_SETTING_SEPARATOR) {
+                            Some((handler_name, setting)) => lang_dir.join(handler_name).join(KNOBS_DIR).join(setting),
+                            None => lang_dir.join(service_name),
+                        };
+                        tokio::fs::create_dir_all(&handler_dir).await?;
                         let local_benchmark_path = handler_dir
                             .join("benchmarks");
                         if local_benchmark_path.is_dir() {
@@ -1 +1,2 @@
 pub mod levene;
+pub mod regression;
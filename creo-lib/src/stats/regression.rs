@@ -0,0 +1,92 @@
+/// [`Polynomial`] is a polynomial with the given coefficients in ascending order of degree, i.e.,
+/// the first coefficient is the intercept.
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct Polynomial(pub Vec<f64>);
+
+impl Polynomial {
+    /// Fits a polynomial of (at most) the given degree to the points by the method of least
+    /// squares.
+    ///
+    /// The degree is reduced to one less than the number of distinct x-values, such that the
+    /// system of normal equations is always solvable. No points result in the zero polynomial.
+    pub fn fit(points: &[(f64, f64)], degree: usize) -> Self {
+        let mut xs: Vec<f64> = points.iter().map(|(x, _)| *x).collect();
+        xs.sort_by(f64::total_cmp);
+        xs.dedup();
+        if xs.is_empty() {
+            return Self(vec![0.0]);
+        }
+        let n = degree.min(xs.len() - 1) + 1;
+
+        // Normal equations (X^T X) c = X^T y as augmented matrix
+        let mut matrix = vec![vec![0.0; n + 1]; n];
+        for (x, y) in points {
+            let powers: Vec<f64> = (0..n).map(|k| x.powi(k as i32)).collect();
+            for (row, p_row) in matrix.iter_mut().zip(&powers) {
+                for (cell, p_col) in row.iter_mut().zip(&powers) {
+                    *cell += p_row * p_col;
+                }
+                row[n] += p_row * y;
+            }
+        }
+
+        // Gaussian elimination with partial pivoting
+        for col in 0..n {
+            let pivot = (col..n)
+                .max_by(|a, b| matrix[*a][col].abs().total_cmp(&matrix[*b][col].abs()))
+                .expect("non empty pivot range");
+            matrix.swap(col, pivot);
+            let (upper, lower) = matrix.split_at_mut(col + 1);
+            let pivot_row = &upper[col];
+            for row in lower {
+                let factor = row[col] / pivot_row[col];
+                for (cell, pivot_cell) in row.iter_mut().zip(pivot_row).skip(col) {
+                    *cell -= factor * pivot_cell;
+                }
+            }
+        }
+        let mut coefficients = vec![0.0; n];
+        for row in (0..n).rev() {
+            let sum: f64 = ((row + 1)..n)
+                .map(|k| matrix[row][k] * coefficients[k])
+                .sum();
+            coefficients[row] = (matrix[row][n] - sum) / matrix[row][row];
+        }
+
+        Self(coefficients)
+    }
+
+    /// Evaluates the polynomial at `x`.
+    pub fn evaluate(&self, x: f64) -> f64 {
+        self.0.iter().rev().fold(0.0, |acc, c| acc * x + c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_exact_polynomials() {
+        let points: Vec<_> = [0.0, 1.0, 2.0, 3.0]
+            .into_iter()
+            .map(|x| (x, 1.0 + 2.0 * x + 0.5 * x * x))
+            .collect();
+        let quadratic = Polynomial::fit(&points, 2);
+        for (actual, expected) in quadratic.0.iter().zip([1.0, 2.0, 0.5]) {
+            assert!((actual - expected).abs() < 1e-9, "{:?}", quadratic);
+        }
+        assert!((quadratic.evaluate(4.0) - 17.0).abs() < 1e-9);
+
+        let linear = Polynomial::fit(&[(10.0, 5.0), (20.0, 7.0), (30.0, 9.0)], 1);
+        assert!((linear.evaluate(40.0) - 11.0).abs() < 1e-9, "{:?}", linear);
+    }
+
+    #[test]
+    fn test_fit_reduces_degree() {
+        let constant = Polynomial::fit(&[(50.0, 3.0), (50.0, 5.0)], 2);
+        assert_eq!(constant, Polynomial(vec![4.0]));
+        assert_eq!(Polynomial::fit(&[], 2), Polynomial(vec![0.0]));
+    }
+}
@@ -1,3 +1,10 @@
+/// The maximum length of fake strings without a `maxLength`.
+pub const DEFAULT_MAX_STRING_LENGTH: usize = 20;
+/// The maximum of fake numbers without a `maximum`.
+pub const DEFAULT_MAX_NUMBER: f64 = 5000.0;
+/// The maximum of fake integers without a `maximum`.
+pub const DEFAULT_MAX_INTEGER: i64 = 9999;
+
 #[derive(serde::Serialize, Debug)]
 pub struct FakeFunction {
     name: String,
@@ -8,4 +8,8 @@ pub struct ServiceInfo {
     pub version: String,
     /// The openapi contact information for the microservice.
     pub contact: openapiv3::Contact,
+    /// Flag indicating whether the microservice injects faults.
+    pub fault_injection: bool,
+    /// Flag indicating whether the microservice emits OpenTelemetry spans.
+    pub tracing: bool,
 }
@@ -11,7 +11,9 @@ mod writer;
 
 pub use dependencies::DependencyData;
 pub use error::{Error, Result};
-pub use faker::{FakeFunction, Fakeable};
+pub use faker::{
+    FakeFunction, Fakeable, DEFAULT_MAX_INTEGER, DEFAULT_MAX_NUMBER, DEFAULT_MAX_STRING_LENGTH,
+};
 pub use import::Import;
 pub use info::ServiceInfo;
 pub(crate) use main_file::{AuxiliryTemplate, MainGenerator, MainTemplate};
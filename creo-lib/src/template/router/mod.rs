@@ -22,6 +22,7 @@ pub(crate) fn create_router_file_data(
     let mut http_get_operations: Vec<models::HTTPGetOperation> = Vec::default();
     let mut http_post_operations: Vec<models::HTTPPostOperation> = Vec::default();
     let mut type_imports: IndexSet<Import> = IndexSet::default();
+    let mut has_header_params = false;
 
     for endpoint in graph.iter_service_endpoints(service) {
         let handler_func = registry.get_function(endpoint.id);
@@ -31,37 +32,58 @@ pub(crate) fn create_router_file_data(
                 &handler_func.signature.function,
             ),
         });
+        let handler_func_name = symbol_generator.generate_handler_function_reference(
+            &handler_func.import_path,
+            &handler_func.signature.function,
+        );
         let name = symbol_generator.generate_operation_function_name(endpoint.id);
         let path = graph.get_endpoint_path(endpoint.id);
         let n_args = handler_func.signature.parameters.len();
         let mut query_params: Vec<models::QueryParameter> = Vec::with_capacity(n_args);
+        let mut path_params: Vec<models::QueryParameter> = Vec::default();
+        let mut header_params: Vec<models::QueryParameter> = Vec::default();
         let mut handler_args: Vec<models::Argument> = Vec::with_capacity(n_args);
         let mut body_param_name: Option<String> = None;
+        let knob_setting = graph.get_knob_setting(endpoint.id);
         for param in &handler_func.signature.parameters {
+            let constant = knob_setting
+                .and_then(|setting| setting.get(&param.arg))
+                .map(|value| to_literal(param, value));
+            let is_constant = constant.is_some();
             handler_args.push(models::Argument {
                 name: param.as_name(),
                 is_pos_arg: match param.arg {
                     handler::PassingType::Kw(_) => false,
                     handler::PassingType::Pos(_) => true,
                 },
-                is_body_arg: !param.is_primitive_type(),
+                location: param.location(),
+                constant,
             });
+            if is_constant {
+                continue;
+            }
 
-            if param.schema.get_object_schema().is_some()
-                || param.schema.get_array_schema_type().is_some()
-            {
-                body_param_name = Some(param.as_name())
-            } else {
-                let param_type = generator::core::to_data_type(
-                    &param.schema.schema_kind,
-                    &mut type_imports,
-                    data_type_mapper,
-                );
-                query_params.push(models::QueryParameter::new(
-                    param.as_name(),
-                    param_type,
-                    &param.schema,
-                ));
+            let location = param.location();
+            if location == handler::ParamLocation::Body {
+                body_param_name = Some(param.as_name());
+                continue;
+            }
+            let param_type = generator::core::to_data_type(
+                &param.schema.schema_kind,
+                &mut type_imports,
+                data_type_mapper,
+            );
+            let request_param = models::QueryParameter::new(
+                param.as_name(),
+                location,
+                param.as_header_name(),
+                param_type,
+                &param.schema,
+            );
+            match location {
+                handler::ParamLocation::Path => path_params.push(request_param),
+                handler::ParamLocation::Header => header_params.push(request_param),
+                _ => query_params.push(request_param),
             }
         }
 
@@ -71,6 +93,8 @@ pub(crate) fn create_router_file_data(
         } else {
             String::new()
         };
+        let forwards_result = has_service_calls
+            && super::service_calls::forwards_result(endpoint.id, graph, registry);
         if has_service_calls {
             service_call_imports.insert(Import {
                 import: symbol_generator.generate_service_call_function_import(
@@ -79,20 +103,26 @@ pub(crate) fn create_router_file_data(
                 ),
             });
         }
+        has_header_params |= !header_params.is_empty();
+        let method = handler_func.get_http_method().as_str().to_lowercase();
         if let Some(body_param_name) = body_param_name {
             http_post_operations.push(models::HTTPPostOperation {
                 name,
                 description: handler_func.description.clone(),
                 is_async: handler_func.is_async,
                 endpoint_index: endpoint.id.0,
+                method,
                 path,
+                path_params,
+                header_params,
                 handler_args,
-                handler_func_name: handler_func.signature.function.clone(),
+                handler_func_name,
                 body_param_name,
                 query_params,
                 has_service_calls,
                 service_call_function_name,
                 has_return_type: handler_func.returns,
+                forwards_result,
             });
         } else {
             http_get_operations.push(models::HTTPGetOperation {
@@ -100,13 +130,17 @@ pub(crate) fn create_router_file_data(
                 description: handler_func.description.clone(),
                 is_async: handler_func.is_async,
                 endpoint_index: endpoint.id.0,
+                method,
                 path,
+                path_params,
+                header_params,
                 handler_args,
-                handler_func_name: handler_func.signature.function.clone(),
+                handler_func_name,
                 query_params,
                 has_service_calls,
                 service_call_function_name,
                 has_return_type: handler_func.returns,
+                forwards_result,
             });
         }
     }
@@ -117,6 +151,17 @@ pub(crate) fn create_router_file_data(
         type_imports,
         http_get_operations,
         http_post_operations,
+        has_header_params,
+    }
+}
+
+/// Returns the literal of the knob value passed to the given parameter.
+fn to_literal(param: &handler::Param, value: f64) -> String {
+    match &param.schema.schema_kind {
+        crate::schema::SchemaKind::Type(crate::schema::Type::Integer(_)) => {
+            format!("{}", value.round() as i64)
+        }
+        _ => format!("{:?}", value),
     }
 }
 
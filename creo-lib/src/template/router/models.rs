@@ -1,6 +1,6 @@
 use indexmap::IndexSet;
 
-use crate::{schema, template::import::Import};
+use crate::{handler::ParamLocation, schema, template::import::Import};
 
 #[derive(serde::Serialize, Debug)]
 /// [`RouterFileData`] contains the data passed to the router file template.
@@ -11,14 +11,17 @@ pub struct RouterFileData {
     pub service_call_imports: IndexSet<Import>,
     /// contains the import statements for the handler function of each endpoint.
     pub handler_func_imports: IndexSet<Import>,
-    /// contains all HTTP GET operations.
+    /// contains all operations without a request body, e.g., HTTP GET operations.
     pub http_get_operations: Vec<HTTPGetOperation>,
-    /// contains all HTTP POST operations.
+    /// contains all operations with a request body, e.g., HTTP POST operations.
     pub http_post_operations: Vec<HTTPPostOperation>,
+    /// the flag indicating whether any operation expects a header parameter.
+    pub has_header_params: bool,
 }
 
 #[derive(serde::Serialize, Debug)]
-/// [`HTTPPostOperation`] contains the information for a HTTP POST operation.
+/// [`HTTPPostOperation`] contains the information for an operation with a request body, e.g., a
+/// HTTP POST operation.
 pub struct HTTPPostOperation {
     /// the function name of the operation.
     pub name: String,
@@ -28,8 +31,14 @@ pub struct HTTPPostOperation {
     pub is_async: bool,
     /// the unique endpoint index
     pub endpoint_index: usize,
-    /// the path string of the operation.
+    /// the lowercase HTTP method of the operation, e.g. `put`.
+    pub method: String,
+    /// the path string of the operation without its path parameters.
     pub path: String,
+    /// the list of path parameters, which are appended to the path in the given order.
+    pub path_params: Vec<QueryParameter>,
+    /// the list of header parameters.
+    pub header_params: Vec<QueryParameter>,
     /// the list of arguments to pass to the handler function.
     pub handler_args: Vec<Argument>,
     /// the name of the handler function, that is called by this operation.
@@ -46,9 +55,14 @@ pub struct HTTPPostOperation {
     pub service_call_function_name: String,
     /// the flag indicating whether the handler function of this operation returns a value or not.
     pub has_return_type: bool,
+    /// the flag indicating whether the result of the handler function is forwarded to the service
+    /// calls, which are then issued after the handler function returned.
+    pub forwards_result: bool,
 }
 
 #[derive(serde::Serialize, Debug)]
+/// [`HTTPGetOperation`] contains the information for an operation without a request body, e.g., a
+/// HTTP GET operation.
 pub struct HTTPGetOperation {
     /// the function name of the operation.
     pub name: String,
@@ -58,8 +72,14 @@ pub struct HTTPGetOperation {
     pub is_async: bool,
     /// the unique endpoint index
     pub endpoint_index: usize,
-    /// the path string of the operation.
+    /// the lowercase HTTP method of the operation, e.g. `put`.
+    pub method: String,
+    /// the path string of the operation without its path parameters.
     pub path: String,
+    /// the list of path parameters, which are appended to the path in the given order.
+    pub path_params: Vec<QueryParameter>,
+    /// the list of header parameters.
+    pub header_params: Vec<QueryParameter>,
     /// the list of arguments to pass to the handler function.
     pub handler_args: Vec<Argument>,
     /// the name of the handler function, that is called by this operation.
@@ -74,6 +94,9 @@ pub struct HTTPGetOperation {
     pub service_call_function_name: String,
     /// the flag indicating whether the handler function of this operation returns a value or not.
     pub has_return_type: bool,
+    /// the flag indicating whether the result of the handler function is forwarded to the service
+    /// calls, which are then issued after the handler function returned.
+    pub forwards_result: bool,
 }
 
 #[derive(serde::Serialize, Debug)]
@@ -83,15 +106,21 @@ pub struct Argument {
     pub name: String,
     /// the flag indicating whether this argument is a positional or key-word argument.
     pub is_pos_arg: bool,
-    /// the flag indicating whether this argument is included in the body
-    pub is_body_arg: bool,
+    /// the location of the argument in the request.
+    pub location: ParamLocation,
+    /// the literal passed to the handler function, if the argument is set by a knob.
+    pub constant: Option<String>,
 }
 
 #[derive(serde::Serialize, Debug)]
-/// [`QueryParameter`] represents a single query parameter.
+/// [`QueryParameter`] represents a single primitive parameter in the query, the path, or a header.
 pub struct QueryParameter {
     /// the name (or key) of the query parameter.
     name: String,
+    /// the location of the parameter in the request.
+    location: ParamLocation,
+    /// the name of the HTTP header, if the parameter is a header parameter.
+    header_name: String,
     /// the primitive data type of the query parameter.
     param_type: String,
     /// the description of the query parameter.
@@ -105,15 +134,27 @@ pub struct QueryParameter {
 }
 
 impl QueryParameter {
-    pub fn new(name: String, param_type: String, schema: &schema::Schema) -> Self {
+    pub fn new(
+        name: String,
+        location: ParamLocation,
+        header_name: String,
+        param_type: String,
+        schema: &schema::Schema,
+    ) -> Self {
         let description = if let Some(description) = schema.schema_data.description.as_ref() {
             description.clone()
         } else {
-            format!("Query Parameter {}", name.clone())
+            match location {
+                ParamLocation::Path => format!("Path Parameter {}", name),
+                ParamLocation::Header => format!("Header Parameter {}", name),
+                _ => format!("Query Parameter {}", name),
+            }
         };
 
         Self {
             name,
+            location,
+            header_name,
             param_type,
             description,
             string_validation: match &schema.schema_kind {
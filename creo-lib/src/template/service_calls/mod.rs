@@ -1,11 +1,10 @@
 use std::collections::HashSet;
 
 use crate::{
+    application::{CallMode, FailurePolicy},
     generator::core::SymbolGenerator,
-    graph::{ApplicationGraph, MicroServiceIndex},
-    handler,
-    http_method::HTTPMethod,
-    schema,
+    graph::{ApplicationGraph, EndpointIndex, MicroServiceIndex},
+    handler, schema,
 };
 
 use super::faker::{FakeFunction, Fakeable};
@@ -75,21 +74,66 @@ fn create_array_fake_function(
 fn create_query_data_function(
     name: String,
     parameters: &[handler::Param],
+    caller: &handler::Function,
     faker: &dyn Fakeable,
 ) -> models::QueryDataFunction {
     let mut params: Vec<models::QueryParamFakeFunction> = Vec::default();
     for param in parameters {
         let param_name = param.as_name();
         let fake_func = to_fake_function("", &param.schema.schema_kind, faker);
+        let forward = caller.find_forward(&param.schema);
         params.push(models::QueryParamFakeFunction {
             name: param_name,
             fake_func,
             nullable: param.schema.schema_data.nullable,
             exclude_probability: crate::constants::DEFAULT_EXCLUDE_PROBABILITY,
+            forwarded: forward.is_some(),
+            forwarded_property: forward
+                .as_ref()
+                .and_then(|forward| forward.property())
+                .map(str::to_string),
         });
     }
 
-    models::QueryDataFunction { name, params }
+    let forwards_result = params.iter().any(|param| param.forwarded);
+    models::QueryDataFunction {
+        name,
+        params,
+        forwards_result,
+    }
+}
+
+/// Returns the parameters of the target handler function, which are sent with a service call, i.e.,
+/// all primitive parameters as query parameters and the first structured parameter as body.
+fn iter_sent_parameters(parameters: &[handler::Param]) -> impl Iterator<Item = &handler::Param> {
+    let body = parameters.iter().find(|param| !param.is_primitive_type());
+    parameters
+        .iter()
+        .filter(|param| param.is_primitive_type())
+        .chain(body)
+}
+
+/// Returns whether the endpoint forwards the result of its handler function to any of its service
+/// calls. In this case, the service calls are issued after the handler function returned.
+pub(crate) fn forwards_result(
+    endpoint: EndpointIndex,
+    graph: &ApplicationGraph,
+    registry: &handler::FunctionRegistry,
+) -> bool {
+    let caller = registry.get_function(endpoint);
+    if caller.signature.returns.is_none() {
+        return false;
+    }
+    graph.iter_service_calls(endpoint).any(|call| {
+        let parameters: Vec<handler::Param> = graph
+            .get_request_parameters(call.target, registry.get_function(call.target))
+            .into_iter()
+            .cloned()
+            .collect();
+        let forwards = iter_sent_parameters(&parameters)
+            .any(|param| caller.find_forward(&param.schema).is_some());
+        forwards
+    })
 }
 
 fn create_object_fake_function(
@@ -153,130 +197,187 @@ pub(crate) fn create_service_call_file_data(
     let mut array_fake_functions: Vec<models::ArrayFakeFunction> = Vec::default();
     let mut query_data_functions: Vec<models::QueryDataFunction> = Vec::default();
     let mut service_call_functions: Vec<models::ServiceCallFunction> = Vec::default();
+    let mut has_path_params = false;
+    let mut has_header_params = false;
 
     for endpoint in graph.iter_service_endpoints(service) {
+        let caller = registry.get_function(endpoint.id);
         let function_name = symbol_generator.generate_service_calls_function_name(endpoint.id);
         let mut service_call_function = models::ServiceCallFunction::new(function_name.clone());
         let mut did_iter = false;
-        for call in graph.iter_service_calls(endpoint.id) {
+        // The successors of an endpoint are yielded in reverse order of their definition, but
+        // sequential calls must be issued in the order of their definition.
+        let calls: Vec<_> = graph.iter_service_calls(endpoint.id).collect();
+        for call in calls.into_iter().rev() {
             did_iter = true;
+            let defined_calls = service_call_function.get_service_calls.len()
+                + service_call_function.post_service_calls.len();
             let handler_func = registry.get_function(call.target);
+            let parameters: Vec<handler::Param> = graph
+                .get_request_parameters(call.target, handler_func)
+                .into_iter()
+                .cloned()
+                .collect();
             let path = graph.get_endpoint_path(call.target);
             let host_env_var = graph.get_host_env_var(graph.get_service(call.target));
-            let method = handler_func.get_http_method();
+            let method = handler_func.get_http_method().to_string();
+            let path_params: Vec<String> = parameters
+                .iter()
+                .filter(|param| param.location() == handler::ParamLocation::Path)
+                .map(handler::Param::as_name)
+                .collect();
+            let header_params: Vec<models::HeaderParam> = parameters
+                .iter()
+                .filter(|param| param.location() == handler::ParamLocation::Header)
+                .map(|param| models::HeaderParam {
+                    name: param.as_name(),
+                    header_name: param.as_header_name(),
+                })
+                .collect();
+            has_path_params |= !path_params.is_empty();
+            has_header_params |= !header_params.is_empty();
             let function_name =
                 symbol_generator.generate_individual_service_call_function_name(call);
 
-            match method {
-                HTTPMethod::Get => {
-                    if handler_func.signature.parameters.is_empty() {
-                        service_call_function
-                            .get_service_calls
-                            .push(models::GetServiceCall {
-                                name: function_name,
-                                requires_data: false,
-                                query_data_func: String::new(),
-                                path,
-                                host_env_var,
-                            })
-                    } else {
-                        let query_func_name =
-                            symbol_generator.generate_query_data_function_name(call);
-                        let query_func = create_query_data_function(
-                            query_func_name.clone(),
-                            &handler_func.signature.parameters,
-                            faker,
-                        );
+            if parameters.iter().all(handler::Param::is_primitive_type) {
+                if parameters.is_empty() {
+                    service_call_function
+                        .get_service_calls
+                        .push(models::GetServiceCall {
+                            name: function_name,
+                            requires_data: false,
+                            query_data_func: String::new(),
+                            forwards_result: false,
+                            method,
+                            path,
+                            path_params,
+                            header_params,
+                            host_env_var,
+                        })
+                } else {
+                    let query_func_name = symbol_generator.generate_query_data_function_name(call);
+                    let query_func = create_query_data_function(
+                        query_func_name.clone(),
+                        &parameters,
+                        caller,
+                        faker,
+                    );
 
-                        query_data_functions.push(query_func);
-                        service_call_function
-                            .get_service_calls
-                            .push(models::GetServiceCall {
-                                name: function_name,
-                                requires_data: true,
-                                query_data_func: query_func_name,
-                                path,
-                                host_env_var,
-                            });
-                    }
+                    service_call_function
+                        .get_service_calls
+                        .push(models::GetServiceCall {
+                            name: function_name,
+                            requires_data: true,
+                            query_data_func: query_func_name,
+                            forwards_result: query_func.forwards_result,
+                            method,
+                            path,
+                            path_params,
+                            header_params,
+                            host_env_var,
+                        });
+                    query_data_functions.push(query_func);
                 }
-                HTTPMethod::Post => {
-                    let query_func_name = symbol_generator.generate_query_data_function_name(call);
-                    let primitive_params: Vec<handler::Param> = handler_func
-                        .signature
-                        .parameters
-                        .iter()
-                        .filter(|p| p.is_primitive_type())
-                        .cloned()
-                        .collect();
-                    let query_func =
-                        create_query_data_function(query_func_name, &primitive_params, faker);
-                    for param in &handler_func.signature.parameters {
-                        if let Some(object_type) = param.schema.get_object_schema() {
-                            let unique_object_func_name = symbol_generator
-                                .generate_parameter_function_name(call, &param.as_name());
+            } else {
+                let query_func_name = symbol_generator.generate_query_data_function_name(call);
+                let primitive_params: Vec<handler::Param> = parameters
+                    .iter()
+                    .filter(|p| p.is_primitive_type())
+                    .cloned()
+                    .collect();
+                let query_func =
+                    create_query_data_function(query_func_name, &primitive_params, caller, faker);
+                for param in &parameters {
+                    if param.is_primitive_type() {
+                        continue;
+                    }
+                    let forward = caller.find_forward(&param.schema);
+                    let body_data_func = if forward.is_some() {
+                        String::new()
+                    } else if let Some(object_type) = param.schema.get_object_schema() {
+                        let unique_object_func_name = symbol_generator
+                            .generate_parameter_function_name(call, &param.as_name());
 
-                            let body_func = create_object_fake_function(
-                                unique_object_func_name,
-                                object_type,
-                                &mut object_fake_functions,
-                                &mut array_fake_functions,
-                                symbol_generator,
-                                faker,
-                            );
-                            service_call_function.post_service_calls.push(
-                                models::PostServiceCall {
-                                    name: function_name,
-                                    requires_query_data: !query_func.params.is_empty(),
-                                    query_data_func: {
-                                        if !query_func.params.is_empty() {
-                                            query_func.name.clone()
-                                        } else {
-                                            String::new()
-                                        }
-                                    },
-                                    body_data_func: body_func.name.clone(),
-                                    path,
-                                    host_env_var,
-                                },
-                            );
-                            object_fake_functions.push(body_func);
-                            break;
-                        }
+                        let body_func = create_object_fake_function(
+                            unique_object_func_name,
+                            object_type,
+                            &mut object_fake_functions,
+                            &mut array_fake_functions,
+                            symbol_generator,
+                            faker,
+                        );
+                        let body_data_func = body_func.name.clone();
+                        object_fake_functions.push(body_func);
+                        body_data_func
+                    } else if let Some(array_type) = param.schema.get_array_schema_type() {
+                        let unique_array_func_name = symbol_generator
+                            .generate_parameter_function_name(call, &param.as_name());
 
-                        if let Some(array_type) = param.schema.get_array_schema_type() {
-                            let unique_array_func_name = symbol_generator
-                                .generate_parameter_function_name(call, &param.as_name());
+                        let body_func = create_array_fake_function(
+                            unique_array_func_name,
+                            array_type,
+                            &mut object_fake_functions,
+                            &mut array_fake_functions,
+                            symbol_generator,
+                            faker,
+                        );
+                        let body_data_func = body_func.name.clone();
+                        array_fake_functions.push(body_func);
+                        body_data_func
+                    } else {
+                        continue;
+                    };
+                    service_call_function
+                        .post_service_calls
+                        .push(models::PostServiceCall {
+                            name: function_name,
+                            requires_query_data: !query_func.params.is_empty(),
+                            query_data_func: {
+                                if !query_func.params.is_empty() {
+                                    query_func.name.clone()
+                                } else {
+                                    String::new()
+                                }
+                            },
+                            body_data_func,
+                            query_forwards_result: query_func.forwards_result,
+                            forwarded_body: forward.is_some(),
+                            forwarded_body_property: forward
+                                .as_ref()
+                                .and_then(|forward| forward.property())
+                                .map(str::to_string),
+                            forwards_result: query_func.forwards_result || forward.is_some(),
+                            method,
+                            path,
+                            path_params,
+                            header_params,
+                            host_env_var,
+                        });
+                    break;
+                }
+                query_data_functions.push(query_func);
+            }
 
-                            let body_func = create_array_fake_function(
-                                unique_array_func_name,
-                                array_type,
-                                &mut object_fake_functions,
-                                &mut array_fake_functions,
-                                symbol_generator,
-                                faker,
-                            );
-                            service_call_function.post_service_calls.push(
-                                models::PostServiceCall {
-                                    name: function_name,
-                                    requires_query_data: !query_func.params.is_empty(),
-                                    query_data_func: {
-                                        if !query_func.params.is_empty() {
-                                            query_func.name.clone()
-                                        } else {
-                                            String::new()
-                                        }
-                                    },
-                                    body_data_func: body_func.name.clone(),
-                                    path,
-                                    host_env_var,
-                                },
-                            );
-                            array_fake_functions.push(body_func);
-                            break;
-                        }
-                    }
-                    query_data_functions.push(query_func);
+            if service_call_function.get_service_calls.len()
+                + service_call_function.post_service_calls.len()
+                > defined_calls
+            {
+                let invocation = models::CallInvocation {
+                    name: symbol_generator.generate_individual_service_call_function_name(call),
+                    probability: call.semantics.probability,
+                    unconditional: call.semantics.probability >= 1.0,
+                    repetitions: call.semantics.repetitions,
+                    timeout: call.semantics.policy.timeout,
+                    retries: call.semantics.policy.retries,
+                    backoff: call.semantics.policy.backoff,
+                    fail_fast: call.semantics.policy.on_failure == FailurePolicy::FailFast,
+                    forwards_result: iter_sent_parameters(&parameters)
+                        .any(|param| caller.find_forward(&param.schema).is_some()),
+                };
+                service_call_function.forwards_result |= invocation.forwards_result;
+                match call.semantics.mode {
+                    CallMode::Sequential => service_call_function.sequential_calls.push(invocation),
+                    CallMode::Parallel => service_call_function.parallel_calls.push(invocation),
                 }
             }
         }
@@ -291,6 +392,9 @@ pub(crate) fn create_service_call_file_data(
         array_fake_functions,
         query_data_functions,
         service_call_functions,
+        tracing: graph.get_tracing().is_some(),
+        has_path_params,
+        has_header_params,
     }
 }
 
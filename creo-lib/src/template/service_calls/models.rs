@@ -12,6 +12,12 @@ pub struct ServiceCallFileData {
     pub query_data_functions: Vec<QueryDataFunction>,
     /// all service call functions
     pub service_call_functions: Vec<ServiceCallFunction>,
+    /// whether the service calls propagate the trace context
+    pub tracing: bool,
+    /// whether any service call sends a path parameter
+    pub has_path_params: bool,
+    /// whether any service call sends a header parameter
+    pub has_header_params: bool,
 }
 
 /// [`ObjectFakeFunction`] represents the dynamic information for an object fake function.
@@ -73,13 +79,16 @@ impl ArrayFakeFunction {
 }
 
 /// [`QueryDataFunction`] represents the dynamic information for the query data generation function
-/// for a single service call.
+/// for a single service call. Besides the query parameters, the data contains the path and header
+/// parameters of the service call.
 #[derive(serde::Serialize, Debug)]
 pub struct QueryDataFunction {
     /// the name of the function
     pub name: String,
     /// the expected input parameters
     pub params: Vec<QueryParamFakeFunction>,
+    /// flag indicating whether any parameter is forwarded from the result of the handler function
+    pub forwards_result: bool,
 }
 
 /// [`QueryParamFakeFunction`] represents a query parameter with its corresponding fake
@@ -94,10 +103,24 @@ pub struct QueryParamFakeFunction {
     pub nullable: bool,
     /// the probability with which to exclude the parameter if it is nullable
     pub exclude_probability: f64,
+    /// flag indicating whether the parameter is forwarded from the result of the handler function
+    /// instead of being faked
+    pub forwarded: bool,
+    /// the property of the result, which is forwarded, or none if the whole result is forwarded
+    pub forwarded_property: Option<String>,
+}
+
+/// [`HeaderParam`] represents a parameter, which a service call sends as HTTP header.
+#[derive(serde::Serialize, Debug)]
+pub struct HeaderParam {
+    /// the parameter name, i.e., its key in the data of the data function
+    pub name: String,
+    /// the name of the HTTP header
+    pub header_name: String,
 }
 
 /// [`PostServiceCall`] represents the dynamic information for a single service call to another
-/// endpoint.
+/// endpoint, which sends a request body.
 #[derive(serde::Serialize, Debug)]
 pub struct PostServiceCall {
     /// the name of the function
@@ -106,14 +129,34 @@ pub struct PostServiceCall {
     pub requires_query_data: bool,
     /// the data function name if the service call requires query data
     pub query_data_func: String,
-    /// the data function name if the service call requires body payload data
+    /// the data function name if the service call requires body payload data. If the body is
+    /// forwarded, this will be an empty string.
     pub body_data_func: String,
-    /// the path of the service call
+    /// flag indicating whether the query data function requires the result of the handler function
+    pub query_forwards_result: bool,
+    /// flag indicating whether the body is forwarded from the result of the handler function
+    pub forwarded_body: bool,
+    /// the property of the result, which is forwarded as body, or none if the whole result is
+    /// forwarded
+    pub forwarded_body_property: Option<String>,
+    /// flag indicating whether the service call requires the result of the handler function
+    pub forwards_result: bool,
+    /// the HTTP method of the service call, e.g. `PUT`
+    pub method: String,
+    /// the path of the service call without its path parameters
     pub path: String,
+    /// the names of the parameters, which are appended to the path in the given order. Their
+    /// values are taken from the data function.
+    pub path_params: Vec<String>,
+    /// the parameters, which are sent as HTTP headers. Their values are taken from the data
+    /// function.
+    pub header_params: Vec<HeaderParam>,
     /// the name of environment variable containing the host name for the service call
     pub host_env_var: String,
 }
 
+/// [`GetServiceCall`] represents the dynamic information for a single service call to another
+/// endpoint, which sends no request body.
 #[derive(serde::Serialize, Debug)]
 pub struct GetServiceCall {
     /// the name of the function
@@ -122,22 +165,62 @@ pub struct GetServiceCall {
     pub requires_data: bool,
     /// the data function name if the service call requires query data
     pub query_data_func: String,
-    /// the path of the service call
+    /// flag indicating whether the service call requires the result of the handler function
+    pub forwards_result: bool,
+    /// the HTTP method of the service call, e.g. `PUT`
+    pub method: String,
+    /// the path of the service call without its path parameters
     pub path: String,
+    /// the names of the parameters, which are appended to the path in the given order. Their
+    /// values are taken from the data function.
+    pub path_params: Vec<String>,
+    /// the parameters, which are sent as HTTP headers. Their values are taken from the data
+    /// function.
+    pub header_params: Vec<HeaderParam>,
     /// the name of environment variable containing the host name for the service call
     pub host_env_var: String,
 }
 
+/// [`CallInvocation`] represents how a single service call is issued by its endpoint.
+#[derive(serde::Serialize, Debug)]
+pub struct CallInvocation {
+    /// the name of the individual service call function
+    pub name: String,
+    /// the probability with which the call is issued per request
+    pub probability: f64,
+    /// flag indicating whether the call is issued on every request
+    pub unconditional: bool,
+    /// the number of times the call is issued
+    pub repetitions: usize,
+    /// the optional number of milliseconds after which a request times out
+    pub timeout: Option<u64>,
+    /// the number of times a failed request is retried
+    pub retries: usize,
+    /// the number of milliseconds to wait before the first retry
+    pub backoff: u64,
+    /// flag indicating whether a failed call fails the calling endpoint
+    pub fail_fast: bool,
+    /// flag indicating whether the service call requires the result of the handler function
+    pub forwards_result: bool,
+}
+
 /// [`ServiceCallFunction`] represents the dynamic information for all outgoing service
 /// calls of an endpoint.
 #[derive(serde::Serialize, Debug)]
 pub struct ServiceCallFunction {
     /// the name of the function
     pub name: String,
-    /// the individual service calls with a HTTP POST method
+    /// the individual service calls with a request body, e.g., HTTP POST requests
     pub post_service_calls: Vec<PostServiceCall>,
-    /// the individual service calls with a HTTP GET method
+    /// the individual service calls without a request body, e.g., HTTP GET requests
     pub get_service_calls: Vec<GetServiceCall>,
+    /// the service calls to issue one after another, in the order of their definition
+    pub sequential_calls: Vec<CallInvocation>,
+    /// the service calls to issue concurrently after the sequential calls
+    pub parallel_calls: Vec<CallInvocation>,
+    /// flag indicating whether any service call requires the result of the handler function, i.e.,
+    /// whether the service calls are issued after the handler function returned
+    pub forwards_result: bool,
 }
 
 impl ServiceCallFunction {
@@ -147,6 +230,9 @@ impl ServiceCallFunction {
             name,
             post_service_calls: Vec::default(),
             get_service_calls: Vec::default(),
+            sequential_calls: Vec::default(),
+            parallel_calls: Vec::default(),
+            forwards_result: false,
         }
     }
 }
@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+
+use indexmap::IndexMap;
+
+use super::{
+    call_graph::{CallGraph, CallGraphBuilder},
+    Error, Result,
+};
+
+/// The call types representing inter-service calls, while the remaining types (e.g., `db`, `mc`
+/// and `mq`) represent calls to stateful backends.
+const SERVICE_CALL_TYPES: [&str; 2] = ["rpc", "http"];
+
+/// The placeholder names of callers and callees outside of the traced application.
+const EXTERNAL_SERVICES: [&str; 4] = ["", "USER", "UNKNOWN", "UNAVAILABLE"];
+
+/// The endpoint name of a caller, whose own incoming call is not part of the trace.
+const ENTRY_ENDPOINT: &str = "entry";
+
+struct Row {
+    rpc_id: String,
+    upstream: String,
+    downstream: String,
+    call_type: Option<String>,
+    interface: String,
+}
+
+/// Parses the CSV call graph table of the Alibaba microservice traces.
+///
+/// Each row represents a call from the upstream service `um` to the `interface` of the downstream
+/// service `dm`. The endpoint of the upstream service is the interface of the parent call, i.e.,
+/// the call with the parent `rpcid` in the same trace.
+pub(super) fn parse(reader: impl std::io::Read) -> Result<CallGraph> {
+    let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(reader);
+    let headers = reader.headers()?.clone();
+    let column = |names: &[&'static str]| {
+        names
+            .iter()
+            .find_map(|name| headers.iter().position(|header| header == *name))
+            .ok_or(Error::MissingColumn(names[0]))
+    };
+    let trace_id = column(&["traceid", "trace_id"])?;
+    let rpc_id = column(&["rpcid", "rpc_id"])?;
+    let upstream = column(&["um"])?;
+    let downstream = column(&["dm"])?;
+    let interface = column(&["interface"])?;
+    let call_type = column(&["rpctype", "rpc_type"]).ok();
+
+    let mut traces: IndexMap<String, Vec<Row>> = IndexMap::new();
+    for record in reader.records() {
+        let record = record?;
+        let field = |idx: usize| record.get(idx).unwrap_or_default().to_string();
+        traces.entry(field(trace_id)).or_default().push(Row {
+            rpc_id: field(rpc_id),
+            upstream: field(upstream),
+            downstream: field(downstream),
+            call_type: call_type.map(field),
+            interface: field(interface),
+        });
+    }
+
+    let mut builder = CallGraphBuilder::default();
+    for rows in traces.values() {
+        add_trace(&mut builder, rows);
+    }
+    Ok(builder.build())
+}
+
+fn add_trace(builder: &mut CallGraphBuilder, rows: &[Row]) {
+    let calls: HashMap<&str, &Row> = rows.iter().map(|row| (row.rpc_id.as_str(), row)).collect();
+
+    for row in rows {
+        let is_service_call = row
+            .call_type
+            .as_deref()
+            .is_none_or(|call_type| SERVICE_CALL_TYPES.contains(&call_type));
+        if !is_service_call || EXTERNAL_SERVICES.contains(&row.downstream.as_str()) {
+            continue;
+        }
+        if EXTERNAL_SERVICES.contains(&row.upstream.as_str()) {
+            builder.add_endpoint(&row.downstream, &row.interface);
+            continue;
+        }
+
+        let caller = row
+            .rpc_id
+            .rsplit_once('.')
+            .and_then(|(parent, _)| calls.get(parent))
+            .filter(|parent| parent.downstream == row.upstream)
+            .map_or(ENTRY_ENDPOINT, |parent| parent.interface.as_str());
+        builder.add_call((&row.upstream, caller), (&row.downstream, &row.interface));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trace::Call;
+
+    #[test]
+    fn test_parse_alibaba() {
+        let csv = "\
+,timestamp,traceid,service,rpcid,um,rpctype,dm,interface,rt
+0,0,t1,s1,0,UNKNOWN,http,MS_1,i1,10
+1,1,t1,s1,0.1,MS_1,rpc,MS_2,i2,5
+2,2,t1,s1,0.1.1,MS_2,db,MS_DB,query,1
+3,3,t1,s1,0.1.2,MS_2,rpc,MS_3,i3,2
+4,4,t2,s1,0.1,MS_4,rpc,MS_2,i2,5
+";
+
+        let graph = parse(csv.as_bytes()).expect("should parse the trace");
+        let names: Vec<_> = graph.services.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["MS_1", "MS_2", "MS_3", "MS_4"]);
+        assert_eq!(
+            graph.services[1].endpoints[0].calls,
+            [Call {
+                service: "MS_3".to_string(),
+                endpoint: "i3".to_string()
+            }]
+        );
+        assert_eq!(graph.services[3].endpoints[0].name, ENTRY_ENDPOINT);
+    }
+
+    #[test]
+    fn test_parse_alibaba_with_missing_column() {
+        let csv = "traceid,rpcid,um,interface\nt1,0,MS_1,i1\n";
+
+        let result = parse(csv.as_bytes());
+        assert!(matches!(result, Err(Error::MissingColumn("dm"))));
+    }
+}
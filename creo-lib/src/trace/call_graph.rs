@@ -0,0 +1,149 @@
+use indexmap::{IndexMap, IndexSet};
+
+/// [`CallGraph`] contains the services, endpoints and inter-service calls observed in a trace
+/// dataset.
+#[derive(Debug, PartialEq, Eq)]
+pub struct CallGraph {
+    /// The services in the order of their first occurrence.
+    pub services: Vec<Service>,
+    /// The number of distinct calls, which were dropped, since they would have closed a cycle.
+    pub dropped_calls: usize,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Service {
+    pub name: String,
+    pub endpoints: Vec<Endpoint>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Endpoint {
+    pub name: String,
+    /// The endpoints called by this endpoint.
+    pub calls: Vec<Call>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Call {
+    pub service: String,
+    pub endpoint: String,
+}
+
+/// [`CallGraphBuilder`] incrementally collects endpoints and calls, while keeping the call graph
+/// acyclic.
+#[derive(Default)]
+pub(super) struct CallGraphBuilder {
+    endpoints: IndexSet<(String, String)>,
+    calls: Vec<IndexSet<usize>>,
+    dropped: IndexSet<(usize, usize)>,
+}
+
+impl CallGraphBuilder {
+    /// Adds the given endpoint, if it does not exist yet, and returns its index.
+    pub(super) fn add_endpoint(&mut self, service: &str, endpoint: &str) -> usize {
+        let (idx, inserted) = self
+            .endpoints
+            .insert_full((service.to_string(), endpoint.to_string()));
+        if inserted {
+            self.calls.push(IndexSet::new());
+        }
+        idx
+    }
+
+    /// Adds a call between the given endpoints.
+    ///
+    /// Calls within the same service are ignored, while calls closing a cycle are dropped.
+    pub(super) fn add_call(&mut self, source: (&str, &str), target: (&str, &str)) {
+        let source = self.add_endpoint(source.0, source.1);
+        let target = self.add_endpoint(target.0, target.1);
+        if self.endpoints[source].0 == self.endpoints[target].0
+            || self.calls[source].contains(&target)
+        {
+            return;
+        }
+        if self.is_reachable(target, source) {
+            self.dropped.insert((source, target));
+            return;
+        }
+        self.calls[source].insert(target);
+    }
+
+    /// Returns true, if there is a path of calls from `source` to `target`.
+    fn is_reachable(&self, source: usize, target: usize) -> bool {
+        let mut seen = vec![false; self.endpoints.len()];
+        let mut stack = vec![source];
+        while let Some(node) = stack.pop() {
+            if node == target {
+                return true;
+            }
+            if std::mem::replace(&mut seen[node], true) {
+                continue;
+            }
+            stack.extend(self.calls[node].iter().copied());
+        }
+        false
+    }
+
+    pub(super) fn build(self) -> CallGraph {
+        let mut services: IndexMap<&str, Vec<Endpoint>> = IndexMap::new();
+        for (idx, (service, endpoint)) in self.endpoints.iter().enumerate() {
+            let calls = self.calls[idx]
+                .iter()
+                .map(|&target| {
+                    let (service, endpoint) = &self.endpoints[target];
+                    Call {
+                        service: service.clone(),
+                        endpoint: endpoint.clone(),
+                    }
+                })
+                .collect();
+            services.entry(service).or_default().push(Endpoint {
+                name: endpoint.clone(),
+                calls,
+            });
+        }
+
+        CallGraph {
+            services: services
+                .into_iter()
+                .map(|(name, endpoints)| Service {
+                    name: name.to_string(),
+                    endpoints,
+                })
+                .collect(),
+            dropped_calls: self.dropped.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_call_graph_builder() {
+        let mut builder = CallGraphBuilder::default();
+        builder.add_call(("a", "x"), ("b", "y"));
+        builder.add_call(("a", "x"), ("b", "y"));
+        builder.add_call(("b", "y"), ("c", "z"));
+        builder.add_call(("c", "z"), ("a", "x"));
+        builder.add_call(("c", "z"), ("c", "w"));
+
+        let graph = builder.build();
+        assert_eq!(
+            graph.dropped_calls, 1,
+            "should drop the call closing a cycle"
+        );
+        let names: Vec<_> = graph.services.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["a", "b", "c"]);
+        assert_eq!(
+            graph.services[0].endpoints[0].calls,
+            [Call {
+                service: "b".to_string(),
+                endpoint: "y".to_string()
+            }]
+        );
+        assert_eq!(graph.services[2].endpoints.len(), 2);
+        assert!(graph.services[2].endpoints[0].calls.is_empty());
+    }
+}
@@ -0,0 +1,15 @@
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("failed to read trace file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("invalid json trace format: {0}")]
+    ParsingJson(#[from] serde_json::Error),
+    #[error("invalid csv trace format: {0}")]
+    ParsingCsv(#[from] csv::Error),
+    #[error("missing column {0} in csv trace file")]
+    MissingColumn(&'static str),
+    #[error("the trace file does not contain any calls")]
+    Empty,
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
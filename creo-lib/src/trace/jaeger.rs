@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use super::{
+    call_graph::{CallGraph, CallGraphBuilder},
+    span::{self, Span},
+    Result,
+};
+
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum Export {
+    Data { data: Vec<Trace> },
+    Traces(Vec<Trace>),
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Trace {
+    spans: Vec<JaegerSpan>,
+    processes: HashMap<String, Process>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JaegerSpan {
+    #[serde(rename = "spanID")]
+    span_id: String,
+    operation_name: String,
+    #[serde(default)]
+    references: Vec<Reference>,
+    #[serde(rename = "processID")]
+    process_id: String,
+    #[serde(default)]
+    tags: Vec<Tag>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Reference {
+    ref_type: String,
+    #[serde(rename = "spanID")]
+    span_id: String,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Process {
+    service_name: String,
+}
+
+#[derive(serde::Deserialize)]
+struct Tag {
+    key: String,
+    #[serde(default)]
+    value: serde_json::Value,
+}
+
+/// Parses a Jaeger JSON export, i.e., `{"data": [<trace>, ...]}` or a plain list of traces.
+pub(super) fn parse(reader: impl std::io::Read) -> Result<CallGraph> {
+    let traces = match serde_json::from_reader(reader)? {
+        Export::Data { data } => data,
+        Export::Traces(traces) => traces,
+    };
+
+    let mut builder = CallGraphBuilder::default();
+    for trace in traces {
+        let spans: Vec<Span> = trace
+            .spans
+            .into_iter()
+            .map(|span| {
+                let parent_id = span
+                    .references
+                    .iter()
+                    .find(|reference| reference.ref_type == "CHILD_OF")
+                    .or_else(|| span.references.first())
+                    .map(|reference| reference.span_id.clone());
+                let service = trace
+                    .processes
+                    .get(&span.process_id)
+                    .map(|process| process.service_name.clone())
+                    .unwrap_or(span.process_id);
+                let is_client = span
+                    .tags
+                    .iter()
+                    .any(|tag| tag.key == "span.kind" && tag.value == "client");
+                Span {
+                    id: span.span_id,
+                    parent_id,
+                    service,
+                    operation: span.operation_name,
+                    is_client,
+                }
+            })
+            .collect();
+        span::add_trace(&mut builder, &spans);
+    }
+
+    Ok(builder.build())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trace::Call;
+
+    #[test]
+    fn test_parse_jaeger() {
+        let json = r#"{
+            "data": [{
+                "traceID": "t1",
+                "spans": [
+                    {"traceID": "t1", "spanID": "a", "operationName": "HTTP GET /dispatch", "references": [], "processID": "p1"},
+                    {"traceID": "t1", "spanID": "b", "operationName": "HTTP GET /customer", "references": [{"refType": "CHILD_OF", "traceID": "t1", "spanID": "a"}], "processID": "p1", "tags": [{"key": "span.kind", "type": "string", "value": "client"}]},
+                    {"traceID": "t1", "spanID": "c", "operationName": "HTTP GET /customer", "references": [{"refType": "CHILD_OF", "traceID": "t1", "spanID": "b"}], "processID": "p2"}
+                ],
+                "processes": {
+                    "p1": {"serviceName": "frontend", "tags": []},
+                    "p2": {"serviceName": "customer", "tags": []}
+                }
+            }]
+        }"#;
+
+        let graph = parse(json.as_bytes()).expect("should parse the trace");
+        assert_eq!(graph.services.len(), 2);
+        assert_eq!(graph.services[0].name, "frontend");
+        assert_eq!(graph.services[0].endpoints.len(), 1);
+        assert_eq!(
+            graph.services[0].endpoints[0].calls,
+            [Call {
+                service: "customer".to_string(),
+                endpoint: "HTTP GET /customer".to_string()
+            }]
+        );
+    }
+}
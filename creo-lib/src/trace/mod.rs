@@ -0,0 +1,39 @@
+mod alibaba;
+mod call_graph;
+mod error;
+mod jaeger;
+mod span;
+mod zipkin;
+
+pub use call_graph::{Call, CallGraph, Endpoint, Service};
+pub use error::{Error, Result};
+
+/// [`TraceFormat`] specifies the file format of a trace dataset.
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TraceFormat {
+    /// The JSON export of the Jaeger UI or query API, i.e., `{"data": [<trace>, ...]}`.
+    Jaeger,
+    /// The Zipkin v2 JSON format, i.e., a list of spans or a list of traces.
+    Zipkin,
+    /// The CSV format of the call graph table (`MSCallGraph`) of the Alibaba microservice traces.
+    Alibaba,
+}
+
+/// Parses the trace file at the given path into a call graph.
+pub fn parse_call_graph(
+    path: impl AsRef<std::path::Path>,
+    format: TraceFormat,
+) -> Result<CallGraph> {
+    let file = std::io::BufReader::new(std::fs::File::open(path)?);
+    let call_graph = match format {
+        TraceFormat::Jaeger => jaeger::parse(file)?,
+        TraceFormat::Zipkin => zipkin::parse(file)?,
+        TraceFormat::Alibaba => alibaba::parse(file)?,
+    };
+
+    if call_graph.services.is_empty() {
+        return Err(Error::Empty);
+    }
+    Ok(call_graph)
+}
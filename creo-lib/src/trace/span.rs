@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+use super::call_graph::CallGraphBuilder;
+
+/// [`Span`] is the format-independent representation of a single span of a trace.
+pub(super) struct Span {
+    pub(super) id: String,
+    pub(super) parent_id: Option<String>,
+    pub(super) service: String,
+    pub(super) operation: String,
+    pub(super) is_client: bool,
+}
+
+/// Adds the endpoints and inter-service calls of a single trace to the given builder.
+///
+/// The endpoint of a span is the operation of the outermost span of the same service, i.e., spans
+/// with a parent span of the same service (e.g., internal or client spans) are attributed to the
+/// endpoint of their parent. A span with a parent span of another service represents a call from
+/// the parent's endpoint to the span's endpoint.
+pub(super) fn add_trace(builder: &mut CallGraphBuilder, spans: &[Span]) {
+    let mut index: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (idx, span) in spans.iter().enumerate() {
+        index.entry(span.id.as_str()).or_default().push(idx);
+    }
+
+    let parents: Vec<Option<usize>> = spans
+        .iter()
+        .enumerate()
+        .map(|(idx, span)| {
+            let candidates = index.get(span.parent_id.as_deref()?)?;
+            select_parent(spans, idx, candidates)
+        })
+        .collect();
+
+    for (idx, span) in spans.iter().enumerate() {
+        if entry_span(spans, &parents, idx) != idx {
+            continue;
+        }
+        builder.add_endpoint(&span.service, &span.operation);
+
+        if let Some(parent) = parents[idx] {
+            let caller = &spans[entry_span(spans, &parents, parent)];
+            builder.add_call(
+                (&caller.service, &caller.operation),
+                (&span.service, &span.operation),
+            );
+        }
+    }
+}
+
+/// Selects the parent of the span at `idx` among the spans sharing the parent's ID.
+///
+/// Some formats (e.g., Zipkin) use the same ID for the client span of the caller and the server
+/// span of the callee. Hence, spans of the same service are preferred, followed by non-client
+/// spans.
+fn select_parent(spans: &[Span], idx: usize, candidates: &[usize]) -> Option<usize> {
+    let candidates = candidates.iter().copied().filter(|&parent| parent != idx);
+    candidates
+        .clone()
+        .find(|&parent| spans[parent].service == spans[idx].service)
+        .or_else(|| candidates.clone().find(|&parent| !spans[parent].is_client))
+        .or_else(|| candidates.clone().next())
+}
+
+/// Returns the outermost span of the same service, which (transitively) contains the given span.
+fn entry_span(spans: &[Span], parents: &[Option<usize>], mut idx: usize) -> usize {
+    // Bound the number of steps to guard against cyclic parent references in malformed traces
+    for _ in 0..spans.len() {
+        match parents[idx] {
+            Some(parent) if spans[parent].service == spans[idx].service => idx = parent,
+            _ => break,
+        }
+    }
+    idx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trace::Call;
+
+    fn span(id: &str, parent_id: Option<&str>, service: &str, operation: &str) -> Span {
+        Span {
+            id: id.to_string(),
+            parent_id: parent_id.map(str::to_string),
+            service: service.to_string(),
+            operation: operation.to_string(),
+            is_client: false,
+        }
+    }
+
+    #[test]
+    fn test_add_trace() {
+        let spans = [
+            span("1", None, "frontend", "GET /"),
+            span("2", Some("1"), "frontend", "GET /cart"),
+            span("3", Some("2"), "cart", "get_cart"),
+            span("4", Some("3"), "cart", "redis"),
+            span("5", Some("1"), "catalogue", "list"),
+        ];
+        let mut builder = CallGraphBuilder::default();
+        add_trace(&mut builder, &spans);
+
+        let graph = builder.build();
+        assert_eq!(graph.services.len(), 3);
+        assert_eq!(graph.services[0].endpoints.len(), 1);
+        assert_eq!(graph.services[0].endpoints[0].name, "GET /");
+        assert_eq!(
+            graph.services[0].endpoints[0].calls,
+            [
+                Call {
+                    service: "cart".to_string(),
+                    endpoint: "get_cart".to_string()
+                },
+                Call {
+                    service: "catalogue".to_string(),
+                    endpoint: "list".to_string()
+                },
+            ]
+        );
+        assert_eq!(graph.services[1].endpoints.len(), 1);
+    }
+}
@@ -0,0 +1,90 @@
+use indexmap::IndexMap;
+
+use super::{
+    call_graph::{CallGraph, CallGraphBuilder},
+    span::{self, Span},
+    Result,
+};
+
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum Export {
+    Traces(Vec<Vec<ZipkinSpan>>),
+    Spans(Vec<ZipkinSpan>),
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ZipkinSpan {
+    trace_id: String,
+    id: String,
+    parent_id: Option<String>,
+    #[serde(default)]
+    name: String,
+    kind: Option<String>,
+    local_endpoint: Option<LocalEndpoint>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LocalEndpoint {
+    service_name: Option<String>,
+}
+
+/// Parses a Zipkin v2 JSON export, i.e., a list of spans or a list of traces.
+pub(super) fn parse(reader: impl std::io::Read) -> Result<CallGraph> {
+    let spans = match serde_json::from_reader(reader)? {
+        Export::Traces(traces) => traces.into_iter().flatten().collect(),
+        Export::Spans(spans) => spans,
+    };
+
+    let mut traces: IndexMap<String, Vec<Span>> = IndexMap::new();
+    for span in spans {
+        let service = span
+            .local_endpoint
+            .and_then(|endpoint| endpoint.service_name)
+            .unwrap_or_else(|| "unknown".to_string());
+        traces.entry(span.trace_id).or_default().push(Span {
+            id: span.id,
+            parent_id: span.parent_id,
+            service,
+            operation: span.name,
+            is_client: span.kind.as_deref() == Some("CLIENT"),
+        });
+    }
+
+    let mut builder = CallGraphBuilder::default();
+    for spans in traces.values() {
+        span::add_trace(&mut builder, spans);
+    }
+
+    Ok(builder.build())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trace::Call;
+
+    #[test]
+    fn test_parse_zipkin_with_shared_spans() {
+        let json = r#"[
+            {"traceId": "t1", "id": "a", "name": "get /", "kind": "SERVER", "localEndpoint": {"serviceName": "frontend"}},
+            {"traceId": "t1", "id": "b", "parentId": "a", "name": "get /cart", "kind": "CLIENT", "localEndpoint": {"serviceName": "frontend"}},
+            {"traceId": "t1", "id": "b", "parentId": "a", "name": "get /cart", "kind": "SERVER", "shared": true, "localEndpoint": {"serviceName": "cart"}},
+            {"traceId": "t1", "id": "c", "parentId": "b", "name": "get", "kind": "CLIENT", "localEndpoint": {"serviceName": "cart"}}
+        ]"#;
+
+        let graph = parse(json.as_bytes()).expect("should parse the trace");
+        assert_eq!(graph.services.len(), 2);
+        assert_eq!(graph.services[1].name, "cart");
+        assert_eq!(graph.services[1].endpoints.len(), 1);
+        assert_eq!(
+            graph.services[0].endpoints[0].calls,
+            [Call {
+                service: "cart".to_string(),
+                endpoint: "get /cart".to_string()
+            }]
+        );
+    }
+}
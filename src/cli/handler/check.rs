@@ -0,0 +1,9 @@
+#[derive(argh::FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "check")]
+/// Check that the definition, utilization, and source files of each handler function are valid
+/// and consistent with each other.
+pub struct SubCommand {
+    #[argh(option)]
+    /// the language whose handler functions are checked (default: all languages).
+    pub language: Option<creo_lib::programming_language::ProgrammingLanguage>,
+}
@@ -0,0 +1,18 @@
+pub mod check;
+pub mod new;
+
+#[derive(argh::FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "handler")]
+/// Check existing and create new handler functions of the supported languages.
+pub struct Command {
+    #[argh(subcommand)]
+    pub command: HandlerSubCommands,
+}
+
+#[derive(argh::FromArgs, PartialEq, Debug)]
+#[argh(subcommand)]
+/// Subcommands for `handler`.
+pub enum HandlerSubCommands {
+    Check(check::SubCommand),
+    New(new::SubCommand),
+}
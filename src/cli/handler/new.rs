@@ -0,0 +1,34 @@
+#[derive(argh::FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "new")]
+/// Create the skeleton of a new handler function, including its definition file and a placeholder
+/// utilization file, which marks the handler function as unprofiled.
+pub struct SubCommand {
+    #[argh(positional)]
+    /// the name of the handler function directory and package.
+    pub name: String,
+
+    #[argh(option)]
+    /// the language of the handler function.
+    pub language: creo_lib::programming_language::ProgrammingLanguage,
+
+    #[argh(option)]
+    /// the name of the handler function (default: the name of the package).
+    pub function: Option<String>,
+
+    #[argh(option)]
+    /// a parameter of the handler function in the form <name>:<type>, where the type is one of
+    /// string, integer, number, or boolean. May be repeated.
+    pub param: Vec<creo_lib::handler::ScaffoldParameter>,
+
+    #[argh(option)]
+    /// a short description of the handler function.
+    pub description: Option<String>,
+
+    #[argh(switch)]
+    /// whether the handler function is async.
+    pub is_async: bool,
+
+    #[argh(switch)]
+    /// whether the handler function returns a value.
+    pub returns: bool,
+}
@@ -2,6 +2,7 @@ pub mod benchmark;
 pub mod deploy;
 pub mod download;
 pub mod generate;
+pub mod handler;
 pub mod profile;
 
 use argh::FromArgs;
@@ -14,6 +15,7 @@ pub enum Commands {
     Deploy(deploy::Command),
     Benchmark(benchmark::Command),
     Download(download::Command),
+    Handler(handler::Command),
 }
 
 #[derive(FromArgs, PartialEq, Debug)]
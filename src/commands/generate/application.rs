@@ -2,74 +2,236 @@ use creo_lib::Port;
 use lazy_errors::prelude::*;
 use std::collections::HashMap;
 
-use creo_lib::{graph, handler, programming_language::ProgrammingLanguage};
+use creo_lib::{
+    application::CallSemantics,
+    graph::{self, EndpointIndex},
+    handler,
+    programming_language::{Framework, ProgrammingLanguage},
+    ServiceType,
+};
 
-use crate::{config, Result};
-
-use rand::seq::IteratorRandom;
+use crate::{config, Error, Result};
 
 pub fn auto_pilot<R: rand::Rng>(
     root: impl AsRef<std::path::Path>,
     args: &config::application::AutoPilotConfig,
     graph: graph::ColoredGraph,
+    call_semantics: HashMap<(EndpointIndex, EndpointIndex), CallSemantics>,
+    pinned_service_types: &[Option<ServiceType>],
     start_port: Port,
     rng: &mut R,
 ) -> Result<creo_lib::graph::ApplicationGraph> {
     let root = root.as_ref();
-    let service_types = &args.service_types;
 
     let handler_root_dir = root.join(creo_lib::HANDLER_FUNCTION_DIR);
     let all_defs =
         crate::io::parse_handler_definitions(handler_root_dir, args.programming_languages.iter())?;
+    let request_rates = args.request_rate.map(|request_rate| {
+        creo_lib::selection::expected_request_rates(&graph.graph, &call_semantics, request_rate)
+    });
     Ok(generate_application_graph(
         graph,
-        service_types,
+        args,
         all_defs,
+        pinned_service_types,
+        request_rates,
         rng,
         start_port,
-    ))
+    )?
+    .with_call_semantics(call_semantics))
 }
 
 fn generate_application_graph<R: rand::Rng>(
     graph: creo_lib::graph::ColoredGraph,
-    service_types: &creo_lib::ServiceTypeVec,
+    args: &config::application::AutoPilotConfig,
     mut all_defs: HashMap<ProgrammingLanguage, Vec<handler::Definition>>,
+    pinned_service_types: &[Option<ServiceType>],
+    request_rates: Option<Vec<f64>>,
     rng: &mut R,
     start_port: Port,
-) -> creo_lib::graph::ApplicationGraph {
+) -> Result<creo_lib::graph::ApplicationGraph> {
+    let budget = args.budget.as_ref();
+    let labels: Vec<_> = args
+        .service_types
+        .0
+        .iter()
+        .chain(pinned_service_types.iter().flatten())
+        .flat_map(|s_type| s_type.properties.iter().map(|property| &property.label))
+        .chain(budget.iter().flat_map(|budget| budget.0.keys()))
+        .collect();
+    for (lang, definitions) in &all_defs {
+        creo_lib::selection::check_profiled_labels(definitions, labels.iter().copied()).map_err(
+            |err| {
+                Error::new(format!(
+                    "failed to select {} handler functions!\n\tReason: {}",
+                    lang, err
+                ))
+            },
+        )?;
+    }
     let mut langs: Vec<ProgrammingLanguage> = Vec::with_capacity(graph.color_count());
+    let mut frameworks = Vec::with_capacity(graph.color_count());
     let mut s_types = Vec::with_capacity(graph.color_count());
-    let mut defs: Vec<std::path::PathBuf> = Vec::with_capacity(graph.graph.node_count());
+    let mut assignment: Vec<handler::Definition> = Vec::with_capacity(graph.graph.node_count());
+    let mut admissible = Vec::new();
 
-    for _ in graph.iter_colors() {
-        let lang = all_defs.keys().choose(rng).unwrap();
-        langs.push(*lang);
-        let s_type = creo_lib::selection::select_service_type(&service_types.0, rng);
-        s_types.push(s_type);
+    for color in graph.iter_colors() {
+        let lang =
+            creo_lib::selection::select_programming_language(&args.programming_languages, rng);
+        langs.push(lang);
+        frameworks.push(creo_lib::selection::select_framework(
+            &lang,
+            &args.frameworks,
+            rng,
+        ));
+        // Draw the service type even if it is pinned, such that pinning the service type of one
+        // service does not change the service types of the others
+        let s_type = creo_lib::selection::select_service_type(&args.service_types.0, rng);
+        match pinned_service_types.get(color.0).cloned().flatten() {
+            Some(pinned) => s_types.push(pinned),
+            None => s_types.push(s_type),
+        }
     }
 
-    for (_, color) in graph.iter_nodes_with_colors() {
+    let mut selection_log = Vec::new();
+    for (node, color) in graph.iter_nodes_with_colors() {
         let lang = &langs[color.0];
         let s_type = &s_types[color.0];
-        let resource = creo_lib::selection::select_resource(s_type, rng);
-        let handler_definitions = all_defs.get_mut(lang).unwrap();
-        let definition =
-            creo_lib::selection::select_definition(handler_definitions, &resource, rng);
+        let request_rate = request_rates.as_ref().map(|rates| rates[node.0]);
+        let mut projected_definitions;
+        let handler_definitions = match request_rate {
+            Some(request_rate) => {
+                projected_definitions = all_defs[lang]
+                    .iter()
+                    .map(|definition| definition.at_request_rate(request_rate))
+                    .collect::<Vec<_>>();
+                &mut projected_definitions
+            }
+            None => all_defs.get_mut(lang).unwrap(),
+        };
+        let definition = match args.selection {
+            creo_lib::selection::SelectionMode::Label => {
+                let resource = creo_lib::selection::select_resource(s_type, rng);
+                let definition =
+                    creo_lib::selection::select_definition(handler_definitions, &resource, rng)
+                        .map_err(|err| {
+                            Error::new(format!(
+                        "failed to select a {} handler function for resource {}!\n\tReason: {}",
+                        lang, resource, err
+                    ))
+                        })?;
+                if budget.is_some() {
+                    admissible.push(creo_lib::selection::admissible_definitions(
+                        handler_definitions,
+                        std::slice::from_ref(&resource),
+                        &definition,
+                    ));
+                }
+                definition
+            }
+            creo_lib::selection::SelectionMode::Vector => {
+                let (definition, mut explanation) =
+                    creo_lib::selection::select_definition_by_distance(
+                        handler_definitions,
+                        s_type,
+                        rng,
+                    )
+                    .map_err(|err| {
+                        Error::new(format!(
+                            "failed to select a {} handler function for {}!\n\tReason: {}",
+                            lang, s_type, err
+                        ))
+                    })?;
+                log::debug!(
+                    "Selected handler function {} for endpoint {} with distance {:.3}",
+                    explanation.handler_function,
+                    node.0,
+                    explanation.distance
+                );
+                explanation.request_rate = request_rate;
+                selection_log.push(explanation);
+                if budget.is_some() {
+                    admissible.push(creo_lib::selection::admissible_definitions(
+                        handler_definitions,
+                        &s_type.properties,
+                        &definition,
+                    ));
+                }
+                definition
+            }
+        };
+        assignment.push(definition);
+    }
+
+    let budget_report = match budget {
+        Some(budget) => {
+            let selected = assignment.clone();
+            let report =
+                creo_lib::selection::optimize_assignment(&mut assignment, &admissible, budget);
+            if !report.is_satisfied() {
+                return Err(Error::new(format!(
+                    "failed to meet the budget of the application!\n\tReason: {}",
+                    report
+                )));
+            }
+            log::info!("Predicted utilization of the application: {}", report);
+            for (endpoint, (selected, assigned)) in selected.iter().zip(&assignment).enumerate() {
+                if selected == assigned {
+                    continue;
+                }
+                let handler_function = assigned.name();
+                log::debug!(
+                    "Replaced handler function {} of endpoint {} by {} to meet the budget",
+                    selected,
+                    endpoint,
+                    handler_function
+                );
+                if let Some(explanation) = selection_log.get_mut(endpoint) {
+                    explanation.replaced_by = Some(handler_function);
+                }
+            }
+            Some(report)
+        }
+        None => None,
+    };
+    let mut knob_settings = HashMap::new();
+    let mut defs = Vec::with_capacity(assignment.len());
+    for (endpoint, definition) in assignment.into_iter().enumerate() {
+        if !definition.knobs.is_empty() {
+            knob_settings.insert(EndpointIndex(endpoint), definition.knobs);
+        }
         defs.push(definition.directory);
     }
 
-    creo_lib::graph::ApplicationGraph::new(graph, langs, start_port.into(), defs)
+    Ok(
+        creo_lib::graph::ApplicationGraph::new(graph, langs, start_port.into(), defs)
+            .with_frameworks(frameworks)
+            .with_service_types(s_types)
+            .with_selection_log(selection_log)
+            .with_budget_report(budget_report)
+            .with_knob_settings(knob_settings),
+    )
 }
 
 pub fn manual(
     graph: graph::ColoredGraph,
     languages: Vec<ProgrammingLanguage>,
+    frameworks: Vec<Framework>,
     definitions: Vec<std::path::PathBuf>,
     start_port: Port,
 ) -> Result<creo_lib::graph::ApplicationGraph> {
     let mut errors =
         ErrorStash::new(|| "There were one or more errors during handler function assignment!");
 
+    for (language, framework) in languages.iter().zip(&frameworks) {
+        if !framework.is_of(language) {
+            errors.push(format!(
+                "invalid framework assignment: {} is not a framework of {}",
+                framework, language
+            ));
+        }
+    }
+
     for def in &definitions {
         if !def.is_dir() {
             errors.push(format!(
@@ -81,10 +243,96 @@ pub fn manual(
 
     errors.into_result()?;
 
-    Ok(creo_lib::graph::ApplicationGraph::new(
-        graph,
-        languages,
-        start_port.into(),
-        definitions,
-    ))
+    Ok(
+        creo_lib::graph::ApplicationGraph::new(graph, languages, start_port.into(), definitions)
+            .with_frameworks(frameworks),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use creo_lib::de::FromYamlStr;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    fn definition(name: &str, latency: Option<f64>) -> handler::Definition {
+        let mut utilization = String::from("CPU: 10.0");
+        if let Some(latency) = latency {
+            utilization.push_str(&format!("\nLATENCY: {}", latency));
+        }
+        handler::Definition::new(
+            name,
+            FromYamlStr::from_yaml_str(&utilization).expect("valid utilization"),
+        )
+    }
+
+    fn generate(
+        definitions: Vec<handler::Definition>,
+    ) -> Result<creo_lib::graph::ApplicationGraph> {
+        let args = config::application::AutoPilotConfig::from_yaml_str(
+            "
+            programming_languages: [rust]
+            service_types:
+              - fraction: 100
+                properties:
+                  - label: LATENCY
+                    fraction: 100
+                    bucket: HIGH
+            ",
+        )
+        .expect("valid config");
+        let graph = graph::ColoredGraphBuilder::new(
+            graph::DiGraphBuilder::new().add_nodes(3).build(),
+            vec![graph::algorithms::coloring::ColorIndex(0); 3],
+            1,
+        )
+        .build();
+        let definitions = HashMap::from_iter([(ProgrammingLanguage::Rust(1), definitions)]);
+        let mut rng = StdRng::seed_from_u64(42);
+
+        generate_application_graph(
+            graph,
+            &args,
+            definitions,
+            &[],
+            None,
+            &mut rng,
+            Port::default(),
+        )
+    }
+
+    #[test]
+    fn test_generate_application_by_latency() {
+        let application = generate(vec![
+            definition("slow", Some(40.0)),
+            definition("fast", Some(2.0)),
+            definition("medium", Some(10.0)),
+        ])
+        .expect("should generate the application");
+
+        assert!(
+            application
+                .iter_endpoints()
+                .all(|endpoint| *endpoint.handler_dir == std::path::Path::new("slow")),
+            "every endpoint should use the slowest handler function"
+        );
+    }
+
+    #[test]
+    fn test_generate_application_without_latency() {
+        let err = generate(vec![
+            definition("slow", Some(40.0)),
+            definition("fast", Some(2.0)),
+            definition("unprofiled", None),
+        ])
+        .err()
+        .expect("should not generate the application");
+
+        assert!(
+            err.to_string().contains("unprofiled"),
+            "unexpected error: {}",
+            err
+        );
+    }
 }
@@ -1,39 +1,143 @@
 use std::collections::HashMap;
 
-use crate::{Error, Result};
+use crate::{config, Error, Result};
 
-pub struct AutoPilotParameters {
+pub struct AutoPilotParameters<'a> {
     pub vertices: usize,
     pub edges: usize,
     pub colors: usize,
+    pub model: &'a config::graph::TopologyModel,
+    pub coloring: &'a config::graph::ColoringStrategy,
 }
 
 pub fn auto_pilot<R: rand::Rng>(
     params: AutoPilotParameters,
     rng: &mut R,
 ) -> Result<creo_lib::graph::ColoredGraph> {
-    let gnm_params = creo_lib::graph::algorithms::random::GNMParameters {
-        node_count: params.vertices,
-        edge_count: params.edges,
-        maximum_degree: Some(params.colors),
-    };
-    let graph = creo_lib::graph::algorithms::random::random_gnm_graph(&gnm_params, rng);
-    let graph = color_graph(graph, params.colors)?;
+    let graph = random_graph(&params, rng)?;
+    let graph = color_graph(graph, &params, rng)?;
     Ok(graph)
 }
 
-fn color_graph(
+fn random_graph<R: rand::Rng>(
+    params: &AutoPilotParameters,
+    rng: &mut R,
+) -> Result<creo_lib::graph::DiGraph> {
+    use creo_lib::graph::algorithms::random;
+
+    // An equitable coloring with `colors` colors is only guaranteed to exist, if every node has
+    // less than `colors` neighbours. The other colorers assign the endpoints within quotas instead.
+    let maximum_degree = match params.coloring {
+        config::graph::ColoringStrategy::Equitable => Some(params.colors),
+        _ => None,
+    };
+    let graph = match params.model {
+        config::graph::TopologyModel::Gnm => {
+            let gnm_params = random::GNMParameters {
+                node_count: params.vertices,
+                edge_count: params.edges,
+                maximum_degree,
+            };
+            random::random_gnm_graph(&gnm_params, rng)
+        }
+        config::graph::TopologyModel::ScaleFree => {
+            let scale_free_params = random::ScaleFreeParameters {
+                node_count: params.vertices,
+                edge_count: params.edges,
+                maximum_degree,
+            };
+            random::random_scale_free_graph(&scale_free_params, rng)
+        }
+        config::graph::TopologyModel::Layered { layers } => {
+            let layered_params = random::LayeredParameters {
+                node_count: params.vertices,
+                edge_count: params.edges,
+                layer_count: *layers,
+                maximum_degree,
+            };
+            random::random_layered_graph(&layered_params, rng)
+        }
+        config::graph::TopologyModel::FanOut => {
+            let tree_params = random::TreeParameters {
+                node_count: params.vertices,
+                edge_count: params.edges,
+                direction: random::TreeDirection::FanOut,
+                maximum_degree,
+            };
+            random::random_tree_graph(&tree_params, rng)
+        }
+        config::graph::TopologyModel::FanIn => {
+            let tree_params = random::TreeParameters {
+                node_count: params.vertices,
+                edge_count: params.edges,
+                direction: random::TreeDirection::FanIn,
+                maximum_degree,
+            };
+            random::random_tree_graph(&tree_params, rng)
+        }
+    };
+
+    graph.map_err(|err| {
+        let msg = match err {
+            random::Error::Infeasible {
+                maximum_edge_count, ..
+            } if maximum_degree.is_some() => format!(
+                "the topology with {} endpoints, {} inter-service calls and {} services is infeasible: \
+                at most {} inter-service calls fit, since each endpoint may have less than {} \
+                inter-service calls (incoming and outgoing) and calls must not form a cycle",
+                params.vertices, params.edges, params.colors, maximum_edge_count, params.colors
+            ),
+            random::Error::Infeasible {
+                maximum_edge_count, ..
+            } => format!(
+                "the topology with {} endpoints and {} inter-service calls is infeasible: \
+                at most {} inter-service calls fit, since calls must not form a cycle",
+                params.vertices, params.edges, maximum_edge_count
+            ),
+            _ => format!("could not generate the topology: {}", err),
+        };
+        Error::with_log(msg, err)
+    })
+}
+
+fn color_graph<R: rand::Rng>(
     graph: creo_lib::graph::DiGraph,
-    color_count: usize,
+    params: &AutoPilotParameters,
+    rng: &mut R,
 ) -> Result<creo_lib::graph::ColoredGraph> {
-    let coloring =
-        creo_lib::graph::algorithms::coloring::equitable_coloring(&graph, color_count)
-            .map_err(|err| Error::with_log("could not find a valid coloring".to_string(), err))?;
+    use creo_lib::graph::algorithms::coloring::{self, Colorer};
 
-    Ok(creo_lib::graph::ColoredGraphBuilder::new(graph, coloring, color_count).build())
+    let colorer: Box<dyn Colorer> = match params.coloring {
+        config::graph::ColoringStrategy::Equitable => Box::new(coloring::EquitableColorer),
+        config::graph::ColoringStrategy::Dsatur => Box::new(coloring::GreedyColorer),
+        config::graph::ColoringStrategy::Zipf { exponent } => Box::new(coloring::ZipfColorer {
+            exponent: *exponent,
+        }),
+        config::graph::ColoringStrategy::Histogram { endpoints } => {
+            Box::new(coloring::HistogramColorer {
+                sizes: endpoints.clone(),
+            })
+        }
+    };
+    let coloring = colorer.color(&graph, params.colors, rng).map_err(|err| {
+        let msg = match err {
+            coloring::Error::ColorSizesNotReached { ref sizes } => format!(
+                "could not assign {:?} endpoints to the services without an inter-service call \
+                between two endpoints of the same service, consider fewer inter-service calls",
+                sizes
+            ),
+            _ => format!("could not find a valid coloring: {}", err),
+        };
+        Error::with_log(msg, err)
+    })?;
+
+    Ok(creo_lib::graph::ColoredGraphBuilder::new(graph, coloring, params.colors).build())
 }
 
-pub fn manual(params: ManualParameters) -> Result<creo_lib::graph::ColoredGraph> {
+/// Builds the manually specified topology.
+///
+/// Returns the topology together with the semantics of its inter-service calls.
+pub fn manual(params: ManualParameters) -> Result<(creo_lib::graph::ColoredGraph, CallSemantics)> {
     let v_map = params
         .vertices
         .iter()
@@ -42,6 +146,7 @@ pub fn manual(params: ManualParameters) -> Result<creo_lib::graph::ColoredGraph>
         .map(rev2)
         .collect::<HashMap<_, _>>();
     let mut edges = Vec::with_capacity(params.edges.len());
+    let mut semantics = HashMap::with_capacity(params.edges.len());
     for edge in params.edges {
         let source = *v_map
             .get(edge.source.as_key())
@@ -50,6 +155,13 @@ pub fn manual(params: ManualParameters) -> Result<creo_lib::graph::ColoredGraph>
             .get(edge.target.as_key())
             .ok_or_else(|| Error::new(format!("invalid edge target {}", edge.target.as_key())))?;
         edges.push(creo_lib::graph::Edge::from((source, target)));
+        semantics.insert(
+            (
+                creo_lib::graph::EndpointIndex(source),
+                creo_lib::graph::EndpointIndex(target),
+            ),
+            edge.semantics,
+        );
     }
     let graph = creo_lib::graph::DiGraphBuilder::with_node_and_edge_count(
         params.vertices.len(),
@@ -75,7 +187,9 @@ pub fn manual(params: ManualParameters) -> Result<creo_lib::graph::ColoredGraph>
         ));
     }
 
-    Ok(creo_lib::graph::ColoredGraphBuilder::new(graph, coloring, params.services.len()).build())
+    let graph =
+        creo_lib::graph::ColoredGraphBuilder::new(graph, coloring, params.services.len()).build();
+    Ok((graph, semantics))
 }
 
 pub struct VertexDefinition<'a> {
@@ -99,8 +213,18 @@ impl<'a> VertexDefinition<'a> {
 pub struct EdgeDefinition<'a> {
     pub source: VertexDefinition<'a>,
     pub target: VertexDefinition<'a>,
+    pub semantics: creo_lib::application::CallSemantics,
 }
 
+/// The semantics of the inter-service calls, indexed by the calling and called endpoint.
+pub type CallSemantics = HashMap<
+    (
+        creo_lib::graph::EndpointIndex,
+        creo_lib::graph::EndpointIndex,
+    ),
+    creo_lib::application::CallSemantics,
+>;
+
 pub struct ManualParameters<'a, 'b> {
     pub vertices: &'b [VertexDefinition<'a>],
     pub edges: &'b [EdgeDefinition<'a>],
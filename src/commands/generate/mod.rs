@@ -26,9 +26,19 @@ pub fn generate<P: AsRef<std::path::Path>>(
                 vertices: topology.number_of_endpoints,
                 edges: topology.number_of_inter_service_calls,
                 colors: topology.number_of_services,
+                model: &topology.model,
+                coloring: &topology.coloring,
             };
             let graph = graph::auto_pilot(params, &mut rng)?;
-            application::auto_pilot(root, workload, graph, start_port, &mut rng)?
+            application::auto_pilot(
+                root,
+                workload,
+                graph,
+                HashMap::new(),
+                &[],
+                start_port,
+                &mut rng,
+            )?
         }
         config::generate::Mode::Hybrid { topology, workload } => {
             let mut services = HashMap::with_capacity(topology.services.len());
@@ -43,13 +53,18 @@ pub fn generate<P: AsRef<std::path::Path>>(
                     ));
                     for call in endpoint.inter_service_calls.iter() {
                         // TODO: Error
-                        let (target_service, target_endpoint) = call.split_once(".").unwrap();
+                        let (target_service, target_endpoint) =
+                            call.target.split_once(".").unwrap();
                         let source = graph::VertexDefinition::new(
                             service.name.as_ref(),
                             endpoint.name.as_ref(),
                         );
                         let target = graph::VertexDefinition::new(target_service, target_endpoint);
-                        edges.push(graph::EdgeDefinition { source, target });
+                        edges.push(graph::EdgeDefinition {
+                            source,
+                            target,
+                            semantics: call.as_semantics(&args.call_policy),
+                        });
                     }
                 }
             }
@@ -58,19 +73,78 @@ pub fn generate<P: AsRef<std::path::Path>>(
                 edges: &edges,
                 services: &services,
             };
-            let graph = graph::manual(params)?;
-            application::auto_pilot(root, workload, graph, start_port, &mut rng)?
+            let (graph, call_semantics) = graph::manual(params)?;
+            let pinned_service_types: Vec<_> = topology
+                .services
+                .iter()
+                .map(|service| service.service_type.clone())
+                .collect();
+            application::auto_pilot(
+                root,
+                workload,
+                graph,
+                call_semantics,
+                &pinned_service_types,
+                start_port,
+                &mut rng,
+            )?
+        }
+        config::generate::Mode::Trace { topology, workload } => {
+            let call_graph =
+                crate::io::parse_call_graph(root.join(&topology.path), topology.format)?;
+            let mut services = HashMap::with_capacity(call_graph.services.len());
+            let mut vertices = Vec::default();
+            let mut edges = Vec::default();
+            for (idx, service) in call_graph.services.iter().enumerate() {
+                services.insert(service.name.as_str(), idx);
+                for endpoint in service.endpoints.iter() {
+                    vertices.push(graph::VertexDefinition::new(&service.name, &endpoint.name));
+                    for call in endpoint.calls.iter() {
+                        let source = graph::VertexDefinition::new(&service.name, &endpoint.name);
+                        let target = graph::VertexDefinition::new(&call.service, &call.endpoint);
+                        edges.push(graph::EdgeDefinition {
+                            source,
+                            target,
+                            semantics: creo_lib::application::CallSemantics {
+                                policy: args.call_policy,
+                                ..Default::default()
+                            },
+                        });
+                    }
+                }
+            }
+            let params = graph::ManualParameters {
+                vertices: &vertices,
+                edges: &edges,
+                services: &services,
+            };
+            let (graph, call_semantics) = graph::manual(params)?;
+            application::auto_pilot(
+                root,
+                workload,
+                graph,
+                call_semantics,
+                &[],
+                start_port,
+                &mut rng,
+            )?
         }
         config::generate::Mode::Manual { application } => {
             let mut services = HashMap::with_capacity(application.services.len());
             let mut vertices = Vec::default();
             let mut edges = Vec::default();
             let mut languages = Vec::with_capacity(application.services.len());
+            let mut frameworks = Vec::with_capacity(application.services.len());
             let mut definitions = Vec::default();
             let handler_root = root.join(creo_lib::HANDLER_FUNCTION_DIR);
             for (idx, service) in application.services.iter().enumerate() {
                 services.insert(service.name.as_ref(), idx);
                 languages.push(service.language);
+                frameworks.push(
+                    service
+                        .framework
+                        .unwrap_or_else(|| service.language.default_framework()),
+                );
                 for endpoint in service.endpoints.iter() {
                     vertices.push(graph::VertexDefinition::new(
                         service.name.as_ref(),
@@ -83,13 +157,18 @@ pub fn generate<P: AsRef<std::path::Path>>(
                     );
                     for call in endpoint.vertex.inter_service_calls.iter() {
                         // TODO: Error
-                        let (target_service, target_endpoint) = call.split_once(".").unwrap();
+                        let (target_service, target_endpoint) =
+                            call.target.split_once(".").unwrap();
                         let source = graph::VertexDefinition::new(
                             service.name.as_ref(),
                             endpoint.vertex.name.as_ref(),
                         );
                         let target = graph::VertexDefinition::new(target_service, target_endpoint);
-                        edges.push(graph::EdgeDefinition { source, target });
+                        edges.push(graph::EdgeDefinition {
+                            source,
+                            target,
+                            semantics: call.as_semantics(&args.call_policy),
+                        });
                     }
                 }
             }
@@ -98,10 +177,19 @@ pub fn generate<P: AsRef<std::path::Path>>(
                 edges: &edges,
                 services: &services,
             };
-            let graph = graph::manual(params)?;
-            application::manual(graph, languages, definitions, start_port)?
+            let (graph, call_semantics) = graph::manual(params)?;
+            application::manual(graph, languages, frameworks, definitions, start_port)?
+                .with_call_semantics(call_semantics)
         }
-    };
+    }
+    .with_call_policy(args.call_policy)
+    .with_fault_injection(args.fault_injection.clone())
+    .with_tracing(args.tracing);
+
+    let language_mix = creo_lib::io::language_mix(&application);
+    for mix in &language_mix {
+        log::info!("Language mix of {}: {}", args.app_name.as_ref(), mix);
+    }
 
     // Create output directory (if it does not exist)
     let out_dir = root.join(out.as_ref());
@@ -119,9 +207,15 @@ pub fn generate<P: AsRef<std::path::Path>>(
                 start: start_port.into(),
                 end: u32::from(start_port) + application.service_count() as u32,
             },
+            language_mix,
+            fault_injection: application.get_fault_injection(),
+            tracing: application.get_tracing(),
+            budget: application.get_budget_report(),
         },
     )?;
 
+    crate::io::write_application_topology(&app_dir, &application)?;
+
     let digits = application.service_count().digits();
     let registry = crate::io::create_handler_function_registry(&application)?;
     let template_dir = root.join(creo_lib::TEMPLATES_DIR);
@@ -139,7 +233,6 @@ pub fn generate<P: AsRef<std::path::Path>>(
             &service,
             &application,
             &registry,
-            &mut rng,
         )?;
         let service_dir = app_dir.join(&dir_name);
         let docker_compose = creo_lib::compose::create_service_compose_with_build(
@@ -218,6 +311,9 @@ pub fn generate<P: AsRef<std::path::Path>>(
     }
     let mut application_compose = creo_lib::compose::create_application_compose(service_compose);
     crate::io::add_metrics_collection(&app_dir, depends_on, &mut application_compose)?;
+    if let Some(tracing) = application.get_tracing() {
+        creo_lib::metrics::add_trace_collection(tracing, &mut application_compose);
+    }
     crate::io::create_init_service_file(&application_init, app_dir.join("init-services.conf"))?;
     crate::io::copy_file(
         std::path::Path::new("assets/init-services/init.sh"),
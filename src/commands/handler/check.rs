@@ -0,0 +1,85 @@
+use creo_lib::{
+    handler::{Finding, Severity},
+    programming_language::ProgrammingLanguage,
+};
+use strum::IntoEnumIterator;
+
+use crate::{Error, Result};
+
+/// Checks the handler functions of the given language, or of all languages if none is given, and
+/// writes the findings of each handler function to `stdout`.
+pub fn check(
+    language: Option<ProgrammingLanguage>,
+    root: impl AsRef<std::path::Path>,
+    stdout: &mut impl std::io::Write,
+) -> Result<()> {
+    let root_handler_dir = root.as_ref().join(creo_lib::HANDLER_FUNCTION_DIR);
+    let languages = match language {
+        Some(language) => vec![language],
+        None => ProgrammingLanguage::iter().collect(),
+    };
+
+    let mut handler_count = 0;
+    let mut error_count = 0;
+    let mut warning_count = 0;
+    for lang in languages {
+        for handler_dir in list_handler_dirs(&root_handler_dir, &lang)? {
+            let findings = creo_lib::handler::check_handler_function(&handler_dir, &lang);
+            handler_count += 1;
+            if findings.is_empty() {
+                continue;
+            }
+
+            writeln!(stdout, "{}:", handler_dir.display())?;
+            for finding in findings.iter() {
+                writeln!(stdout, "  {}", finding)?;
+            }
+            error_count += count_findings(&findings, Severity::Error);
+            warning_count += count_findings(&findings, Severity::Warning);
+        }
+    }
+    writeln!(
+        stdout,
+        "Checked {} handler functions: {} errors, {} warnings",
+        handler_count, error_count, warning_count
+    )?;
+
+    if error_count > 0 {
+        return Err(Error::new(format!(
+            "found {} errors in the handler functions",
+            error_count
+        )));
+    }
+    Ok(())
+}
+
+/// Returns the sorted handler function directories of the language. In contrast to the handler
+/// functions used for generation, directories without a definition file are included.
+fn list_handler_dirs(
+    root_handler_dir: &std::path::Path,
+    lang: &ProgrammingLanguage,
+) -> Result<Vec<std::path::PathBuf>> {
+    let lang_dir = root_handler_dir.join(lang.as_dir_name());
+    let entries = lang_dir.read_dir().map_err(|err| {
+        Error::new(format!(
+            "failed to read the handler functions of language {} at {}!\n\tReason: {}",
+            lang,
+            lang_dir.display(),
+            err
+        ))
+    })?;
+
+    let mut handler_dirs = Vec::default();
+    for entry in entries {
+        let path = entry?.path();
+        if path.is_dir() {
+            handler_dirs.push(path);
+        }
+    }
+    handler_dirs.sort();
+    Ok(handler_dirs)
+}
+
+fn count_findings(findings: &[Finding], severity: Severity) -> usize {
+    findings.iter().filter(|f| f.severity == severity).count()
+}
@@ -0,0 +1,5 @@
+mod check;
+mod new;
+
+pub use check::check;
+pub use new::new;
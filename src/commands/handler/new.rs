@@ -0,0 +1,40 @@
+use crate::{cli, Error, Result};
+
+/// Creates the skeleton of a new handler function in the handler function directory of its
+/// language and returns the path of the created directory.
+pub fn new(
+    args: cli::handler::new::SubCommand,
+    root: impl AsRef<std::path::Path>,
+) -> Result<std::path::PathBuf> {
+    let lang_dir = root
+        .as_ref()
+        .join(creo_lib::HANDLER_FUNCTION_DIR)
+        .join(args.language.as_dir_name());
+    let handler_dir = lang_dir.join(&args.name);
+    if handler_dir.exists() {
+        return Err(Error::new(format!(
+            "the handler function directory {} already exists",
+            handler_dir.display()
+        )));
+    }
+    std::fs::create_dir_all(&lang_dir)?;
+
+    let scaffold = creo_lib::handler::Scaffold {
+        function: args.function.unwrap_or_else(|| args.name.clone()),
+        name: args.name,
+        description: args.description,
+        is_async: args.is_async,
+        returns: args.returns,
+        parameters: args.param,
+    };
+    scaffold
+        .write(&handler_dir, &args.language)
+        .map_err(|err| {
+            Error::new(format!(
+                "failed to create the handler function at {}!\n\tReason: {}",
+                handler_dir.display(),
+                err
+            ))
+        })?;
+    Ok(handler_dir)
+}
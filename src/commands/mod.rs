@@ -2,4 +2,5 @@ pub mod benchmark;
 pub mod deploy;
 pub mod download;
 pub mod generate;
+pub mod handler;
 pub mod profile;
@@ -1,14 +1,44 @@
-use crate::cli;
+use std::collections::HashMap;
 
+use creo_lib::graph::EndpointIndex;
+
+use crate::{cli, Error, Result};
+
+use super::graph;
+
+/// Creates the profiling application with one service per handler function. Handler functions with
+/// knobs get one service per knob setting, such that each setting is profiled separately.
 pub fn profile_application(
     args: &cli::profile::generate::Config,
-    graph: creo_lib::graph::ColoredGraph,
-    defs: Vec<std::path::PathBuf>,
-) -> creo_lib::graph::ApplicationGraph {
-    creo_lib::graph::ApplicationGraph::new(
+    handler_dirs: Vec<std::path::PathBuf>,
+) -> Result<creo_lib::graph::ApplicationGraph> {
+    let mut defs = Vec::with_capacity(handler_dirs.len());
+    let mut knob_settings = HashMap::new();
+    for handler_dir in handler_dirs {
+        let function = creo_lib::io::parse_handler_function(&handler_dir).map_err(|err| {
+            Error::new(format!(
+                "invalid definition file at path {}!\n\tReason: {}",
+                handler_dir.display(),
+                err
+            ))
+        })?;
+        let settings = function.knob_settings();
+        if settings.is_empty() {
+            defs.push(handler_dir);
+            continue;
+        }
+        for setting in settings {
+            knob_settings.insert(EndpointIndex(defs.len()), setting);
+            defs.push(handler_dir.clone());
+        }
+    }
+
+    let graph = graph::generate_graph(defs.len());
+    Ok(creo_lib::graph::ApplicationGraph::new(
         graph,
         vec![args.language; defs.len()],
         args.start_port,
         defs,
     )
+    .with_knob_settings(knob_settings))
 }
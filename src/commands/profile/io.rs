@@ -13,5 +13,9 @@ pub fn create_application_meta_data<'a>(
             start: args.start_port,
             end: args.start_port + service_count as u32,
         },
+        language_mix: Vec::default(),
+        fault_injection: None,
+        tracing: None,
+        budget: None,
     }
 }
@@ -1,6 +1,5 @@
 use chrono::Utc;
 use creo_lib::{application::get_host, programming_language::ProgrammingLanguage};
-use rand_seeder as rng;
 use std::ffi::OsString;
 
 use crate::{cli, Error, Result};
@@ -28,11 +27,9 @@ pub fn generate(
     let seed = format!("profiling-{}", &args.language).to_lowercase();
     let generation_time = Utc::now();
     let gen_ts = generation_time.timestamp();
-    let mut rng: rng::SipRng = rng::Seeder::from(&seed).make_rng();
 
     let all_defs = crate::io::glob_language_handler_definitions(&root_handler_dir, &args.language)?;
-    let graph = graph::generate_graph(all_defs.len());
-    let application = application::profile_application(args, graph, all_defs);
+    let application = application::profile_application(args, all_defs)?;
 
     // Create output directory (if it does not exist)
     let out_dir = root.join(creo_lib::PROFILE_DIR);
@@ -60,6 +57,20 @@ pub fn generate(
                 .file_name()
                 .expect("should be able to obtain directory name"),
         );
+        if let Some(setting) = application.get_knob_setting(endpoint.id) {
+            // The index of the knob setting maps the benchmark results back to the setting
+            let idx = registry
+                .get_function(endpoint.id)
+                .knob_settings()
+                .iter()
+                .position(|other| other == setting)
+                .expect("should be a knob setting of the handler function");
+            dir_name.push(format!(
+                "{}{}",
+                creo_lib::handler::KNOB_SETTING_SEPARATOR,
+                idx
+            ));
+        }
         let service_name = dir_name.to_string_lossy();
         crate::io::create_service_folder(
             &app_dir,
@@ -68,7 +79,6 @@ pub fn generate(
             &service,
             &application,
             &registry,
-            &mut rng,
         )?;
         let service_dir = app_dir.join(&dir_name);
         let mut docker_compose = creo_lib::compose::create_service_compose_with_build(
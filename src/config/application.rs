@@ -11,20 +11,48 @@ pub struct AutoPilotConfig {
     )]
     pub programming_languages:
         creo_lib::de::UniqueVec<creo_lib::programming_language::ProgrammingLanguage>,
+    /// The web frameworks that are available during the generation. Languages without a listed
+    /// framework use their default framework.
+    #[serde(default, deserialize_with = "deserialize_frameworks")]
+    pub frameworks: creo_lib::de::UniqueVec<creo_lib::programming_language::Framework>,
+    /// How the handler functions of the endpoints are selected (Default: by a single label).
+    #[serde(default)]
+    pub selection: creo_lib::selection::SelectionMode,
+    /// The (optional) maximum total utilization of the application per label.
+    #[serde(default)]
+    pub budget: Option<creo_lib::selection::Budget>,
+    /// The (optional) number of requests per second the users send to the application. If
+    /// present, handler functions are selected by the utilization they show at the request rate
+    /// each endpoint is expected to receive.
+    #[serde(default, deserialize_with = "deserialize_request_rate")]
+    pub request_rate: Option<f64>,
 }
 
-/// Selects all programming languages as available during the generation.
+/// Selects all programming languages with profiled handler functions as available during the
+/// generation.
 fn select_all_programming_languages(
 ) -> creo_lib::de::UniqueVec<creo_lib::programming_language::ProgrammingLanguage> {
     creo_lib::programming_language::ProgrammingLanguage::iter()
+        .filter(is_profiled)
         .collect::<Vec<_>>()
         .into()
 }
 
+/// Returns whether the bundled handler functions of the programming language are profiled, such
+/// that the handler selection can choose among them.
+fn is_profiled(language: &creo_lib::programming_language::ProgrammingLanguage) -> bool {
+    !matches!(
+        language,
+        creo_lib::programming_language::ProgrammingLanguage::Go(_)
+            | creo_lib::programming_language::ProgrammingLanguage::Java(_)
+            | creo_lib::programming_language::ProgrammingLanguage::Node(_)
+    )
+}
+
 /// Deserializes and validate a list of programming languages.
 ///
-/// The list is invalid if it either contains duplicate languages or if it specifies selection
-/// probabilities that do not sum up to `100`.
+/// The list is invalid if it either contains duplicate languages, languages without profiled
+/// handler functions or if it specifies selection probabilities that do not sum up to `100`.
 fn deserialize_languages<'de, D>(
     deserializer: D,
 ) -> Result<creo_lib::de::UniqueVec<creo_lib::programming_language::ProgrammingLanguage>, D::Error>
@@ -35,6 +63,12 @@ where
         creo_lib::de::UniqueVec::<creo_lib::programming_language::ProgrammingLanguage>::deserialize(
             deserializer,
         )?;
+    if let Some(language) = this.iter().find(|l| !is_profiled(l)) {
+        return Err(serde::de::Error::custom(format!(
+            "the handler functions of {} are not profiled yet, use the manual mode instead",
+            language
+        )));
+    }
     let sum: usize = this.iter().map(|l| l.as_fraction()).sum();
     if !this.iter().all(|l| l.as_fraction() == 1) && sum != 100 {
         return Err(serde::de::Error::custom(format!(
@@ -44,3 +78,85 @@ where
     }
     Ok(this)
 }
+
+/// Deserializes and validate a list of web frameworks.
+///
+/// The list is invalid if it either contains duplicate frameworks or if it specifies selection
+/// probabilities for the frameworks of a programming language that do not sum up to `100`.
+fn deserialize_frameworks<'de, D>(
+    deserializer: D,
+) -> Result<creo_lib::de::UniqueVec<creo_lib::programming_language::Framework>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let this = creo_lib::de::UniqueVec::<creo_lib::programming_language::Framework>::deserialize(
+        deserializer,
+    )?;
+    for language in creo_lib::programming_language::ProgrammingLanguage::iter() {
+        let frameworks: Vec<_> = this.iter().filter(|f| f.is_of(&language)).collect();
+        let sum: usize = frameworks.iter().map(|f| f.as_fraction()).sum();
+        if !frameworks.iter().all(|f| f.as_fraction() == 1) && sum != 100 {
+            return Err(serde::de::Error::custom(format!(
+                "expected {} framework fractions to sum to 100, but was {}",
+                language, sum
+            )));
+        }
+    }
+    Ok(this)
+}
+
+/// Deserializes and validates the request rate of the application.
+///
+/// The request rate is invalid if it is not positive.
+fn deserialize_request_rate<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let this = <Option<f64> as serde::Deserialize>::deserialize(deserializer)?;
+    if let Some(request_rate) = this.filter(|r| r.is_nan() || *r <= 0.0) {
+        return Err(serde::de::Error::custom(format!(
+            "expected a positive request rate, but was {}",
+            request_rate
+        )));
+    }
+    Ok(this)
+}
+
+#[cfg(test)]
+mod tests {
+    use creo_lib::de::FromYamlStr;
+
+    use super::*;
+
+    const SERVICE_TYPES: &str = "
+        service_types:
+          - fraction: 100
+            properties:
+              - label: CPU
+                fraction: 100
+                bucket: HIGH
+    ";
+
+    #[test]
+    fn test_unprofiled_languages() {
+        let config = AutoPilotConfig::from_yaml_str(SERVICE_TYPES).expect("valid config");
+        assert!(
+            config.programming_languages.iter().all(is_profiled),
+            "unexpected default languages: {:?}",
+            config.programming_languages
+        );
+
+        for language in ["go", "java", "node"] {
+            let err = AutoPilotConfig::from_yaml_str(&format!(
+                "{}\n        programming_languages: [python, {}]",
+                SERVICE_TYPES, language
+            ))
+            .expect_err("the language is not profiled");
+            assert!(
+                err.to_string().contains("not profiled"),
+                "unexpected error: {}",
+                err
+            );
+        }
+    }
+}
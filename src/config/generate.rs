@@ -19,6 +19,20 @@ pub struct Config {
     #[serde(default)]
     pub start_port: creo_lib::Port,
 
+    /// The timeout, retry and failure policy of all inter-service calls, which do not specify
+    /// their own policy (Default: no timeout, no retries, degrade gracefully).
+    #[serde(default)]
+    pub call_policy: creo_lib::application::CallPolicy,
+
+    /// The (optional) faults injected into the generated microservices.
+    #[serde(default)]
+    pub fault_injection: Option<creo_lib::fault_injection::FaultInjection>,
+
+    /// The (optional) distributed tracing of the generated microservices. If present, the
+    /// microservices emit OpenTelemetry spans, which are collected by an added Jaeger service.
+    #[serde(default)]
+    pub tracing: Option<creo_lib::metrics::Tracing>,
+
     #[serde(flatten)]
     pub mode: Mode,
 }
@@ -34,6 +48,10 @@ pub enum Mode {
         topology: super::graph::HypridConfig,
         workload: super::application::AutoPilotConfig,
     },
+    Trace {
+        topology: super::graph::TraceConfig,
+        workload: super::application::AutoPilotConfig,
+    },
     Manual {
         #[serde(flatten)]
         application: super::graph::ManualConfig,
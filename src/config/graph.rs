@@ -13,6 +13,160 @@ pub struct AutoPilotConfig {
     /// The number of services (colors) to generate.
     #[serde(alias = "services")]
     pub number_of_services: usize,
+
+    /// The random graph model used to generate the topology (Default: `gnm`).
+    #[serde(default)]
+    pub model: TopologyModel,
+
+    /// The strategy used to assign the endpoints to services (Default: `equitable`).
+    #[serde(default)]
+    pub coloring: ColoringStrategy,
+}
+
+#[derive(Debug, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(tag = "name", rename_all = "snake_case", remote = "Self")]
+/// The random graph model of an automatically generated topology.
+///
+/// The model can either be specified by its name only, e.g., `model: scale_free`, or as a mapping
+/// with its name and parameters, e.g., `model: { name: layered, layers: 4 }`.
+pub enum TopologyModel {
+    /// Inter-service calls are drawn uniformly from all possible calls.
+    #[default]
+    #[serde(alias = "uniform")]
+    Gnm,
+    /// Inter-service calls are attached preferentially to already frequently called endpoints,
+    /// resulting in a few hub endpoints.
+    #[serde(alias = "preferential_attachment")]
+    ScaleFree,
+    /// Endpoints are split into tiers and inter-service calls only lead to deeper tiers.
+    Layered {
+        /// The number of tiers.
+        #[serde(default = "default_layer_count")]
+        layers: usize,
+    },
+    /// Endpoints form a tree, in which each endpoint calls its children.
+    FanOut,
+    /// Endpoints form a tree, in which each endpoint calls its parent.
+    FanIn,
+}
+
+fn default_layer_count() -> usize {
+    3
+}
+
+impl<'de> serde::Deserialize<'de> for TopologyModel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Name(String),
+            Model(#[serde(with = "TopologyModel")] TopologyModel),
+        }
+
+        let this = match Repr::deserialize(deserializer)? {
+            Repr::Name(name) => {
+                let map = serde::de::value::MapDeserializer::<_, D::Error>::new(std::iter::once((
+                    "name", name,
+                )));
+                TopologyModel::deserialize(map)?
+            }
+            Repr::Model(model) => model,
+        };
+
+        if let TopologyModel::Layered { layers } = this {
+            if layers < 2 {
+                return Err(serde::de::Error::custom(format!(
+                    "expected a layered topology model to have at least 2 layers, but got {}",
+                    layers
+                )));
+            }
+        }
+
+        Ok(this)
+    }
+}
+
+#[derive(Debug, Default, PartialEq, serde::Deserialize)]
+#[serde(tag = "name", rename_all = "snake_case", remote = "Self")]
+/// The strategy to assign the endpoints of an automatically generated topology to services.
+///
+/// The strategy can either be specified by its name only, e.g., `coloring: dsatur`, or as a
+/// mapping with its name and parameters, e.g., `coloring: { name: zipf, exponent: 1.5 }`.
+pub enum ColoringStrategy {
+    /// All services have the same number of endpoints (up to a difference of one).
+    #[default]
+    Equitable,
+    /// Endpoints are assigned in DSatur order, i.e., the endpoint with the most distinctly
+    /// assigned neighbours first, each to the least used service among the services without any of
+    /// its neighbours. Results in roughly, but not exactly balanced services.
+    #[serde(alias = "greedy")]
+    Dsatur,
+    /// The number of endpoints per service approximately follows Zipf's law, resulting in a few
+    /// large and many small services.
+    Zipf {
+        /// The exponent of the distribution, where larger values result in more skewed services.
+        #[serde(default = "default_zipf_exponent")]
+        exponent: f64,
+    },
+    /// The number of endpoints of each service is specified explicitly.
+    Histogram {
+        /// The number of endpoints of each service.
+        endpoints: Vec<usize>,
+    },
+}
+
+fn default_zipf_exponent() -> f64 {
+    1.0
+}
+
+impl<'de> serde::Deserialize<'de> for ColoringStrategy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Name(String),
+            Strategy(#[serde(with = "ColoringStrategy")] ColoringStrategy),
+        }
+
+        let this = match Repr::deserialize(deserializer)? {
+            Repr::Name(name) => {
+                let map = serde::de::value::MapDeserializer::<_, D::Error>::new(std::iter::once((
+                    "name", name,
+                )));
+                ColoringStrategy::deserialize(map)?
+            }
+            Repr::Strategy(strategy) => strategy,
+        };
+
+        match &this {
+            ColoringStrategy::Zipf { exponent } if !exponent.is_finite() || *exponent < 0.0 => {
+                Err(serde::de::Error::custom(format!(
+                    "expected the exponent of the zipf coloring to be a non-negative number, but got {}",
+                    exponent
+                )))
+            }
+            ColoringStrategy::Histogram { endpoints } if endpoints.contains(&0) => {
+                Err(serde::de::Error::custom(
+                    "expected every service of the histogram coloring to have at least 1 endpoint",
+                ))
+            }
+            _ => Ok(this),
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct TraceConfig {
+    /// The path to the trace file, relative to the project root.
+    pub path: std::path::PathBuf,
+    /// The format of the trace file.
+    pub format: creo_lib::trace::TraceFormat,
 }
 
 #[derive(Debug, serde::Deserialize)]
@@ -28,6 +182,10 @@ pub struct MicroserviceTopologyDefinition {
     pub name: creo_lib::de::NonEmptyString,
     /// The endpoints of the microservice.
     pub endpoints: creo_lib::de::NonEmptyVec<VertexDefinition>,
+    /// The (optional) service type of the microservice, either as the name of a preset or with its
+    /// properties. If absent, the service type is drawn from the workload's service types.
+    #[serde(default)]
+    pub service_type: Option<creo_lib::ServiceType>,
 }
 
 impl PartialEq for MicroserviceTopologyDefinition {
@@ -50,7 +208,7 @@ pub struct VertexDefinition {
     pub name: creo_lib::de::NonEmptyString,
     /// The IDs of the vertices this vertex is connected to
     #[serde(default)]
-    pub inter_service_calls: creo_lib::de::UniqueVec<String>,
+    pub inter_service_calls: creo_lib::de::UniqueVec<InterServiceCallDefinition>,
 }
 
 impl PartialEq for VertexDefinition {
@@ -80,8 +238,8 @@ impl<'de> serde::Deserialize<'de> for VertexDefinition {
         });
 
         for call in this.inter_service_calls.iter() {
-            if !call.contains(".") {
-                let msg = format!("invalid inter-service call definition: {}, (expected format: <service_name>.<endpoint_name>)", call);
+            if !call.target.contains(".") {
+                let msg = format!("invalid inter-service call definition: {}, (expected format: <service_name>.<endpoint_name>)", call.target);
                 errors.push(msg);
             }
         }
@@ -91,6 +249,127 @@ impl<'de> serde::Deserialize<'de> for VertexDefinition {
     }
 }
 
+#[derive(Debug, serde::Deserialize)]
+#[serde(remote = "Self")]
+/// A manual definition of an inter-service call.
+///
+/// The call can either be specified by its target only, e.g., `catalogue.product`, or as a mapping
+/// with its target and call semantics, e.g., `{ target: catalogue.product, mode: sequential }`.
+pub struct InterServiceCallDefinition {
+    /// The called endpoint in the format `<service_name>.<endpoint_name>`.
+    pub target: String,
+    /// Whether the call is issued sequentially or in parallel (Default: `parallel`).
+    #[serde(default)]
+    pub mode: creo_lib::application::CallMode,
+    /// The probability with which the call is issued per request (Default: `1`).
+    #[serde(default = "default_call_probability")]
+    pub probability: f64,
+    /// The number of times the call is issued per request (Default: `1`).
+    #[serde(default = "default_call_repetitions")]
+    pub repetitions: usize,
+    /// The number of milliseconds after which a request times out (Default: application's policy).
+    #[serde(
+        default,
+        deserialize_with = "creo_lib::application::deserialize_timeout"
+    )]
+    pub timeout: Option<u64>,
+    /// The number of times a failed request is retried (Default: application's policy).
+    #[serde(default)]
+    pub retries: Option<usize>,
+    /// The number of milliseconds to wait before the first retry (Default: application's policy).
+    #[serde(default)]
+    pub backoff: Option<u64>,
+    /// How the calling endpoint responds, if the call failed (Default: application's policy).
+    #[serde(default)]
+    pub on_failure: Option<creo_lib::application::FailurePolicy>,
+}
+
+fn default_call_probability() -> f64 {
+    1.0
+}
+
+fn default_call_repetitions() -> usize {
+    1
+}
+
+impl InterServiceCallDefinition {
+    /// Returns the semantics of the call, where unspecified policy fields are taken from the
+    /// given application-wide call policy.
+    pub fn as_semantics(
+        &self,
+        call_policy: &creo_lib::application::CallPolicy,
+    ) -> creo_lib::application::CallSemantics {
+        creo_lib::application::CallSemantics {
+            mode: self.mode,
+            probability: self.probability,
+            repetitions: self.repetitions,
+            policy: creo_lib::application::CallPolicy {
+                timeout: self.timeout.or(call_policy.timeout),
+                retries: self.retries.unwrap_or(call_policy.retries),
+                backoff: self.backoff.unwrap_or(call_policy.backoff),
+                on_failure: self.on_failure.unwrap_or(call_policy.on_failure),
+            },
+        }
+    }
+}
+
+impl PartialEq for InterServiceCallDefinition {
+    fn eq(&self, other: &Self) -> bool {
+        self.target == other.target
+    }
+}
+
+impl Eq for InterServiceCallDefinition {}
+
+impl std::hash::Hash for InterServiceCallDefinition {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.target.hash(state);
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for InterServiceCallDefinition {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Target(String),
+            Definition(#[serde(with = "InterServiceCallDefinition")] InterServiceCallDefinition),
+        }
+
+        let this = match Repr::deserialize(deserializer)? {
+            Repr::Target(target) => InterServiceCallDefinition {
+                target,
+                mode: creo_lib::application::CallMode::default(),
+                probability: default_call_probability(),
+                repetitions: default_call_repetitions(),
+                timeout: None,
+                retries: None,
+                backoff: None,
+                on_failure: None,
+            },
+            Repr::Definition(definition) => definition,
+        };
+
+        if !(this.probability > 0.0 && this.probability <= 1.0) {
+            return Err(serde::de::Error::custom(format!(
+                "expected the probability of the inter-service call to {} to be in (0, 1], but got {}",
+                this.target, this.probability
+            )));
+        }
+        if this.repetitions == 0 {
+            return Err(serde::de::Error::custom(format!(
+                "expected the inter-service call to {} to be repeated at least once",
+                this.target
+            )));
+        }
+
+        Ok(this)
+    }
+}
+
 #[derive(Debug, serde::Deserialize)]
 pub struct ManualConfig {
     /// The list of microservice definitions
@@ -104,6 +383,10 @@ pub struct MicroserviceDefinition {
     pub name: creo_lib::de::NonEmptyString,
     /// The programming language of the microservice
     pub language: creo_lib::programming_language::ProgrammingLanguage,
+    /// The (optional) web framework of the microservice (Default: the default framework of the
+    /// programming language).
+    #[serde(default)]
+    pub framework: Option<creo_lib::programming_language::Framework>,
     /// The endpoints of the microservice.
     pub endpoints: creo_lib::de::NonEmptyVec<EndpointDefinition>,
 }
@@ -20,7 +20,7 @@ pub fn parse_handler_definitions<'a>(
     for (key, def) in &defs {
         if def.len() < 3 {
             return Err(Error::new(format!(
-                "at least 3 handler functions are required for language {}",
+                "at least 3 profiled handler functions are required for language {}!\n\tReason: unprofiled handler functions are skipped, profile them with `creo profile`",
                 key
             )));
         }
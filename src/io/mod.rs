@@ -2,11 +2,14 @@ mod config;
 mod handler_definition;
 mod handler_function;
 mod output;
+mod trace;
 
 pub use config::parse_config;
 pub use handler_definition::{glob_language_handler_definitions, parse_handler_definitions};
 pub use handler_function::create_handler_function_registry;
 pub use output::{
     add_metrics_collection, copy_file, create_application_directory, create_init_service_file,
-    create_output_directory, create_service_folder, write_docker_compose_file,
+    create_output_directory, create_service_folder, write_application_topology,
+    write_docker_compose_file,
 };
+pub use trace::parse_call_graph;
@@ -75,14 +75,26 @@ pub fn create_application_directory(
     Ok(())
 }
 
-pub fn create_service_folder<R: rand::Rng>(
+pub fn write_application_topology(
+    app_dir: impl AsRef<std::path::Path>,
+    application: &creo_lib::graph::ApplicationGraph,
+) -> Result<()> {
+    creo_lib::io::write_application_topology(&app_dir, application).map_err(|err| {
+        Error::new(format!(
+            "failed to write application topology to path {}!\n\tReason: {}",
+            app_dir.as_ref().display(),
+            err
+        ))
+    })
+}
+
+pub fn create_service_folder(
     application_dir: impl AsRef<std::path::Path>,
     service_name: impl AsRef<str>,
     template_dir: impl AsRef<std::path::Path>,
     service: &creo_lib::application::MicroService,
     application: &creo_lib::graph::ApplicationGraph,
     registry: &handler::FunctionRegistry,
-    rng: &mut R,
 ) -> Result<()> {
     creo_lib::io::create_service_folder(
         application_dir.as_ref(),
@@ -91,7 +103,6 @@ pub fn create_service_folder<R: rand::Rng>(
         service,
         application,
         registry,
-        rng,
     )
     .map_err(|err| {
         Error::new(format!(
@@ -0,0 +1,25 @@
+use crate::{Error, Result};
+
+pub fn parse_call_graph(
+    path: impl AsRef<std::path::Path>,
+    format: creo_lib::trace::TraceFormat,
+) -> Result<creo_lib::trace::CallGraph> {
+    let path = path.as_ref();
+    let call_graph = creo_lib::trace::parse_call_graph(path, format).map_err(|err| {
+        Error::new(format!(
+            "failed to import topology from trace file {}!\n\tReason: {}",
+            path.display(),
+            err
+        ))
+    })?;
+
+    if call_graph.dropped_calls > 0 {
+        log::warn!(
+            "Dropped {} inter-service calls from trace file {}, since they would form a cycle",
+            call_graph.dropped_calls,
+            path.display()
+        );
+    }
+
+    Ok(call_graph)
+}
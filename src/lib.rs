@@ -116,6 +116,31 @@ pub async fn entrypoint(args: cli::Args, stdout: &mut impl std::io::Write) -> Re
                 }
             }
         }
+        cli::Commands::Handler(handler) => match handler.command {
+            cli::handler::HandlerSubCommands::Check(args) => {
+                let result = commands::handler::check(args.language, &root, stdout);
+                match result {
+                    Ok(_) => log::info!("All handler functions are valid!"),
+                    Err(err) => {
+                        log::error!("{}", err);
+                        std::process::exit(1);
+                    }
+                }
+            }
+            cli::handler::HandlerSubCommands::New(args) => {
+                let result = commands::handler::new(args, &root);
+                match result {
+                    Ok(handler_dir) => log::info!(
+                        "Successfully created handler function at {}! Profile it with `creo profile` to make it selectable.",
+                        handler_dir.display()
+                    ),
+                    Err(err) => {
+                        log::error!("{}", err);
+                        std::process::exit(1);
+                    }
+                }
+            }
+        },
         cli::Commands::Download(args) => {
             let config = io::parse_config::<cli::download::Config>(&args.config)?;
             let result = commands::download::invoke(&config.ssh, config.application, output).await;